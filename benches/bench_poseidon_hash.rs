@@ -14,13 +14,20 @@ fn criterion_benchmark(c: &mut Criterion) {
         "12242166908188651009877250812424843524687801523336557272219921456462821518061",
     )
     .unwrap();
-    let mut big_arr: Vec<Fr> = Vec::new();
-    big_arr.push(b1.clone());
-    big_arr.push(b2.clone());
+    let big_arr: Vec<Fr> = vec![b1, b2];
     let poseidon = Poseidon::new();
 
     c.bench_function("hash", |b| {
-        b.iter(|| poseidon.hash(big_arr.clone()).unwrap())
+        b.iter(|| poseidon.hash(&big_arr).unwrap())
+    });
+
+    c.bench_function("hash2", |b| {
+        b.iter(|| poseidon.hash2(b1, b2).unwrap())
+    });
+
+    let batch: Vec<&[Fr]> = vec![&big_arr; 256];
+    c.bench_function("hash_many 256", |b| {
+        b.iter(|| poseidon.hash_many(&batch).unwrap())
     });
 }
 
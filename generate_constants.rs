@@ -1,57 +1,394 @@
+//! Regenerates `src/static_constants.rs` and `src/static_optimized_constants.rs` from the
+//! circomlib constants in `data/poseidon_constants.json`.
+//!
+//!     cargo run --features codegen --bin generate_constants [-- --check] [constants.json]
+//!
+//! The JSON has the layout of circomlib's `poseidon_constants.json`: `C` holds the round
+//! constants and `M` the MDS matrices, both indexed by `t - 2`, with every element as a
+//! `0x`-prefixed hex (or decimal) string. Its SHA-256 must match the digest pinned in
+//! `data/poseidon_constants.json.sha256`, so a changed constant set has to be re-pinned
+//! explicitly. With `--check` nothing is written; the run fails if the committed files
+//! differ from what the JSON generates.
+//!
+//! circomlib stops at t = 17. The wider states up to t = 25 are generated here with the
+//! Grain LFSR using circomlib's seed parameters (s-box code 0, R_F = 8). Their R_P is the
+//! security bound with the reference script's 7.5% margin, ceil(1.075 * 53) = 57 for
+//! these widths, rounded up to a multiple of t as circomlib does (so its t = 10, 12 and
+//! 15 entries are 60).
+
+extern crate alloc;
+
 use ark_bn254::Fr;
+use ark_ff::{BigInteger256, PrimeField};
 use ark_std::{str::FromStr, vec::Vec};
-use std::fs::File;
-use std::io::Write;
+use std::fs;
 
-// Include your constants module
+#[allow(dead_code)]
 mod src {
-    pub mod constants;
+    pub mod grain;
+    pub mod optimized;
 }
 
+const N_ROUNDS_F: usize = 8;
+const N_ROUNDS_P: [usize; 24] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68, 72, 76, 60, 63, 66, 69, 72, 75,
+];
+
+const DEFAULT_INPUT: &str = "data/poseidon_constants.json";
+const CHECKSUM_FILE: &str = "data/poseidon_constants.json.sha256";
+const STATIC_CONSTANTS: &str = "src/static_constants.rs";
+const STATIC_OPTIMIZED_CONSTANTS: &str = "src/static_optimized_constants.rs";
+
 fn main() {
-    println!("Generating static constants code...");
-    
-    // Load string constants
-    let (c_str, m_str) = src::constants::constants();
-    
+    let mut check = false;
+    let mut input = DEFAULT_INPUT.to_string();
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--check" => check = true,
+            _ => input = arg,
+        }
+    }
+
+    let json = fs::read(&input).unwrap_or_else(|e| fail(&format!("{}: {}", input, e)));
+    let digest = solana_program::hash::hash(&json).to_bytes();
+    let digest: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    let pinned = fs::read_to_string(CHECKSUM_FILE)
+        .unwrap_or_else(|e| fail(&format!("{}: {}", CHECKSUM_FILE, e)));
+    if pinned.split_whitespace().next() != Some(digest.as_str()) {
+        fail(&format!(
+            "{} has SHA-256 {}, expected the digest in {}",
+            input, digest, CHECKSUM_FILE
+        ));
+    }
+
+    let json = String::from_utf8(json).unwrap_or_else(|_| fail("constants are not UTF-8"));
+    let (mut c, mut m) =
+        parse_constants(&json).unwrap_or_else(|e| fail(&format!("{}: {}", input, e)));
+    if c.len() > N_ROUNDS_P.len() {
+        fail("more widths than entries in N_ROUNDS_P");
+    }
+    for (i, (c, m)) in c.iter().zip(&m).enumerate() {
+        let t = i + 2;
+        if m.len() != t || m.iter().any(|row| row.len() != t) {
+            fail(&format!("M[{}] is not a {t}x{t} matrix", i));
+        }
+        if c.len() != (N_ROUNDS_F + N_ROUNDS_P[i]) * t {
+            fail(&format!(
+                "C[{}] has {} constants, expected {}",
+                i,
+                c.len(),
+                (N_ROUNDS_F + N_ROUNDS_P[i]) * t
+            ));
+        }
+    }
+    for (i, &r_p) in N_ROUNDS_P.iter().enumerate().skip(c.len()) {
+        let (c_t, m_t) = src::grain::generate_constants(i + 2, N_ROUNDS_F, r_p, 0);
+        c.push(c_t);
+        m.push(m_t);
+    }
+
+    let outputs = [
+        (STATIC_CONSTANTS, static_constants(&c, &m)),
+        (
+            STATIC_OPTIMIZED_CONSTANTS,
+            static_optimized_constants(&c, &m),
+        ),
+    ];
+    for (path, output) in &outputs {
+        if check {
+            if fs::read_to_string(path).ok().as_deref() != Some(output.as_str()) {
+                fail(&format!("{} is out of date", path));
+            }
+            println!("{} is up to date", path);
+        } else {
+            fs::write(path, output).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+            println!("Generated {}", path);
+        }
+    }
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("error: {}", msg);
+    std::process::exit(1);
+}
+
+fn static_constants(c: &[Vec<Fr>], m: &[Vec<Vec<Fr>>]) -> String {
     let mut output = String::new();
     output.push_str("use ark_bn254::Fr;\n");
-    output.push_str("use ark_ff::MontFp;\n\n");
-    
-    // Generate C constants
-    output.push_str("pub static C_CONSTANTS: &[&[Fr]] = &[\n");
-    for i in 0..c_str.len() {
-        output.push_str("    &[\n");
-        for j in 0..c_str[i].len() {
-            output.push_str(&format!("        MontFp!(\"{}\"),\n", c_str[i][j]));
+    output.push_str("#[allow(unused_imports)]\nuse ark_ff::MontFp;\n\n");
+
+    for (i, c) in c.iter().enumerate() {
+        emit_vector(&mut output, "C", i + 2, c);
+    }
+    emit_table(&mut output, "C_CONSTANTS", "&[&[Fr]]", "C", c.len());
+    for (i, m) in m.iter().enumerate() {
+        emit_matrix(&mut output, "M", i + 2, m);
+    }
+    emit_table(&mut output, "M_CONSTANTS", "&[&[&[Fr]]]", "M", m.len());
+
+    output.push_str(&format!("pub const N_ROUNDS_F: usize = {};\n", N_ROUNDS_F));
+    output.push_str(&format!(
+        "pub static N_ROUNDS_P: &[usize] = &{:?};\n",
+        &N_ROUNDS_P[..c.len()]
+    ));
+    output
+}
+
+// Sparse partial-round constants derived from the parsed ones
+fn static_optimized_constants(c: &[Vec<Fr>], m: &[Vec<Vec<Fr>>]) -> String {
+    let opt: Vec<src::optimized::OptimizedConstants<Fr>> = (0..c.len())
+        .map(|i| {
+            src::optimized::optimize(N_ROUNDS_F, N_ROUNDS_P[i], &c[i], &m[i])
+                .unwrap_or_else(|| fail(&format!("M[{}] has no sparse factorization", i)))
+        })
+        .collect();
+
+    let mut output = String::new();
+    output.push_str("use ark_bn254::Fr;\n");
+    output.push_str("#[allow(unused_imports)]\nuse ark_ff::MontFp;\n\n");
+
+    for (i, o) in opt.iter().enumerate() {
+        emit_vector(&mut output, "OPT_C", i + 2, &o.c);
+    }
+    emit_table(
+        &mut output,
+        "OPT_C_CONSTANTS",
+        "&[&[Fr]]",
+        "OPT_C",
+        opt.len(),
+    );
+    for (i, o) in opt.iter().enumerate() {
+        emit_matrix(&mut output, "OPT_M", i + 2, &o.m_i);
+    }
+    emit_table(
+        &mut output,
+        "OPT_M_CONSTANTS",
+        "&[&[&[Fr]]]",
+        "OPT_M",
+        opt.len(),
+    );
+    for (i, o) in opt.iter().enumerate() {
+        emit_vector(&mut output, "OPT_S", i + 2, &o.s);
+    }
+    emit_table(
+        &mut output,
+        "OPT_S_CONSTANTS",
+        "&[&[Fr]]",
+        "OPT_S",
+        opt.len(),
+    );
+    output.truncate(output.len() - 1);
+    output
+}
+
+// Each width gets its own const, left empty unless the `width-{t}` feature is enabled
+fn emit_width(output: &mut String, name: &str, t: usize, ty: &str, body: &str) {
+    output.push_str(&format!("#[cfg(feature = \"width-{}\")]\n", t));
+    output.push_str(&format!("const {}_{}: {} = &[\n{}];\n", name, t, ty, body));
+    output.push_str(&format!("#[cfg(not(feature = \"width-{}\"))]\n", t));
+    output.push_str(&format!("const {}_{}: {} = &[];\n\n", name, t, ty));
+}
+
+fn emit_vector(output: &mut String, name: &str, t: usize, v: &[Fr]) {
+    let mut body = String::new();
+    for x in v {
+        body.push_str(&format!("    MontFp!(\"{}\"),\n", x.into_bigint()));
+    }
+    emit_width(output, name, t, "&[Fr]", &body);
+}
+
+fn emit_matrix(output: &mut String, name: &str, t: usize, m: &[Vec<Fr>]) {
+    let mut body = String::new();
+    for row in m {
+        body.push_str("    &[\n");
+        for x in row {
+            body.push_str(&format!("        MontFp!(\"{}\"),\n", x.into_bigint()));
         }
-        output.push_str("    ],\n");
+        body.push_str("    ],\n");
+    }
+    emit_width(output, name, t, "&[&[Fr]]", &body);
+}
+
+fn emit_table(output: &mut String, table: &str, ty: &str, name: &str, n: usize) {
+    output.push_str(&format!("pub static {}: {} = &[\n", table, ty));
+    for t in 2..n + 2 {
+        output.push_str(&format!("    {}_{},\n", name, t));
     }
     output.push_str("];\n\n");
-    
-    // Generate M constants
-    output.push_str("pub static M_CONSTANTS: &[&[&[Fr]]] = &[\n");
-    for i in 0..m_str.len() {
-        output.push_str("    &[\n");
-        for j in 0..m_str[i].len() {
-            output.push_str("        &[\n");
-            for k in 0..m_str[i][j].len() {
-                output.push_str(&format!("            MontFp!(\"{}\"),\n", m_str[i][j][k]));
+}
+
+type RoundConstants = Vec<Vec<Fr>>;
+type MdsMatrices = Vec<Vec<Vec<Fr>>>;
+
+fn parse_constants(json: &str) -> Result<(RoundConstants, MdsMatrices), String> {
+    let mut parser = Parser {
+        s: json.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.value()?;
+    parser.skip_ws();
+    if parser.pos != parser.s.len() {
+        return Err("trailing characters".to_string());
+    }
+    let fields = match value {
+        Json::Object(fields) => fields,
+        _ => return Err("expected an object".to_string()),
+    };
+    let get = |key: &str| {
+        fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+            .ok_or(format!("missing \"{}\"", key))
+    };
+
+    let c = get("C")?
+        .array()?
+        .iter()
+        .map(|c| c.array()?.iter().map(Json::field).collect())
+        .collect::<Result<Vec<Vec<Fr>>, String>>()?;
+    let m = get("M")?
+        .array()?
+        .iter()
+        .map(|m| {
+            m.array()?
+                .iter()
+                .map(|row| row.array()?.iter().map(Json::field).collect())
+                .collect()
+        })
+        .collect::<Result<Vec<Vec<Vec<Fr>>>, String>>()?;
+    if c.len() != m.len() {
+        return Err("C and M have different numbers of widths".to_string());
+    }
+    Ok((c, m))
+}
+
+// Only the subset of JSON needed for the constants file: objects, arrays and strings
+enum Json {
+    Object(Vec<(String, Json)>),
+    Array(Vec<Json>),
+    String(String),
+}
+
+impl Json {
+    fn array(&self) -> Result<&[Json], String> {
+        match self {
+            Json::Array(a) => Ok(a),
+            _ => Err("expected an array".to_string()),
+        }
+    }
+
+    fn field(&self) -> Result<Fr, String> {
+        let s = match self {
+            Json::String(s) => s,
+            _ => return Err("expected a string".to_string()),
+        };
+        let x = match s.strip_prefix("0x") {
+            Some(hex) => parse_hex(hex),
+            None => Fr::from_str(s).ok(),
+        };
+        x.ok_or(format!("invalid field element \"{}\"", s))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Fr> {
+    let hex = hex.trim_start_matches('0');
+    if hex.len() > 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut limbs = [0u64; 4];
+    for (i, digit) in hex.bytes().rev().enumerate() {
+        let d = (digit as char).to_digit(16)? as u64;
+        limbs[i / 16] |= d << (4 * (i % 16));
+    }
+    Fr::from_bigint(BigInteger256::new(limbs))
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_ws(&mut self) {
+        while self.pos < self.s.len() && self.s[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), String> {
+        self.skip_ws();
+        if self.s.get(self.pos) != Some(&b) {
+            return Err(format!("expected '{}' at byte {}", b as char, self.pos));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    // Parses a comma separated sequence up to `close`
+    fn list<T>(
+        &mut self,
+        close: u8,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        let mut items = Vec::new();
+        self.skip_ws();
+        if self.s.get(self.pos) == Some(&close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            self.skip_ws();
+            match self.s.get(self.pos) {
+                Some(b',') => self.pos += 1,
+                Some(&b) if b == close => {
+                    self.pos += 1;
+                    return Ok(items);
+                }
+                _ => return Err(format!("unexpected input at byte {}", self.pos)),
             }
-            output.push_str("        ],\n");
         }
-        output.push_str("    ],\n");
     }
-    output.push_str("];\n\n");
-    
-    // Generate rounds constants
-    output.push_str("pub const N_ROUNDS_F: usize = 8;\n");
-    output.push_str("pub static N_ROUNDS_P: &[usize] = &[56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68];\n");
-    
-    // Write to src directory
-    let mut file = File::create("src/static_constants.rs").unwrap();
-    file.write_all(output.as_bytes()).unwrap();
-    
-    println!("Generated src/static_constants.rs");
-    println!("Static constants generation complete!");
-} 
\ No newline at end of file
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_ws();
+        match self.s.get(self.pos) {
+            Some(b'{') => {
+                self.pos += 1;
+                self.list(b'}', |p| {
+                    let key = p.string()?;
+                    p.expect(b':')?;
+                    Ok((key, p.value()?))
+                })
+                .map(Json::Object)
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                self.list(b']', Self::value).map(Json::Array)
+            }
+            Some(b'"') => self.string().map(Json::String),
+            _ => Err(format!("unsupported value at byte {}", self.pos)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let start = self.pos;
+        while self.pos < self.s.len() && self.s[self.pos] != b'"' {
+            if self.s[self.pos] == b'\\' {
+                return Err(format!(
+                    "escape sequences are not supported (byte {})",
+                    self.pos
+                ));
+            }
+            self.pos += 1;
+        }
+        if self.pos == self.s.len() {
+            return Err("unterminated string".to_string());
+        }
+        let s = String::from_utf8_lossy(&self.s[start..self.pos]).into_owned();
+        self.pos += 1;
+        Ok(s)
+    }
+}
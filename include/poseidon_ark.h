@@ -0,0 +1,38 @@
+/* C interface of poseidon-ark's `ffi` feature: the circomlib Poseidon hash over BN254. */
+
+#ifndef POSEIDON_ARK_H
+#define POSEIDON_ARK_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status codes; the values are stable across releases. */
+typedef enum {
+    POSEIDON_OK = 0,
+    /* n is zero or above poseidon_max_inputs() */
+    POSEIDON_INVALID_INPUT_LENGTH = 1,
+    /* an input is not below the BN254 scalar modulus */
+    POSEIDON_NON_CANONICAL_BYTES = 2,
+    POSEIDON_NULL_POINTER = 3,
+    POSEIDON_OTHER = 255,
+} PoseidonStatus;
+
+/* Most inputs a single hash accepts. */
+size_t poseidon_max_inputs(void);
+
+/* Hash n consecutive 32-byte little-endian field elements into a 32-byte little-endian
+ * digest, like Solana's sol_poseidon syscall. out is left untouched on error. */
+PoseidonStatus poseidon_hash_bytes(const uint8_t *inputs, size_t n, uint8_t out[32]);
+
+/* poseidon_hash_bytes with big-endian inputs and digest, like circomlibjs. */
+PoseidonStatus poseidon_hash_bytes_be(const uint8_t *inputs, size_t n, uint8_t out[32]);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,116 @@
+//! Command-line circomlib Poseidon over BN254, for comparing results with circuits,
+//! circomlibjs and on-chain programs.
+//!
+//!     cargo run --features cli --bin poseidon -- [--hex] <command>
+//!
+//! Commands:
+//!
+//!     hash [ELEMENT...]    Poseidon of the elements, or of those read from stdin
+//!     merkle [FILE]        root of the `MerkleTree` over the leaves in FILE, or on stdin
+//!
+//! Elements are decimal or `0x`-prefixed big-endian hex, separated by whitespace, and must
+//! be below the field modulus. Results are printed in decimal, or as `0x`-prefixed hex with
+//! `--hex`.
+
+use std::io::Read;
+use std::process::exit;
+use std::{env, fs, io};
+
+use ark_bn254::Fr;
+use poseidon_ark::merkle::MerkleTree;
+use poseidon_ark::{field_to_bytes_be, parse_field, Poseidon};
+
+const USAGE: &str =
+    "usage: poseidon [--hex] hash [ELEMENT...]\n       poseidon [--hex] merkle [FILE]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let hex = match args.iter().position(|a| a == "--hex") {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    };
+    let (command, rest) = match args.split_first() {
+        Some((c, rest)) => (c.as_str(), rest),
+        None => usage(),
+    };
+
+    let result = match command {
+        "hash" => {
+            let inputs = if rest.is_empty() {
+                parse_all(&read_stdin())
+            } else {
+                rest.iter().map(|s| parse(s)).collect()
+            };
+            Poseidon::new()
+                .hash(&inputs)
+                .unwrap_or_else(|e| fail(&e.to_string()))
+        }
+        "merkle" => {
+            let text = match rest {
+                [] => read_stdin(),
+                [path] => {
+                    fs::read_to_string(path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)))
+                }
+                _ => usage(),
+            };
+            MerkleTree::with_poseidon(Poseidon::new(), &parse_all(&text))
+                .unwrap_or_else(|e| fail(&e.to_string()))
+                .root()
+        }
+        _ => usage(),
+    };
+    println!("{}", format(&result, hex));
+}
+
+fn read_stdin() -> String {
+    let mut text = String::new();
+    io::stdin()
+        .read_to_string(&mut text)
+        .unwrap_or_else(|e| fail(&format!("stdin: {}", e)));
+    text
+}
+
+fn parse_all(text: &str) -> Vec<Fr> {
+    text.split_whitespace().map(parse).collect()
+}
+
+fn parse(s: &str) -> Fr {
+    parse_field(s).unwrap_or_else(|e| fail(&format!("{}: {}", s, e)))
+}
+
+fn format(x: &Fr, hex: bool) -> String {
+    let mut bytes = field_to_bytes_be(x);
+    if hex {
+        let digits: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        return format!("0x{}", digits);
+    }
+    // Repeated division by 10 of the big-endian integer
+    let mut digits = Vec::new();
+    loop {
+        let mut rem = 0u32;
+        for b in bytes.iter_mut() {
+            let v = (rem << 8) | *b as u32;
+            *b = (v / 10) as u8;
+            rem = v % 10;
+        }
+        digits.push(b'0' + rem as u8);
+        if bytes.iter().all(|&b| b == 0) {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("ASCII digits")
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(2)
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("error: {}", msg);
+    exit(1)
+}
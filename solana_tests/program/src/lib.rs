@@ -1,12 +1,22 @@
+//! Reference and benchmark program for `poseidon-ark`.
+//!
+//! Instruction data is one header byte followed by `n` 32-byte little-endian field
+//! elements. The low seven bits of the header are `n - 1` (1 to 24 inputs); with the top
+//! bit clear the program calls `Poseidon::hash_bytes`, which uses the `sol_poseidon`
+//! syscall for up to 12 inputs, and with it set the software permutation. The 32-byte
+//! little-endian digest is returned with `set_return_data`.
+
+use ark_bn254::Fr;
+use ark_ff::Zero;
+use poseidon_ark::{bytes_to_field_strict, field_to_bytes, DecodeMode, Poseidon};
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint,
-    entrypoint::ProgramResult,
-    msg,
-    program_error::ProgramError,
-    pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey,
 };
-use poseidon_ark::Poseidon;
+
+/// Header bit selecting the software permutation
+const SOFTWARE: u8 = 0x80;
+const MAX_INPUTS: usize = 24;
 
 entrypoint!(process_instruction);
 
@@ -15,53 +25,37 @@ pub fn process_instruction(
     _accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    if instruction_data.is_empty() {
+    let (&header, data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let n = (header & !SOFTWARE) as usize + 1;
+    if n > MAX_INPUTS || data.len() != 32 * n {
         return Err(ProgramError::InvalidInstructionData);
     }
-    
+
+    let mut inputs = [&[0u8; 32]; MAX_INPUTS];
+    for (input, chunk) in inputs.iter_mut().zip(data.chunks_exact(32)) {
+        *input = chunk.try_into().expect("32-byte chunks");
+    }
+    let inputs = &inputs[..n];
+
     let poseidon = Poseidon::new();
-    
-    match instruction_data[0] {
-        0 => {
-            // Poseidon hash with 1 input using library (which uses Solana syscall)
-            let input = &instruction_data[1..];
-            if input.len() != 32 {
-                return Err(ProgramError::InvalidInstructionData);
-            }
-            
-            let mut bytes = [0u8; 32];
-            bytes.copy_from_slice(input);
-            
-            match poseidon.hash_bytes(&[&bytes]) {
-                Ok(result) => {
-                    msg!("Poseidon1({} bytes): {:?}", input.len(), &result[..8]);
-                }
-                Err(_) => return Err(ProgramError::InvalidInstructionData),
-            }
-        }
-        1 => {
-            // Poseidon hash with 2 inputs using library (which uses Solana syscall)
-            let input = &instruction_data[1..];
-            if input.len() != 64 {
-                return Err(ProgramError::InvalidInstructionData);
-            }
-            
-            let mut bytes1 = [0u8; 32];
-            let mut bytes2 = [0u8; 32];
-            bytes1.copy_from_slice(&input[0..32]);
-            bytes2.copy_from_slice(&input[32..64]);
-            
-            match poseidon.hash_bytes(&[&bytes1, &bytes2]) {
-                Ok(result) => {
-                    msg!("Poseidon2({} bytes): {:?}", input.len(), &result[..8]);
-                }
-                Err(_) => return Err(ProgramError::InvalidInstructionData),
-            }
+    let digest = if header & SOFTWARE == 0 {
+        poseidon
+            .hash_bytes(inputs, DecodeMode::Strict)
+            .map_err(|_| ProgramError::InvalidInstructionData)?
+            .to_bytes()
+    } else {
+        // `hash_with_initial_state` never takes the syscall
+        let mut elems = [Fr::zero(); MAX_INPUTS];
+        for (x, bytes) in elems.iter_mut().zip(inputs) {
+            *x = bytes_to_field_strict(bytes).map_err(|_| ProgramError::InvalidInstructionData)?;
         }
-        _ => {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-    }
-    
+        let hash = poseidon
+            .hash_with_initial_state(Fr::zero(), &elems[..n])
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        field_to_bytes(&hash)
+    };
+    set_return_data(&digest);
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,164 @@
+//! `ark-crypto-primitives` `CryptographicSponge` over the crate's permutation.
+//!
+//! `DuplexSponge` follows the duplex construction of arkworks' own `PoseidonSponge`:
+//! absorbing after squeezing and squeezing after absorbing both permute first, and the
+//! first `capacity` state elements form the capacity. With the circomlib BN254 instance
+//! it can replace arkworks' sponge in Fiat–Shamir transforms that must match circuits
+//! built on circomlib's constants.
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::sponge::{
+    Absorb, CryptographicSponge, FieldBasedCryptographicSponge, FieldElementSize,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::vec::Vec;
+
+use crate::{Poseidon, PoseidonError, MAX_WIDTH};
+
+/// Permutation and rate/capacity split of a `DuplexSponge`
+#[derive(Clone)]
+pub struct DuplexSpongeConfig<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+    rate: usize,
+    capacity: usize,
+}
+
+impl DuplexSpongeConfig {
+    /// Rate-2, capacity-1 sponge over the circomlib BN254 instance
+    #[cfg(feature = "width-3")]
+    pub fn new() -> DuplexSpongeConfig {
+        DuplexSpongeConfig::with_poseidon(Poseidon::new(), 2, 1).expect("BN254 supports width 3")
+    }
+}
+
+#[cfg(feature = "width-3")]
+impl Default for DuplexSpongeConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> DuplexSpongeConfig<F> {
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        rate: usize,
+        capacity: usize,
+    ) -> Result<DuplexSpongeConfig<F>, PoseidonError> {
+        if rate == 0 || capacity == 0 || !poseidon.supports_width(rate + capacity) {
+            return Err(PoseidonError::InvalidSpongeParameters { rate, capacity });
+        }
+        Ok(DuplexSpongeConfig {
+            poseidon,
+            rate,
+            capacity,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Absorbing(usize),
+    Squeezing(usize),
+}
+
+/// Duplex sponge implementing `CryptographicSponge`
+#[derive(Clone)]
+pub struct DuplexSponge<F: 'static = Fr> {
+    config: DuplexSpongeConfig<F>,
+    state: [F; MAX_WIDTH],
+    mode: Mode,
+}
+
+impl<F: PrimeField> DuplexSponge<F> {
+    fn permute(&mut self) {
+        let t = self.config.rate + self.config.capacity;
+        self.config.poseidon.permute(&mut self.state[..t]);
+    }
+
+    fn absorb_one(&mut self, x: F) {
+        let next = match self.mode {
+            Mode::Absorbing(n) if n < self.config.rate => n,
+            _ => {
+                self.permute();
+                0
+            }
+        };
+        self.state[self.config.capacity + next] += x;
+        self.mode = Mode::Absorbing(next + 1);
+    }
+
+    fn squeeze_one(&mut self) -> F {
+        let next = match self.mode {
+            Mode::Squeezing(n) if n < self.config.rate => n,
+            _ => {
+                self.permute();
+                0
+            }
+        };
+        self.mode = Mode::Squeezing(next + 1);
+        self.state[self.config.capacity + next]
+    }
+}
+
+impl<F: PrimeField> CryptographicSponge for DuplexSponge<F> {
+    type Config = DuplexSpongeConfig<F>;
+
+    fn new(config: &DuplexSpongeConfig<F>) -> Self {
+        DuplexSponge {
+            config: config.clone(),
+            state: [F::zero(); MAX_WIDTH],
+            mode: Mode::Absorbing(0),
+        }
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        for x in input.to_sponge_field_elements_as_vec::<F>() {
+            self.absorb_one(x);
+        }
+    }
+
+    /// Takes the low `(MODULUS_BIT_SIZE - 1) / 8` bytes of each squeezed element
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let usable = ((F::MODULUS_BIT_SIZE - 1) / 8) as usize;
+        let mut bytes = Vec::with_capacity(num_bytes + usable);
+        while bytes.len() < num_bytes {
+            bytes.extend_from_slice(&self.squeeze_one().into_bigint().to_bytes_le()[..usable]);
+        }
+        bytes.truncate(num_bytes);
+        bytes
+    }
+
+    /// Takes the low `MODULUS_BIT_SIZE - 1` bits of each squeezed element
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        let usable = (F::MODULUS_BIT_SIZE - 1) as usize;
+        let mut bits = Vec::with_capacity(num_bits + usable);
+        while bits.len() < num_bits {
+            bits.extend_from_slice(&self.squeeze_one().into_bigint().to_bits_le()[..usable]);
+        }
+        bits.truncate(num_bits);
+        bits
+    }
+
+    /// Elements of the sponge's own field are squeezed directly, as in arkworks' sponge
+    fn squeeze_field_elements<F2: PrimeField>(&mut self, num_elements: usize) -> Vec<F2> {
+        if F::characteristic() == F2::characteristic() {
+            (0..num_elements)
+                .map(|_| cast(&self.squeeze_one()))
+                .collect()
+        } else {
+            self.squeeze_field_elements_with_sizes(
+                &ark_std::vec![FieldElementSize::Full; num_elements],
+            )
+        }
+    }
+}
+
+impl<F: PrimeField> FieldBasedCryptographicSponge<F> for DuplexSponge<F> {
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<F> {
+        (0..num_elements).map(|_| self.squeeze_one()).collect()
+    }
+}
+
+fn cast<F: PrimeField, F2: PrimeField>(x: &F) -> F2 {
+    F2::from_le_bytes_mod_order(&x.into_bigint().to_bytes_le())
+}
@@ -0,0 +1,693 @@
+//! Poseidon constants over the BLS12-381 scalar field.
+//!
+//! Round constants and MDS matrices are the Grain LFSR instances of the Poseidon
+//! reference implementation (x^5, R_F = 8, R_P = 56) for widths 2..=4.
+
+use ark_bls12_381::Fr;
+use ark_ff::MontFp;
+
+use crate::{Poseidon, PoseidonConstants};
+
+pub static BLS12_381_CONSTANTS: PoseidonConstants<Fr> = PoseidonConstants {
+    c: C,
+    m: M,
+    alpha: 5,
+    n_rounds_f: 8,
+    n_rounds_p: &[56, 56, 56],
+    optimized: None,
+};
+
+impl Poseidon<Fr> {
+    /// Poseidon over the BLS12-381 scalar field, hashing 1 to 3 inputs
+    pub const fn new_bls12_381() -> Poseidon<Fr> {
+        Poseidon::with_constants(&BLS12_381_CONSTANTS)
+    }
+}
+
+static C: &[&[Fr]] = &[
+    &[
+        MontFp!("23414800821249851861123738392032430563049924697052533713003446313900782253271"),
+        MontFp!("7965177729960388930876573474537379654913456917035300911374317752264178030350"),
+        MontFp!("18632514059626375351573043313604484993341587819584584273053190726888042942482"),
+        MontFp!("29484429495864976090907976004384813632340194219614389754430235069961869819649"),
+        MontFp!("44513272209122293992922014790746966066660773513663095092066503125834795456564"),
+        MontFp!("35377882259363420277236057598655262769281454122297510128523647842896534365420"),
+        MontFp!("10963509577011381342069297331348201354216056887158583255893554646759184042973"),
+        MontFp!("3394317159046028919720895438344193054491121644614524082570969941529889245453"),
+        MontFp!("39832150264496651894196508989960852895595149517279269138118263304625945435023"),
+        MontFp!("29624948382695537721612401879334835224814106000080072467795318584989148813760"),
+        MontFp!("36702622168552504083988852800008332007070814493086537676684592882375413583026"),
+        MontFp!("20999418232759353073259341513030493578644391312214964341557327728926367605012"),
+        MontFp!("18963383569022421876001940516229471690704858774573963030850938923307457525646"),
+        MontFp!("633056651786448467206026429112767524522902467325013419536745361625323392035"),
+        MontFp!("34941757226421776592311195968429072985728789062627435956464632274882273402574"),
+        MontFp!("16071949902331672905867886009383179022864710357262577729297695792547144287216"),
+        MontFp!("24264030085305900337119102811437473882780912203626277996328870975774797713829"),
+        MontFp!("50530437932549647624593501053927907435994212086769598687362785010196055281086"),
+        MontFp!("22220350774061588670845026308691386325116105083310654408866530137346047201485"),
+        MontFp!("51931202194447801050894447749328343889442721578493760575465344886141635495717"),
+        MontFp!("15865037095800995226074658749910067295452604385314135254160508928908591665440"),
+        MontFp!("23233558849158641603361169221518028321441370016659317190448835853981932687190"),
+        MontFp!("22824024897638747954822953872973846711199485416454737512933905877146679404190"),
+        MontFp!("4756901104017033571860925841943113064093278847897111046679705594810290659975"),
+        MontFp!("25225393314011937706829331712759879324994025833783973285045289779803382071038"),
+        MontFp!("9307310922394374294553296528596970663201974927856386669585988410912242376023"),
+        MontFp!("50214782546614612151322414561759476817931468380236639137107071776329057984381"),
+        MontFp!("2876806457025906954793909231537990914633890677204138200136590873051453400287"),
+        MontFp!("30845693375122854471235155012498691300512965708863790313205732563898127393101"),
+        MontFp!("34378657514131273328713639412852425741312442622713485265270577745364450452428"),
+        MontFp!("21560669142071950152071796743652953318180410978155777819305344600954140520348"),
+        MontFp!("48494134049387838793247023157764048940746828152805146146976697602287228674728"),
+        MontFp!("39273284004440500662046487302545695550194417599669157081142252081635525755523"),
+        MontFp!("34099686681664098158214538620486993692663753770721644617548988368967066894967"),
+        MontFp!("47766341918328818990282667276993684695205231844144447462090808462511109380773"),
+        MontFp!("23259655880868776201403338138784834097811899023856859027058814708842461598184"),
+        MontFp!("42837851887371383349881086619772641096200121090260002202559312044407632848814"),
+        MontFp!("27617036663429195176756553557278940327591686817245572717626561283508629443498"),
+        MontFp!("20103794673162946250202149377048607745413420234522974719929691747191055404146"),
+        MontFp!("50221257303633580423419948826923548578902112741144125507202162224345364591156"),
+        MontFp!("33460633315596970105322966141840331253662515101180106871552375320040348940318"),
+        MontFp!("19255044772713027625275594133235474622593679837251334552759780324344632296130"),
+        MontFp!("19539572349208041507367615449517258713931553408702634230290726293355129635601"),
+        MontFp!("7902248847611076730701140124351951106404208356082305208155245019719645114929"),
+        MontFp!("50835456226993863678412333469708392021278988942689268472290051427408329789726"),
+        MontFp!("36822927802928795741303130937796744673712742080750643418790975126151116425874"),
+        MontFp!("30119573211686815029374809553985857099286504490990483253504512637856706620292"),
+        MontFp!("37730932168830467849053378042693919460008148879235035343875316159893486690531"),
+        MontFp!("38693316719381219846425600184270615618683236316008286673651345233650187723201"),
+        MontFp!("40181963418158999783565577343141450271617210561825508546631452289548319383755"),
+        MontFp!("39227246586570667186102491734213806170019662718052485609342895676648798665067"),
+        MontFp!("37595813774223443911309599423988772304130282012857573143031781730931258048196"),
+        MontFp!("9815933347819605455953665349252256299584012161050035153997845206250630209973"),
+        MontFp!("29273618940582114023106559126873653944355716454656874700472297971784144685767"),
+        MontFp!("23402204203056620415201367195386689819517490939917563862333209702872462754206"),
+        MontFp!("32685367940120430278554542003390657754219249582985233087085807939778920080619"),
+        MontFp!("26948990497716723157195137570634784119998675998609146522708816059534336915597"),
+        MontFp!("9275851318946865695099317080017792665588012717998425917302100825699408020093"),
+        MontFp!("17005595532819343981472054305792244146918134660600767442946529366437429547612"),
+        MontFp!("16110380561207453557430535187405856314070745863543091225772976617360166311669"),
+        MontFp!("10177837889708360920932851041286903585271913217058194436108314334217241107868"),
+        MontFp!("26913404904645152367626796050368277341067327752770331628845566827709859450579"),
+        MontFp!("6344202315374676838670549409794658663115502776426969923897452332922204565515"),
+        MontFp!("32827589578339637561353744969047361553111693426950187366362744659508023384762"),
+        MontFp!("46579195338669666487511837714211091213829024979676863139457882531879618847473"),
+        MontFp!("32055579761321499875690087505321215752431760094050538127429552649255797492393"),
+        MontFp!("24492653620982903602766665910762504881115771775378599132535689207418146338999"),
+        MontFp!("38273307009160509270221951394464929294548391498166056986661191962871367016962"),
+        MontFp!("48295228227337532448789009242936873538765086418610558794621773292316251814452"),
+        MontFp!("51978606006023549020419327370202886698791888967310095892664601771860956413052"),
+        MontFp!("11725199763391234227082332833239765440770321774004359669053883117813261626866"),
+        MontFp!("49329020538870187309556706171241773155123149552770869229674526794844821895511"),
+        MontFp!("46718805946404622201401581985492867028803680412759987016180918526726071548968"),
+        MontFp!("34456720296436736066000873734747289610233460656680735880240763423167184243796"),
+        MontFp!("17783334589829027978911669260501969788807238212100076511483306075972976417215"),
+        MontFp!("42102259724764334024023173718080731380886790968251722686360786542876022952174"),
+        MontFp!("11813125520096899628881559425764469970325431346761752811279829321318912562351"),
+        MontFp!("11727382549418894154726294557527070028984091209571162821735418043058748887955"),
+        MontFp!("43872169891985250817071517064561778195108409353178341586873303732680563627478"),
+        MontFp!("32873086034797351611029886855459671148253841827215132233346169871873303079214"),
+        MontFp!("33717824198887669754461864143240827086565336197373281109960567239243273381159"),
+        MontFp!("44821404860151141551360818743618301326338150674543830662734527326908520744837"),
+        MontFp!("20519310494008724969285918592067250221362704635316723674804521248109726476434"),
+        MontFp!("31857754729404855857763614980246243517471854875882358879143914909447910514358"),
+        MontFp!("50947187945978274128906255162450217745094894130615272838624388889583730317619"),
+        MontFp!("43154879097489701372533583264360754574590794663114287997414472131598170835408"),
+        MontFp!("38308264925404944931667635655720760652363176505877183526491175228042269505478"),
+        MontFp!("48450951787293295937842678999694738020522447801930896850263290997560476685828"),
+        MontFp!("494881728794090480408525894074499049628034519173696548492027210824457844354"),
+        MontFp!("13418935388672953645266739491992398007393031184494911386398849332094332683821"),
+        MontFp!("6854338061509987997072185127960355270473166996972210459377548107981732405966"),
+        MontFp!("45558888090708222507397346827036739380334653629999044326751246069186417631064"),
+        MontFp!("3184463298348132320975507460027359983459871979752256908155057060224421976044"),
+        MontFp!("3045413010317551350277280835299531204345726058172194582042063860266394750695"),
+        MontFp!("37613443741151071698944432475477969701175394933847002651510873313849125972137"),
+        MontFp!("16673146765437798583074779135519678601925792093663467607051266952104201375204"),
+        MontFp!("10657604097433363064680550067238406444249591722637413488931023563001768729250"),
+        MontFp!("23902387584192054608679593092081329327145970129029695357592457200252444747259"),
+        MontFp!("43868866753176383878559897951830217370808256783026735244219440095645200383073"),
+        MontFp!("12803801368249103362689179124002739052507865097021643043607741536940305543895"),
+        MontFp!("15446208485733110540940209387054865258663057001114615637601306517746724739981"),
+        MontFp!("8626835040048675231203734489672858249827193683168735320222933000247403694165"),
+        MontFp!("33864957289885145790249016060805940445339639509937815900018155877266314787421"),
+        MontFp!("37640170512790855120924438142649417845056185759816132351347665221248960834308"),
+        MontFp!("52012952083703373068437105770503563116519558622612231308232689494650903567750"),
+        MontFp!("46811047242581142990831232670747364684710117285207115652462050659199564698450"),
+        MontFp!("23588483859678611025282021870507612229140492100163876829821234997253851644937"),
+        MontFp!("36883878868958586678340084687725805883650222429971544657051141679007132755412"),
+        MontFp!("5618870953181518812203957165252442774252130670247513982435576932689533877511"),
+        MontFp!("2384527457559089532746205745558060501940662271733346711836531010341251931108"),
+        MontFp!("42634022346395288172673733229336911125269172170312271625998216829183532401093"),
+        MontFp!("9795939531435419299816424900878371784908947405265878829495671968929085719321"),
+        MontFp!("48535065388089125445953541104630355256301290089760993004947957319339426219440"),
+        MontFp!("18872817769673939636373997473069063077794512679116672168849916837655827694936"),
+        MontFp!("42369649014671256839963950770648741647642947199284014484754626183208022873946"),
+        MontFp!("9690785864541239777188824471376371248436141559621185241846455846884449627826"),
+        MontFp!("39338381677339910588305800908023663368726903630739868353442383619524279264490"),
+        MontFp!("15686751148118821402303618933908344330223056986705320037971125070492102366560"),
+        MontFp!("34469149506719001398495493092246832574515662237848303492274954362512669663079"),
+        MontFp!("50928889403315065557087916050416354589763602383740639856421746124563651492210"),
+        MontFp!("23396199239787202142363180749112322646374800616113946257490811143157061991837"),
+        MontFp!("2623362708445543827962968866559849053171531607999488614811462603240633570841"),
+        MontFp!("50040464644964968341957734532767324508199553293210304288615542887080051699335"),
+        MontFp!("13169499152616261876850135304311616518994467326994906575534206369189850911250"),
+        MontFp!("34241590539232524539696091363130532858814564960662670703332947413571639120395"),
+        MontFp!("36123837126049282014305669049583291301635252985002665465151947967574246664124"),
+        MontFp!("5692790674331323553568001542989581487941617752200965151287866624926905628782"),
+        MontFp!("21739478634731522439831435471363846291413116163601628783094320730116585914022"),
+    ],
+    &[
+        MontFp!("31267070979841539835461845035900592218724048688848156850416662134392674814818"),
+        MontFp!("15069682083288160432732370099798351618679311578385352494909426280271494064689"),
+        MontFp!("19552372332799130802276417626232312252003112635717995616214163996953876465864"),
+        MontFp!("19043902806963638374160190911613821337150830066549453686591397590151261639780"),
+        MontFp!("28972673025067317663069270863572073326839166365974607290407792537413637176214"),
+        MontFp!("46358852435285817144521265736838145265039097976837298733451665492767803353480"),
+        MontFp!("15514927955085138849903142742338037532910619924819969724732960117103183528083"),
+        MontFp!("49533041141700365126655631109001378131304837150301200397806872251528643994154"),
+        MontFp!("15495014697804903298255235331386641424574726009953953908941032386906465281856"),
+        MontFp!("48679773332359913170453731139323513432216950679553709428698542016143265413134"),
+        MontFp!("22783616522732622893019082906592797185801504915639760629184772959223142258739"),
+        MontFp!("49856876781432927170779498968354510015113362940455788121683215545195112821903"),
+        MontFp!("36823207127907014195688185661509124300880892979552894684628007674849699607594"),
+        MontFp!("32793325341521869918491499158434488994997036272709257383358783091937212392726"),
+        MontFp!("13013282258164837279493662912995836732931467481763657157388243170268288494777"),
+        MontFp!("6981250340229026941600359079713644943364499010076989145230271905447893411428"),
+        MontFp!("24035237617505623260266869665310459241730996321154035943461846954723024413195"),
+        MontFp!("19490150944318489161354799945354283869958593100591069159064256502786081113445"),
+        MontFp!("46485275002276757717910247828363483006197004729024840887588740335741587082044"),
+        MontFp!("42744901072834134696392880816749922233174811837425865272660553891509905326320"),
+        MontFp!("48925578641023140238233925563984058948225729245778470763551929112050022898796"),
+        MontFp!("45885125306816172266496630569408384103499537058602990810785605903635246346303"),
+        MontFp!("51406768969660092835741153429163316469111142401503898903335029488940405426616"),
+        MontFp!("10001310054178566741581108732318364481096445685845154795650911304361521661288"),
+        MontFp!("41385626141777165816180022197987496928764800060770037173373405815673796292478"),
+        MontFp!("40774257558465970727163971030351963836277752898493761934661885479531356658509"),
+        MontFp!("41593363280258445303007931282684826132408532069427470462303087696191124219369"),
+        MontFp!("13265290993276339200466518757285986387873219067370510717658484079003987909088"),
+        MontFp!("46087379692116201289181583941284451567382972808986596055087799167678019157909"),
+        MontFp!("5168875594760045207805167119482723892422371357036786272812920185355882590357"),
+        MontFp!("16404781914610122477169558382353268685340329314879749933213689777663574642030"),
+        MontFp!("47708113255251877127943439411509713986370624181019593607351108719935980863480"),
+        MontFp!("13926627674696294849949462322975705763490000301788840352575629025889041709871"),
+        MontFp!("42567870098898639932509244315898110071280952093823963029501684875809342139776"),
+        MontFp!("5540005465573610713369283553280413619347229650432107922453007392732395383302"),
+        MontFp!("25331413312157336203788915641742781025904079251646304923273846484713954689805"),
+        MontFp!("22209006774619169629238704899737836689832339477498820549083655530919085937469"),
+        MontFp!("5311185660468923011620882201281555732086369217347178075669744505704316314145"),
+        MontFp!("35271792648494182596672373109558490664642876275319642960144409153005284914131"),
+        MontFp!("15478623232978858035827156578649305899475322662360676831830504986059993752553"),
+        MontFp!("34500216308829242835329811368915240013357958201325880074524083281703132874170"),
+        MontFp!("12519402894226670702047228301088292812324011377069509125270413768864485388152"),
+        MontFp!("25265407608799125973911895075576151376017821913856802599988018285045773189436"),
+        MontFp!("51260940924616895921702315283024948868414376484923540548110468177646538465870"),
+        MontFp!("27425326055714195207210257024227984260401076936541461193784131216457004989193"),
+        MontFp!("3768678765872159570334616338154024307389259318183719031698087507626949916634"),
+        MontFp!("25086755002143452066622281417655366233567137314445218198002604928363131851181"),
+        MontFp!("23779491437658546967906847642122014312188103887363792308946119528980860787181"),
+        MontFp!("24955891074778304314273694435921532988165668138889091495806365274009288972837"),
+        MontFp!("27608023756696462562086135196379277578164230293508795841538784900757910244975"),
+        MontFp!("1080226634920093792565119304474407980618655593930293185675690557789787269714"),
+        MontFp!("11469284678695953893354610068791896411465714706458842211112702612541728086569"),
+        MontFp!("30775089646245945317002679594491617362061147180704455819757379593742994734072"),
+        MontFp!("42971858261857846752184609487456573908686298008822592527026892589716642444878"),
+        MontFp!("42094330083704946130104364616514171085286913246514226329675592527938917864407"),
+        MontFp!("8084618519052242994285315279354211170874167518709449374556345882449384664914"),
+        MontFp!("3197111092620571258325644615893031262113157326998479923840014013137131891865"),
+        MontFp!("8936353836030705427988862862233037869944090544819240137805149557956090660037"),
+        MontFp!("3159228639587844516793026797109602325520417644888043082840400709519943335087"),
+        MontFp!("8223951140921425480780788933706256452584667061314395494999262876180139867481"),
+        MontFp!("26278918500147759775494040128510496726909950674693464120779447256751644164722"),
+        MontFp!("29046732525771162766050936150370370465838252929723565893066236477537972125694"),
+        MontFp!("26779879159404780097315149077287766113601664881164369916028591314567870355151"),
+        MontFp!("38183545370450569538095515415584594701591176203719160428388274873088061209035"),
+        MontFp!("34863947964757407053207567143547853150298295915877985119788960367425333393878"),
+        MontFp!("20456223343957287727514213669396629715995897855649159394525218606933035704192"),
+        MontFp!("47933113748671310595634584876947532340489223126676104733451505782820951591065"),
+        MontFp!("12747039712720051884421283218579068027169362545566746338894823615977089768645"),
+        MontFp!("27898851815710187079705561875563256790423231382156200112332228390723271299275"),
+        MontFp!("1082651051840204340034509102399834433911785522401318955077701718631699684491"),
+        MontFp!("7079478478825447313725566949453824123009129220327890795130819256071009925257"),
+        MontFp!("16775457493308856116209955838772682657406283117383038425813679898244265869081"),
+        MontFp!("7470547477163269972171214292244143106200345859222444719977197893052417329024"),
+        MontFp!("48620401238505487989699759866565236219280184671537169026880554793124537255358"),
+        MontFp!("1548460794806212448462544801932001590091559214841471349303252342401635420988"),
+        MontFp!("39148358221924989469840280122600693561302381157280462307295974640212902028183"),
+        MontFp!("37842313473909522221302352447553071698901418823664339039725151507648880548233"),
+        MontFp!("9710379243331339391425756096099609297363072174144732918502957312733827361665"),
+        MontFp!("25557916014812134616741794835185340865176681029029911448118486027379598419824"),
+        MontFp!("27203905164251724386864486493184261124876028946032441782291642547535072250765"),
+        MontFp!("24625437726134665849087299926909516861882286967636758123373386905204291527519"),
+        MontFp!("18399131756860263245632355856085898109846105597980167545439769047691399369221"),
+        MontFp!("39225119191340343726349535131745858225411110255718020629580108116809516481311"),
+        MontFp!("41345479059907761937052664848446319264733527530597731466171458281750485870741"),
+        MontFp!("2006984980145785374239883910267497430622626915726794516080549269061371741375"),
+        MontFp!("42033137593953268469715376914817017109380360758553787395819312609454553405478"),
+        MontFp!("42276813286561171324448571649243177208397885320246748062455890797882475600768"),
+        MontFp!("12396928969388423044049752681574951136377530623963875360936480702464073837248"),
+        MontFp!("37086795539507350548634098417755532846234103923769322864542852803757154140573"),
+        MontFp!("48889708836500101632880693423999155297961018194582841413260268096283076672381"),
+        MontFp!("12114905967386652311865918547204654236955577491397322081448823934460193386797"),
+        MontFp!("10401998325120181088916772218934245595382205296446102357054486991342799209542"),
+        MontFp!("40919933902281057915476927066471701408749622672155924914607644526137041645316"),
+        MontFp!("48422565896168331047971585426766733723576321748443652880593657327789106198925"),
+        MontFp!("49286505295305351262224249170527911128780089178159961854411098177200489303939"),
+        MontFp!("40952139822976350987703461962399460868529181490183893411302281792800312407327"),
+        MontFp!("51355532392187765006740572336195958279116098761939061171893271285987091116763"),
+        MontFp!("26911117626519727234397280470579483704522021687326570539888597777543155036833"),
+        MontFp!("16178563677338850636323817606133246816968202242684869407274198108437675500963"),
+        MontFp!("41681362240161047280027176162972099552391467950572843873880728733870685316468"),
+        MontFp!("29774919239492317318998178257634105819747717627160607652410830684801756096452"),
+        MontFp!("42055767350001186068083363376197904566873240624172390333332141933274583783655"),
+        MontFp!("24609327750431672087261202601111011395169770206984072233495868616129877212531"),
+        MontFp!("19482735308953249432669137408021717720428231082355491611546353416655589592340"),
+        MontFp!("34539155832839590885200587350749923566418440404332529370212489136512925969822"),
+        MontFp!("4764894878114589733354385240482172124912883938531667965986136194711928132233"),
+        MontFp!("42630318283661607210412753740193878425654830130488035399093854844303003268678"),
+        MontFp!("34425639788982814860014610753621297013943954828404656132806226183974111589094"),
+        MontFp!("13114707554944862672438565577661976538565216138843156583255044274084192242063"),
+        MontFp!("26514654721325469812795406739655803678839887227616850563164721996934129593885"),
+        MontFp!("27126849378630612860366678348207722981358217324654958134542524347309943374582"),
+        MontFp!("236991681791069900684736931834346209872667167685372604088058265168904639678"),
+        MontFp!("43727412806586547132103095029760004328836763243858902147488683265438875173902"),
+        MontFp!("50799258892014474647911939197494680572680505776157659025593468325409394031240"),
+        MontFp!("39823468183473107834581398479404652933268407856068665437928623034663245538667"),
+        MontFp!("14751080878640780948793941540266346729539404711251035167406410355870311851517"),
+        MontFp!("49905794531143949719533727856573142765140776302340251276262781820583507345317"),
+        MontFp!("45476990458143505399274700112858353759401711517823703578193003960644752549907"),
+        MontFp!("17063576703247539896769726695823097250024396057514079699081766963840922451736"),
+        MontFp!("2678670726345510540233267914626485703001052639233984097013988075445853551597"),
+        MontFp!("40100808508103671576807835551834450268292156017060053474475149325901477995857"),
+        MontFp!("28227478247801079192371492183561575762821144884795494773821038295704687443211"),
+        MontFp!("5210533892428165644429246427363395072036163009697389936492578843733239349257"),
+        MontFp!("47153800049936752885902746112275040037551145315676560012970705055440961693920"),
+        MontFp!("18565328527355902314638803835821683223077355049529845924128501780833336857204"),
+        MontFp!("34110453545173894478119051184057419743565594329448023022425924120565964019761"),
+        MontFp!("39668566646488078032574160142861077405964573954125755268074111466959659933962"),
+        MontFp!("31334640940175703631074196789373471722932444454900055415625975262477039690639"),
+        MontFp!("44664622299232829736840837833079724586055104682007923414468972223339621394983"),
+        MontFp!("35697189544941570623033812755788840276442824489031614872903167215652897280393"),
+        MontFp!("2141802313494140370073018441574861992652757526683544636831277365623658291589"),
+        MontFp!("30777305207297353514257315934410365723073060235485369702552363116554222576876"),
+        MontFp!("41824290210036994677104425486942026632449557552494176244740456922736493197528"),
+        MontFp!("15903307826353145606870059007907818011919330577771722791409049863634014171629"),
+        MontFp!("21789964138026487246749132534358503414358720591077571155045035726152900260283"),
+        MontFp!("7442079075042857503374740032054228297945408872846551468629659912935727218776"),
+        MontFp!("31821619228305647469090306844639120145066205860782828458889708018718127867318"),
+        MontFp!("11857459086272814010999262004984281116267744158822076580327736381537645075869"),
+        MontFp!("2091190871418370281590070735405568973418605739655753629014523500610411588600"),
+        MontFp!("41888086922615641228706344943270863116841363306579432421165887662430236911581"),
+        MontFp!("51677074583383486901304943732097746556653236979762179122860753270268993875218"),
+        MontFp!("26937997670332816050283264807299604599440310862750972678751522541624260828071"),
+        MontFp!("37044006652663182936174386674627668408792812079084848360011645459936018114650"),
+        MontFp!("29446801197642872330743611217668425590457082924199730325100809240111369650114"),
+        MontFp!("7972639145875100235259305739238229435664723046014927544952331947835699686334"),
+        MontFp!("22990354086345679157773926123388123897037509529865384672393345100211020789940"),
+        MontFp!("27835418420514150922324621870473968951447297351380663051383641541477283087718"),
+        MontFp!("33135497792003262808227965361068408645046006327472982267033003201370563148770"),
+        MontFp!("43841878155728311854840679836421219637215794688068215278421954749142491605464"),
+        MontFp!("26037037662526543183165785736562446815576768327202815158151661638541917290038"),
+        MontFp!("43327437496922372904623474402236759423815159887952005757383346246677913084776"),
+        MontFp!("22733808701222687584037244726901966408364756561054715824812307029085152197642"),
+        MontFp!("6909665327947373477876503326690117023529844275178113772693805280086804275789"),
+        MontFp!("7142936019716449044931405839983402075733572660094931801224445203683818523487"),
+        MontFp!("31856141648661271518171471614318320896876881842081874004435049481042091797765"),
+        MontFp!("39320075652229621189391771049949052411784274633901098393982504738037759602633"),
+        MontFp!("44535517281008712195580778957273298203532050882825005738040578466351013952658"),
+        MontFp!("6195670886424446063977106583870779707000929864809996500832965273157219182343"),
+        MontFp!("23626751267233364310995403567470582688694752693951259636362991013411409154045"),
+        MontFp!("48323287930311500806265452204192043647042552825892586193880581770324140516714"),
+        MontFp!("24117560745911367534934047343436604087458307327200065653417350567748509644008"),
+        MontFp!("11256113935068829705348336396109049816650575048305516437972021145493818303054"),
+        MontFp!("35571449567274993348269117627402024372281979807279137362760818764175989482857"),
+        MontFp!("41065273426297575702227874982188245483502131685341329142960250159047933254131"),
+        MontFp!("32494053407597942585274417967556228132306182389339198673455377186190448080876"),
+        MontFp!("33439982425519964676015704235706844868462437134865770605777641583669833095835"),
+        MontFp!("12038411585482408245866588535507440716321383306582213959649631352380505296649"),
+        MontFp!("6526502100039000285301377000454544755879307550325897610117285417992653989883"),
+        MontFp!("40617372928878335086760011335118504449277596002403813804295571125328045585389"),
+        MontFp!("8205951858827962858297044937178522337758499050416522405544152346456182876452"),
+        MontFp!("37778745447606726195336674945916747887088116054802621227571468229359594581105"),
+        MontFp!("47226393794162709053478981602592821759807116637109687475910390322090212087000"),
+        MontFp!("38031421254080903931419695360900697189585575621927975723438664220804122867402"),
+        MontFp!("258398675175833276592949264479784361720882569973571099775733400234847598810"),
+        MontFp!("22223153731430445043444016839574137508856134848907757325890234838652117711255"),
+        MontFp!("51853248062289316926723317330617956064360564056187433923275105969642173851743"),
+        MontFp!("40430735742859484440196939147996325161360355278137498131058100538532160932299"),
+        MontFp!("38874369899433290751288944011910247867925613285562501365497379645388405238514"),
+        MontFp!("51723510645844434496408727665607711408667477981577838226720557987004040455846"),
+        MontFp!("37548144200223162848117450862546438546147555290257069816532812077464489628950"),
+        MontFp!("22951305417350044093692687017278203980139082646462566498062849603914254938713"),
+        MontFp!("40284623395610288811698496085229863699171393193412887208822553258554235567740"),
+        MontFp!("46941414167102926059813204119025351265541351001023400549609799941085305912449"),
+        MontFp!("26227258298154231476832786489383884166035950963373421526125560917884324210124"),
+        MontFp!("34756539373771160792478077137631603684928688233548866821393796572024611273668"),
+        MontFp!("33266035209479129059314444061163332319271048880536681667167407290061368451961"),
+        MontFp!("30436272453554319177859591817675636370120629606022218083543157569008058848403"),
+        MontFp!("25291782232716612141048957027934887898040440195011622096126796765924895737287"),
+        MontFp!("8505440081055658194053172657484459326491099490258463076621342719248831630197"),
+        MontFp!("48863325277827546152082738512646920707141608882325875129012665929865990228052"),
+        MontFp!("11365018193794909309545286941196906855169274477738507283227421787403768748969"),
+        MontFp!("47918883028082625400256773891122648855076063190786247841633097195909852146629"),
+    ],
+    &[
+        MontFp!("30470571304995235595463659381685019699505426278959289095541009437717507578745"),
+        MontFp!("1370520444679725988228262470913666553238263453957210974636393810919959979221"),
+        MontFp!("41637855569271974110963994248369663785005040003812164334381798821088665880018"),
+        MontFp!("10904314678364808044230213451965080218085737975089994055575542411979256546511"),
+        MontFp!("30100667080035124399931542465269289380452931978633313468137918920672611779870"),
+        MontFp!("47674616656353719647659838076985469635227830608618861608753030780071058084358"),
+        MontFp!("37135886673912085331403062188820011887665781534562336651250441601399153110246"),
+        MontFp!("6349586935615331236579567710640725058404989192089454553534808747777432000567"),
+        MontFp!("8764746332168020320265685146882040707735876837249392529416580448674426737945"),
+        MontFp!("2753995214152641315998608120979458811338081286005609322599429597294278074021"),
+        MontFp!("22087651368285987499179492227258546558776915575568665944406660021708851126718"),
+        MontFp!("18586560579856051660551746178410532388300930801726220293583473786526316231735"),
+        MontFp!("3219384989687415172500344625905168403964311839382894404814053845356587061020"),
+        MontFp!("3969431972379572587155571199555836290596475738381796216269991262070911986908"),
+        MontFp!("6681072794479434321634527872382098993235714362122247914875165392981552688642"),
+        MontFp!("25571738279430898680761101903383154814378626634718212132594299928831200592332"),
+        MontFp!("14951195081217542808583191091966143527501400190276244798784141582090616893850"),
+        MontFp!("6554934978505538932134734303024884835890828784408924826581058267739640748992"),
+        MontFp!("16043658459305955312233658378320470641756824192822111021192660136133301741763"),
+        MontFp!("30127002242654406366323911652409839531486957948401126438587371062005270485465"),
+        MontFp!("5469206939287818887494087595414050903946074496593401839929228620927815547742"),
+        MontFp!("15052501386706020599882126563229866758716220313400866315931952699307126689150"),
+        MontFp!("16397736235714212366409756459423566009306661718453633881576590614957113474670"),
+        MontFp!("29781947864442500887971214839968873106515037887612848687857076945117684549878"),
+        MontFp!("48307238829669060791382885895062637784072668092021325685381463761709970720327"),
+        MontFp!("27055303296734363948563820302369378462318110993895435257425399277854393714338"),
+        MontFp!("12789364635649064696054408181362139458203577736949914512501363359175349596013"),
+        MontFp!("4974768925091180315530469688349560145876078006442609399346127968552493030436"),
+        MontFp!("46037728817580745777320024392808551061696722975764973002576004578434913331004"),
+        MontFp!("23642847112256863921824626464828320950170544767830019950162246263604418341908"),
+        MontFp!("25816059562075803364663984366037288340684968946549488396412915528358975068163"),
+        MontFp!("44928528506676474985818841859695779260454358901054904667167041245633615271627"),
+        MontFp!("5984340577257150194219476524669677068056569168032460881692866992422749959373"),
+        MontFp!("25269611455577922776035009856213089041159237964217618064537581611674840821955"),
+        MontFp!("28507728677301369656652822834706695537913133363551624954314896242376247958035"),
+        MontFp!("7945274132079834101530449801531076631104936788052814984595948662604152543536"),
+        MontFp!("34956059128153221921608329287927848237189464549103613763100680561515874717741"),
+        MontFp!("19628365891313809497596750902837978943828962608377125703304663344695028454095"),
+        MontFp!("39494517569819242437791212826236582466596242648872629767164551454122893853556"),
+        MontFp!("27836694223266583155989163400201173160023062352760945000059173810699310306628"),
+        MontFp!("34831214281166805072534366135166671866493619577597839578262078272050210628416"),
+        MontFp!("5916309528670099066446132553477828769328438692333697616915603152279974329689"),
+        MontFp!("36996077439357007687484803279778415926697506740330313020491165044174879491861"),
+        MontFp!("49175265708447835309439827473714545766019822262148963692807381165857999043172"),
+        MontFp!("33768683041645164146148277234436274522967984595840194786915252559485566297313"),
+        MontFp!("49508626978185470590367121440034384511341104713799915188684523139418451267726"),
+        MontFp!("22556104744504020278873746551928270574902071947335003432178112995253892995905"),
+        MontFp!("5746388943697798704075019607614894992471695727174266423511963777554176222211"),
+        MontFp!("14534795918922206806235409403988677673666382776602070515264645828334875625917"),
+        MontFp!("9335383362627185276799979877799097561991301923269937895509612196472173788880"),
+        MontFp!("45533346487660291849319200570790183765122969235381126648965021210581518495818"),
+        MontFp!("40436434679996370873166999814399803983412215009065932078670779181489922152189"),
+        MontFp!("51866441116705726923216346127426555158701859529082178411477490070550279460567"),
+        MontFp!("46618047317803745148541593797208798491306578287475513291812460445870837866759"),
+        MontFp!("5106964826136471130963814904302232589954964367434983724462627578310059842407"),
+        MontFp!("34438429251158295283729493161101626525833862706587347452660181085251412513108"),
+        MontFp!("43535385529704887077228422015251948660241746508089341108839214274735207645177"),
+        MontFp!("34035767880449610259550339475420389542011422586037747094047640589279252201096"),
+        MontFp!("28182884432704221998797924801002511697488813605515902622706078499354764564963"),
+        MontFp!("27427512859882452474148600504816354349859158946152372063355189981236487231200"),
+        MontFp!("40578596995826695696593082476130721023255275650760976970346076827992645994883"),
+        MontFp!("2497908365207463349748918999005882837399248282842762497518641804295652211712"),
+        MontFp!("23080236203753823521367189700574752738195033273016118767887079224219882828298"),
+        MontFp!("22008177557825548714013013079652933694832981836719244106640938976782875928300"),
+        MontFp!("47294928447998923521324014076897789048249940207923236366871081359591814270830"),
+        MontFp!("13063576081780315381603330418381445226180182907433399394679308045787987352590"),
+        MontFp!("20240940716226487729689425482717905802816967531024801674787006330327633274919"),
+        MontFp!("45065869164012433721176840470170133467617768943084309367517617126882252968461"),
+        MontFp!("29913144010297377538282629681165659650059478787856255551922736312037797026315"),
+        MontFp!("35143601776131354803772865838755421489927807692929952998947047607588878813746"),
+        MontFp!("44079408263890436357508078534771506997065324689055430281228487499499508981758"),
+        MontFp!("46480298445302213980290243816268103215648503120871618532036328727439613526379"),
+        MontFp!("9544331330997447435124467121668874235420308435318676450995468750823261664276"),
+        MontFp!("50092005787032971909099294727140265256514988905039718661882078751920142455043"),
+        MontFp!("37806618598374198840156212585228763048289720059457309979562331362603108174229"),
+        MontFp!("14308956146491308075148954005555924544592891364321982749944572835892226135984"),
+        MontFp!("30244501220588264082418683489572868433089844841546319736879742891158930989708"),
+        MontFp!("38222399948739666432194629046883850948415191612040824587866687629357809728832"),
+        MontFp!("27498626689403765004067833752455973236997852046089320043023311849075169077963"),
+        MontFp!("29327132191098370212840891457762908666375865810726967035743343623972008491024"),
+        MontFp!("17020001715850477393898071880852365340088959550350299994309244131271772089138"),
+        MontFp!("15844781112614200761028238979932846764022593203256895296638159670729660772298"),
+        MontFp!("26017333560401811632837262936567926013863703994918649491776706837631199331502"),
+        MontFp!("38975634234608686767688759004342175892358290095647666870019159028536252553430"),
+        MontFp!("6281080124263669343237170083642108115421057645822521354172989032265370145128"),
+        MontFp!("46259529259248417011451603636408975915689279179183329540424067784880901606427"),
+        MontFp!("32383631651937572829199723158212781522939951480905298149604386187215888385420"),
+        MontFp!("48887854004155330135372340872525615867834693249109116048387415992597667041409"),
+        MontFp!("1655454839116271620575749293234574274222993456067245397034476501783047702987"),
+        MontFp!("50438009192746386491573599403504532375212735259047687062053082186860235946863"),
+        MontFp!("50596178259748710644710488613201561091625758003284944221322227344704022331810"),
+        MontFp!("11302640827401046868813685197817902243008854423815484425332056543009205521370"),
+        MontFp!("44309885703835976940049601828963779802251840761720556693374740273918402647535"),
+        MontFp!("31755725584884824683108308708846110339784130133792678795282015391733276012098"),
+        MontFp!("19578228780438011420624902648651489689332348017075452075789389495641515107526"),
+        MontFp!("1666726261581868906801456292503816513141347546305539848542007723530050849112"),
+        MontFp!("46789428613073784764559820661905340396746310592230609998824183768719102079537"),
+        MontFp!("5599112757051382945777209761927842690976326262291653896197147009061146745988"),
+        MontFp!("2409493590054208958374949729641477680883839845998908515374399694494329108693"),
+        MontFp!("9369028429127203794192971970752143335885775173146694301391342234577831311729"),
+        MontFp!("41391455296316519315383434863390277173783960798132041534329659664311290254089"),
+        MontFp!("24397467974886117679160205937888825720945482611255960634279679963680280751609"),
+        MontFp!("9746726349753403789756808032086478458320047927693804876223210454427848152455"),
+        MontFp!("21482635785009966188378819639648300586364231845630021175074355245099600549611"),
+        MontFp!("29785782372598131347912790563254225046717976185449954822958013085471036026998"),
+        MontFp!("4750785218472448199946495651043918405071094597133085775232454516071918670313"),
+        MontFp!("49401962066339117849248753784554398706053359185290488038762452101566004560528"),
+        MontFp!("8506951532504720413535844871634697448522153784942356613601176599885339116279"),
+        MontFp!("39875284012104279751224451415667455413087111943300775163177837592750238868490"),
+        MontFp!("22505633264778970761396576255816357511261068050218108921438097604965144387230"),
+        MontFp!("11831171017125238400494001160752938431533426772357125798866247136372052031633"),
+        MontFp!("42260406177301868329859295624382893957680661554956574236658516367308496239584"),
+        MontFp!("287378964627352873177644545264407589209245483537768925610934787360661675307"),
+        MontFp!("38649474740806309897142303872611672887202257879863378181836938631941447155337"),
+        MontFp!("21395557280972200352935495524360575478284948876240057549672630565754898687475"),
+        MontFp!("46379007681555317763556400446839909639757804299037000658466714582290985407236"),
+        MontFp!("34972441097555155754078013464247704449175528479252893192760680552615445012801"),
+        MontFp!("44909223020597396561548575912775308090621488857430062407156626561670509845045"),
+        MontFp!("11151574305677326496508847602654325750931882622488859533751156703335791442500"),
+        MontFp!("23176446032156911556681163735964460088367412384346998644831788535866095783907"),
+        MontFp!("25013819107861200871362763274086348118604854366898794646030011194469845796415"),
+        MontFp!("19567114239944088120182151576580746770749741023802236387349349983405056346215"),
+        MontFp!("2106082962224677382939499300262722414994142226749429458574228463349817137446"),
+        MontFp!("21488726403520602433412177403198395506814161155884024548765698473807436437187"),
+        MontFp!("19874389305460885643370670233898930265797432322257101548601144109087150938731"),
+        MontFp!("1626063516190230014066269384047367454995825185479824852734384567195006885600"),
+        MontFp!("43581573334197591889990840911716246975479808268666345487125441053172523036132"),
+        MontFp!("43698285227039724243950399636420747100702078551271024658670684154497346066490"),
+        MontFp!("34835350205978154090858938138031520678449397586635415291484542442551933529673"),
+        MontFp!("10810840117237347488369756214920011498151790731611107208910892886311569789743"),
+        MontFp!("37326885723626525994358476302722768430729430200693563249194614646062355224084"),
+        MontFp!("43594155206580196464620970828001681841704222236633969141271468677975791025713"),
+        MontFp!("46389023133379837328092800598372156744884146045653745289304167072410181328139"),
+        MontFp!("9150715968393290898498549262976154567808374032681082660068962444761451210324"),
+        MontFp!("33116281949819859871861777680907225425872650286783686456190141129142203230879"),
+        MontFp!("8982834703527797861839412363861866770624764672791560560865132583698330639987"),
+        MontFp!("50594177167527957740652660695492511539910893560278818580567932829528059794147"),
+        MontFp!("7578156132851051599628743403125925693771832908105808495208256981767217224493"),
+        MontFp!("27681526531733120476606571351188233778513940164082965303645738300453778753666"),
+        MontFp!("29579295294329594712236231092547100940765537694356324817273857389993408163807"),
+        MontFp!("36076851799465232222174596927451549964173290434019094549309415025670455729211"),
+        MontFp!("24463417913263327143654549068777587238139067649902482248214374567520261816015"),
+        MontFp!("24940531539280306452277732266232662172583427834156412827433855876429941750255"),
+        MontFp!("33481878957858788680845715852007574493706192313506601125498688317640354919880"),
+        MontFp!("3721935602468500474275409904463084130451434647858033328364698929463348406386"),
+        MontFp!("44104407488179434512641750908851416312159133545303893944353822736046511509375"),
+        MontFp!("14760524023936338544565983571830598004344868230350575237710117759391655711145"),
+        MontFp!("9928126117863816642733083939891051939935167916653319936149565996661356913806"),
+        MontFp!("9203309867499608775681898132077626809082089199383545356262185389777976476979"),
+        MontFp!("27058175272637855536119794581494362105972717979599083950780192040500453884860"),
+        MontFp!("13304777388606337567833978771868357294897224276944581464211075155155755981458"),
+        MontFp!("29201349034434125564582673345240847624269947898992363107190834867008408133449"),
+        MontFp!("48696885272480092162592433315127321206813943114606747675303333036648422674142"),
+        MontFp!("11144736070906755782611625123063233295929461829500908937240428238622250764080"),
+        MontFp!("24870627946249178882177389102058934545422736670070762385514286962937053222660"),
+        MontFp!("23564114052540301783116943415774942117663321140998659811977381541292515625754"),
+        MontFp!("6136316109717448476911022204287786386209120824073595158967426769250595091953"),
+        MontFp!("44975712725230779699034848264909483530120612796648932689526824674336265881528"),
+        MontFp!("3091881387868466597386838623831237886049823757718649996447200705078580376115"),
+        MontFp!("49814236837498563279635468060871161876034928905758494051494347939298279849250"),
+        MontFp!("4454686012075561039011926006982290713740289762945264098484664898038754329884"),
+        MontFp!("36177905904242832874814958757026286901642888607495615999798432750180272671535"),
+        MontFp!("50831725255922852633398103617953189994528167346106810913846178936377091966512"),
+        MontFp!("15318541945433775417553530676675532319120180197999278449682125950544603498650"),
+        MontFp!("17146687321154087692378466595707165449687123495891298147092142141505845312206"),
+        MontFp!("8191580993298922860147608305309094640120815917887430996044294592718919389613"),
+        MontFp!("9329061113134462804599113283178896436283404767427401419304710287753840742425"),
+        MontFp!("51736352115027123819666946772972228843963019139872694140180272297508625114952"),
+        MontFp!("7481463502429213881555806863809265523413988113260721713701539910589917212734"),
+        MontFp!("23904473855555189461519282739363920544777287568000883999739923105707820516373"),
+        MontFp!("13013602894042166855542931848610885982581702516124567198498749487460240771110"),
+        MontFp!("8351002998573577697495162211418435742255211700646176831757329133934121781800"),
+        MontFp!("17480710079554125542441006901285024138060122999903968018230261587289407026837"),
+        MontFp!("17075950064777286702673643721856460221311122428907993982312895896590011766349"),
+        MontFp!("19698319689449804965374271249898073014616836315392340065702550277626218800048"),
+        MontFp!("33005932704374144229920752955272390439937502340109904277477469710205762718253"),
+        MontFp!("7772411579765951090672122176465008797185279611302662319745737295929804239087"),
+        MontFp!("11889360987871649601551882899928875042758776607466285118280758002345997830258"),
+        MontFp!("3705415384153857368096223909370609747605144943328000903237748146312742447021"),
+        MontFp!("33079180948924287399905620575076914523028076277844517316581785919649868547453"),
+        MontFp!("39238260936895350257289839726475230219289057036208157052221714355884742081656"),
+        MontFp!("23526004781773596239317400546871333426503416151531259845147457745331749488557"),
+        MontFp!("872976432811155781085699703688271826762824288634904331353135555466071090075"),
+        MontFp!("30183469133727943436505144253897604311665763437749758181992032945736583399052"),
+        MontFp!("18193909225449607731799697042688797027362722499446651268234233463222984822633"),
+        MontFp!("39022198487234570311934457546134250801791394753082345267384771830980067794181"),
+        MontFp!("25347928293935187070195211873894766464931012354412211300648381435654666407204"),
+        MontFp!("19755146966666705788910044185365588087096768032209613831936051669945448406694"),
+        MontFp!("5859978328786901282193761033880070312121687127979056560520976771776012204593"),
+        MontFp!("10748246348071352004097176712330632857291363529103927381613829497545445222625"),
+        MontFp!("25428865798033511134904899547874206248135745722712552731076184474713048816325"),
+        MontFp!("31193013542790318866567149696268674561949006595540977989263667171479826199684"),
+        MontFp!("47786845254110846308750977815961290964999623617096740913854371515304312458812"),
+        MontFp!("6345058700678451451474721364612644686291088373197518117247461922114451362832"),
+        MontFp!("32205925855066707236039656080230269926872437824939507388101636079240574904448"),
+        MontFp!("49528500818261659931048090307321627614640838953791077345803928093852807423471"),
+        MontFp!("43659385217526266791609595021481033309327578965260703464551868615342123316140"),
+        MontFp!("4983946948258244665330639819946746710881367075183909387124249985242631313187"),
+        MontFp!("15683410550117040699830973934425984195796628164705176838337887072093005102304"),
+        MontFp!("26636374903860983787041966592859435803698975896438430107387714615782002161044"),
+        MontFp!("28233149857965597202548710587300858295565407021256435545087048718404029524094"),
+        MontFp!("1109333099910515686066827787339902267447685002446284961778913417692592974833"),
+        MontFp!("9253037108333354268176893380261355448380284264319339629810114358582581436310"),
+        MontFp!("8832195681577639191418280580861396815223235853776583022923869509651957972963"),
+        MontFp!("13417889808975748181538839685324907571136815284913626419487608797684204627591"),
+        MontFp!("8258410361054407490750515862908656279121399225427353480613536653773199887103"),
+        MontFp!("9507351959090265807864525456853284133940932292804999257963948075500872710607"),
+        MontFp!("7718879057666406454245502916422647781482542525726905830189942612531048085025"),
+        MontFp!("22340398882451260022615758120944911465920029942029665778654071297602879638857"),
+        MontFp!("50972402812030663249786291161547348788432086430730211248104607803831635077334"),
+        MontFp!("16469797442999099723607174109015439380006077484826771467979480922177784283864"),
+        MontFp!("43154195839167411320690851701439386389800730416918542024929523788325896785698"),
+        MontFp!("22857940224538172357102129975275338997933005152289183852747623491940528792114"),
+        MontFp!("43023257447961742342424394555479041351559524096762575990490013999210296685024"),
+        MontFp!("40570180419770156020037864233903508973890175994203343282311310591272767144091"),
+        MontFp!("31331146971864439593237678248229707396586727110327542366836868816533413138330"),
+        MontFp!("32397042479169228611116028339659191652447992108101384542456511184471915765969"),
+        MontFp!("28142987505329131998964365677387966775881465840652291779820915108092423574179"),
+        MontFp!("10960395800643098918952216752058689900258345443973501556311011458138797773075"),
+        MontFp!("25029465373010213115037516220461829171851623829126754127456957555936216767861"),
+        MontFp!("29900584232884803922359827065540436740337401181580965048229228782898874260736"),
+        MontFp!("23641171984152300212750271269641428908028196195209313587740831076572785003081"),
+        MontFp!("2344924412149728727359298993358291083972972161029118219844447056909802649589"),
+        MontFp!("27732246361414808510800105623244220999575948692729933039358122231978060696311"),
+        MontFp!("16095093230599114084437216924729069586404843861744829375971615465966955225309"),
+        MontFp!("3295336389769061462166494005913072151119130898184831584973073657686928230528"),
+        MontFp!("51858366209918577319524176532719890333724118247089011174102937457714709340277"),
+        MontFp!("195796946742617127687672210696189716578713722188348948884505364959912445558"),
+        MontFp!("9374247208038257594741465202689484503296451728954324710551988713532193732644"),
+        MontFp!("10727773120486838979089580862042142030433744334234879247497582539431870018024"),
+        MontFp!("8350613923891547934559799726383813628352222864626316704240749679088863466077"),
+        MontFp!("9693902666656303608929913487510127256111716875482188778379262228306244757998"),
+        MontFp!("9345277687408427711681424759385633099249988663052404484813576985013666314567"),
+        MontFp!("23584157439605349666865128560222502259879306939450450490348805071695367322019"),
+        MontFp!("47695205005278807598236624729942781604815212495933795750303986937291634839045"),
+        MontFp!("29863000552347166611836594190581929332447053589485907262624714132115994324424"),
+        MontFp!("7714913014073522539597004492368959398970741926943700214687695082971872201312"),
+        MontFp!("18809426280749616425280716491035589720305312414990527785909546592963158964901"),
+        MontFp!("21434589496312620237248425051135790079786030041568259058625330920419964009338"),
+        MontFp!("4110092154188680597255898662211488790253302387770024693109739981543060705977"),
+        MontFp!("17812614221813611873505355143702971110454556404760183975640689638497877713"),
+        MontFp!("10770381837050558913849551632018116909204377294339150623407793497997880022589"),
+        MontFp!("30378891669998135563481296575020566541910784880248198256969428253008915155268"),
+        MontFp!("17948591912327775478764714953388009242629974017518162245393457489636839428338"),
+        MontFp!("3217711550190844364754938996682178457925813754374337362122259938503701223514"),
+        MontFp!("43204970838383471860463433524942487162469485945918099762406989553882959213310"),
+        MontFp!("17451034796031199065423201816962343060679136695245178062038613568095103541289"),
+        MontFp!("41427386310570365112487648780379384999034761353846562155788279955498938363037"),
+        MontFp!("10191181251603844246548191313643393903858957062299334476512715147322199150709"),
+        MontFp!("35060238016154253353094702281634853449599733497857991815042056783066515864254"),
+        MontFp!("19908549411457324950018053787613807577647937881036226924984564655676161533944"),
+        MontFp!("23982442404642540877935180667772707427047811736260845110630305866858053989496"),
+        MontFp!("42208166637174186461509802865423789900086869022299207291705165521345223085570"),
+        MontFp!("8801172789462661502531253780677460369462564389801914778759702073476275150594"),
+        MontFp!("16230680055445324689858676497347378166143176489164118311557124553683754803626"),
+        MontFp!("4458956708241845925140619860655725914597025899331632144147060195988887036062"),
+    ],
+];
+
+static M: &[&[&[Fr]]] = &[
+    &[
+        &[
+            MontFp!("17522435315831737489501815865530957605307281571700251851950000506674770112125"),
+            MontFp!("34432080677364704349826577775533415494786529421103693046903965285120437844260"),
+        ],
+        &[
+            MontFp!("16013240619325677721700975714189555246612401606391224231917910274643427921202"),
+            MontFp!("13634149354847127809176140824491472261508984630776148602084010480717518443255"),
+        ],
+    ],
+    &[
+        &[
+            MontFp!("14977649599106758538466663697952640531279791477868050746366077143134790793761"),
+            MontFp!("5045729558877059250916334190704842341388565927674799850173430094190068729282"),
+            MontFp!("1979214402110400782122876591837508470055790341460672312870705317993475500999"),
+        ],
+        &[
+            MontFp!("14084541615959058280384279629813803116513502611787810820985051838651328739130"),
+            MontFp!("41666632947341909769152061848030606807349822045401544360043682063690071106974"),
+            MontFp!("34921040054459346230943477686368502015429973021495503320742463158245221506683"),
+        ],
+        &[
+            MontFp!("40643579965451559592298690207774902996162021888437477644702929601767974748873"),
+            MontFp!("33704311568513766675619287591616476835868220293320432698334323643265868139753"),
+            MontFp!("46055233707892508216960286286895760015631965134827963950282664077325297690747"),
+        ],
+    ],
+    &[
+        &[
+            MontFp!("51513445440885542661124724379861585879844381657133359810724674195704442276654"),
+            MontFp!("19019665472855706517036984667973231498181166052268711880642745946838556112086"),
+            MontFp!("30379042337171105496048453755761780807671678629466078809971975539484429872902"),
+            MontFp!("37170861369564781654444152853242964562117041323113690106651764849501738685414"),
+        ],
+        &[
+            MontFp!("25418369360722501912638996759654098317735052749697822077520303843655934636955"),
+            MontFp!("19434842819338005492800025658129207492766053599342799890355401957332256827804"),
+            MontFp!("47037378652113204549742672410996908651445086052146079991202232709405433673934"),
+            MontFp!("56432420774773408489518883214861160874199290532882706552529105691359926590"),
+        ],
+        &[
+            MontFp!("32905415560783004903210014438116659793841429737372860127215102386158369385365"),
+            MontFp!("12723597102098163073832998497851188550553827068221118187346824077102248679397"),
+            MontFp!("29754293623585602606082915326921468004422435414165909465016361532226285109305"),
+            MontFp!("7764756588031335327984904468349044034613188095047173504449143545782689255629"),
+        ],
+        &[
+            MontFp!("21983297930103516115954590580135029593737269245483422630724850790705651077794"),
+            MontFp!("46055171764194086454444909687347187645359120613439622119881989354866828312103"),
+            MontFp!("18956063476768453226347452524406661907334934455868366327969515164741329464413"),
+            MontFp!("49900644058159589743345291032817500608082724095853261893141752628825512771701"),
+        ],
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_permutation;
+
+    // Poseidon reference implementation (zkhash `POSEIDON_BLS_{2,3}_PARAMS`)
+    #[test]
+    fn kats() {
+        let poseidon = Poseidon::new_bls12_381();
+        assert_permutation(
+            &poseidon,
+            &[
+                "0x1dc37ce34aeee058292bb73bff9acffce73a8a92f3d6d1daa8b77d9516b5c837",
+                "0x534cc8001b9c21da25d62749e136ea3d702651ba129f0d5ed7847cf81bc8b042",
+            ],
+        );
+        assert_permutation(
+            &poseidon,
+            &[
+                "0x200e6982ac00df8fa65cef1fde9f21373fdbbfd98f2df1eb5fa04f3302ab0397",
+                "0x2233c9a40d91c1f643b700f836a1ac231c3f3a8d438ad1609355e1b7317a47e5",
+                "0x2eae6736db3c086ad29938869dedbf969dd9804a58aa228ec467b7d5a08dc765",
+            ],
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! borsh encodings of the public value types.
+//!
+//! Field elements are written as their canonical 32-byte little-endian form and rejected
+//! on decoding when not canonical; sequences, options and integers follow borsh's own
+//! layout, so the encodings match a `#[derive(BorshSerialize)]` struct holding `[u8; 32]`
+//! in place of each field element.
+
+use alloc::vec::Vec;
+use ark_ff::PrimeField;
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::chain::PoseidonChain;
+use crate::incremental::IncrementalMerkleTree;
+use crate::merkle::{MerklePath, NaryMerkleProof};
+use crate::smt::SmtProof;
+use crate::{bytes_to_field_strict, field_to_bytes, PoseidonDigest};
+
+fn write_field<F: PrimeField, W: Write>(x: &F, writer: &mut W) -> Result<()> {
+    writer.write_all(&field_to_bytes(x))
+}
+
+fn read_field<F: PrimeField, R: Read>(reader: &mut R) -> Result<F> {
+    let bytes = <[u8; 32]>::deserialize_reader(reader)?;
+    bytes_to_field_strict(&bytes)
+        .map_err(|_| Error::new(ErrorKind::InvalidData, "non-canonical field element"))
+}
+
+fn write_fields<F: PrimeField, W: Write>(v: &[F], writer: &mut W) -> Result<()> {
+    (v.len() as u32).serialize(writer)?;
+    v.iter().try_for_each(|x| write_field(x, writer))
+}
+
+// Grows the vector as elements arrive, so a corrupt length cannot force a huge allocation
+fn read_fields<F: PrimeField, R: Read>(reader: &mut R) -> Result<Vec<F>> {
+    let len = u32::deserialize_reader(reader)?;
+    (0..len).map(|_| read_field(reader)).collect()
+}
+
+fn write_opt_field<F: PrimeField, W: Write>(x: &Option<F>, writer: &mut W) -> Result<()> {
+    x.map(|x| field_to_bytes(&x)).serialize(writer)
+}
+
+fn read_opt_field<F: PrimeField, R: Read>(reader: &mut R) -> Result<Option<F>> {
+    match u8::deserialize_reader(reader)? {
+        0 => Ok(None),
+        1 => Ok(Some(read_field(reader)?)),
+        _ => Err(Error::new(ErrorKind::InvalidData, "invalid option tag")),
+    }
+}
+
+impl<F: PrimeField> BorshSerialize for MerklePath<F> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_fields(&self.siblings, writer)?;
+        self.path_indices.serialize(writer)
+    }
+}
+
+impl<F: PrimeField> BorshDeserialize for MerklePath<F> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Ok(MerklePath {
+            siblings: read_fields(reader)?,
+            path_indices: Vec::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl<F: PrimeField> BorshSerialize for NaryMerkleProof<F> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.index as u64).serialize(writer)?;
+        (self.siblings.len() as u32).serialize(writer)?;
+        self.siblings
+            .iter()
+            .try_for_each(|level| write_fields(level, writer))
+    }
+}
+
+impl<F: PrimeField> BorshDeserialize for NaryMerkleProof<F> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let index = u64::deserialize_reader(reader)?
+            .try_into()
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "index out of range"))?;
+        let levels = u32::deserialize_reader(reader)?;
+        let siblings = (0..levels)
+            .map(|_| read_fields(reader))
+            .collect::<Result<_>>()?;
+        Ok(NaryMerkleProof { index, siblings })
+    }
+}
+
+impl<F: PrimeField> BorshSerialize for SmtProof<F> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_fields(&self.siblings, writer)?;
+        write_opt_field(&self.value, writer)?;
+        self.other
+            .map(|(k, v)| (field_to_bytes(&k), field_to_bytes(&v)))
+            .serialize(writer)
+    }
+}
+
+impl<F: PrimeField> BorshDeserialize for SmtProof<F> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let siblings = read_fields(reader)?;
+        let value = read_opt_field(reader)?;
+        let other = match u8::deserialize_reader(reader)? {
+            0 => None,
+            1 => Some((read_field(reader)?, read_field(reader)?)),
+            _ => return Err(Error::new(ErrorKind::InvalidData, "invalid option tag")),
+        };
+        Ok(SmtProof {
+            siblings,
+            value,
+            other,
+        })
+    }
+}
+
+/// Laid out as the filled subtrees, the zero hashes, the root and the number of leaves,
+/// `(2 * DEPTH + 1) * 32 + 8` bytes in total
+impl<const DEPTH: usize, F: PrimeField> BorshSerialize for IncrementalMerkleTree<DEPTH, F> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let (filled_subtrees, zeros, root, next_index) = self.raw_parts();
+        filled_subtrees
+            .iter()
+            .chain(zeros)
+            .chain([root])
+            .try_for_each(|x| write_field(x, writer))?;
+        next_index.serialize(writer)
+    }
+}
+
+impl<const DEPTH: usize, F: PrimeField> BorshDeserialize for IncrementalMerkleTree<DEPTH, F> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut filled_subtrees = [F::zero(); DEPTH];
+        for x in filled_subtrees.iter_mut() {
+            *x = read_field(reader)?;
+        }
+        let mut zeros = [F::zero(); DEPTH];
+        for x in zeros.iter_mut() {
+            *x = read_field(reader)?;
+        }
+        let root = read_field(reader)?;
+        let next_index = u64::deserialize_reader(reader)?;
+        IncrementalMerkleTree::from_raw_parts(filled_subtrees, zeros, root, next_index).ok_or_else(
+            || {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    "leaf count exceeds the tree capacity",
+                )
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> BorshSerialize for PoseidonChain<F> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let (head, len) = self.state();
+        write_field(&head, writer)?;
+        len.serialize(writer)
+    }
+}
+
+impl<F: PrimeField> BorshDeserialize for PoseidonChain<F> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let head = read_field(reader)?;
+        Ok(PoseidonChain::from_state(
+            head,
+            u64::deserialize_reader(reader)?,
+        ))
+    }
+}
+
+impl BorshSerialize for PoseidonDigest {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+}
+
+impl BorshDeserialize for PoseidonDigest {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        <[u8; 32]>::deserialize_reader(reader).map(PoseidonDigest::new)
+    }
+}
@@ -0,0 +1,173 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{Poseidon, PoseidonError};
+
+/// Capacity tag of `PoseidonChain::checkpoint`, which keeps checkpoint digests apart from
+/// chain links of the same width
+pub const CHECKPOINT_DOMAIN: u64 = 1;
+
+/// Hash chain `h_{i+1} = Poseidon([h_i, x_i])` over appended entries, starting from a
+/// genesis head `h_0` (zero by default).
+///
+/// The state is the head and the number of entries, exported by `state` and restored by
+/// `from_state`, so a log kept in a fixed-size Solana account can be replayed and
+/// continued off-chain. `checkpoint` commits to both, so a checkpoint taken at entry `i`
+/// cannot be mistaken for the head at another position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct PoseidonChain<F = Fr> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elem"))]
+    head: F,
+    len: u64,
+}
+
+impl Default for PoseidonChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonChain {
+    pub fn new() -> PoseidonChain {
+        PoseidonChain::with_genesis(Fr::from(0u64))
+    }
+
+    /// Append `entry`, returning its index
+    pub fn append(&mut self, entry: Fr) -> Result<u64, PoseidonError> {
+        self.append_with(&Poseidon::new(), entry)
+    }
+
+    /// Append every entry in order
+    pub fn extend(&mut self, entries: &[Fr]) -> Result<(), PoseidonError> {
+        self.extend_with(&Poseidon::new(), entries)
+    }
+
+    /// `Poseidon([head, len])` under `CHECKPOINT_DOMAIN`
+    pub fn checkpoint(&self) -> Result<Fr, PoseidonError> {
+        self.checkpoint_with(&Poseidon::new())
+    }
+}
+
+impl<F: PrimeField> PoseidonChain<F> {
+    /// Empty chain with head `genesis`
+    pub fn with_genesis(genesis: F) -> PoseidonChain<F> {
+        PoseidonChain::from_state(genesis, 0)
+    }
+
+    /// Chain resumed from the head and length returned by `state`
+    pub fn from_state(head: F, len: u64) -> PoseidonChain<F> {
+        PoseidonChain { head, len }
+    }
+
+    /// The head and the number of entries
+    pub fn state(&self) -> (F, u64) {
+        (self.head, self.len)
+    }
+
+    /// Append `entry` hashing with `poseidon`, which has to support two inputs and be the
+    /// same instance for the whole chain; returns the index of the entry
+    pub fn append_with(&mut self, poseidon: &Poseidon<F>, entry: F) -> Result<u64, PoseidonError> {
+        let index = self.len;
+        self.head = poseidon.hash(&[self.head, entry])?;
+        self.len = index + 1;
+        Ok(index)
+    }
+
+    /// Append every entry in order; on an error the chain is left unchanged
+    pub fn extend_with(
+        &mut self,
+        poseidon: &Poseidon<F>,
+        entries: &[F],
+    ) -> Result<(), PoseidonError> {
+        let mut chain = *self;
+        for entry in entries {
+            chain.append_with(poseidon, *entry)?;
+        }
+        *self = chain;
+        Ok(())
+    }
+
+    /// Digest binding the head to the number of entries, `Poseidon([head, len])` with
+    /// `CHECKPOINT_DOMAIN` in the capacity element
+    pub fn checkpoint_with(&self, poseidon: &Poseidon<F>) -> Result<F, PoseidonError> {
+        poseidon.hash_with_domain(F::from(CHECKPOINT_DOMAIN), &[self.head, F::from(self.len)])
+    }
+
+    /// Current head `h_len`
+    pub fn head(&self) -> F {
+        self.head
+    }
+
+    /// Number of appended entries
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(all(test, feature = "width-3"))]
+mod tests {
+    use super::*;
+
+    fn entries() -> [Fr; 3] {
+        [Fr::from(11u64), Fr::from(22u64), Fr::from(33u64)]
+    }
+
+    #[test]
+    fn append_links_entries_in_order() {
+        let [a, b, _] = entries();
+        let mut chain = PoseidonChain::new();
+        assert_eq!(chain.append(a).unwrap(), 0);
+        assert_eq!(chain.append(b).unwrap(), 1);
+        let h1 = crate::hash(&[Fr::from(0u64), a]).unwrap();
+        assert_eq!(chain.head(), crate::hash(&[h1, b]).unwrap());
+        assert_eq!(chain.len(), 2);
+
+        let mut swapped = PoseidonChain::new();
+        swapped.extend(&[b, a]).unwrap();
+        assert_ne!(swapped.head(), chain.head());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn extend_with_leaves_the_chain_unchanged_on_error() {
+        // A width-2 instance cannot hash a link
+        let (c, m) = crate::grain::generate_constants::<Fr>(2, 8, 56, 0);
+        let poseidon = Poseidon::with_params(crate::PoseidonParams::new(8, 56, 5, c, m).unwrap());
+        let mut chain = PoseidonChain::with_genesis(Fr::from(5u64));
+        chain.extend(&entries()[..1]).unwrap();
+        let before = chain;
+        assert!(chain.extend_with(&poseidon, &entries()).is_err());
+        assert_eq!(chain, before);
+    }
+
+    #[test]
+    fn resumes_from_exported_state() {
+        let [a, b, c] = entries();
+        let mut chain = PoseidonChain::new();
+        chain.extend(&[a, b]).unwrap();
+        let (head, len) = chain.state();
+        let mut resumed = PoseidonChain::from_state(head, len);
+        assert_eq!(resumed, chain);
+
+        chain.append(c).unwrap();
+        assert_eq!(resumed.append(c).unwrap(), 2);
+        assert_eq!(resumed.head(), chain.head());
+    }
+
+    #[test]
+    fn checkpoint_is_domain_separated() {
+        let mut chain = PoseidonChain::new();
+        chain.extend(&entries()).unwrap();
+        let plain = crate::hash(&[chain.head(), Fr::from(chain.len())]).unwrap();
+        assert_ne!(chain.checkpoint().unwrap(), plain);
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::{Poseidon, PoseidonError};
+
+/// Poseidon authenticated encryption (Khovratovich's "Encryption with Poseidon", as
+/// implemented by MACI and zk-kit's `poseidon-cipher`).
+///
+/// The width-4 permutation is started from `[0, key.0, key.1, nonce + len * 2^128]`; each
+/// block of three message elements is added to `state[1..4]` after a permutation and the
+/// resulting state elements form the ciphertext. The message is zero padded to a multiple
+/// of three and a final permutation supplies `state[1]` as the authentication tag, so a
+/// ciphertext is `3 * ceil(len / 3) + 1` elements.
+pub struct PoseidonCipher<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+}
+
+#[cfg(feature = "width-4")]
+impl Default for PoseidonCipher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonCipher {
+    #[cfg(feature = "width-4")]
+    pub fn new() -> PoseidonCipher {
+        PoseidonCipher::with_poseidon(Poseidon::new()).expect("BN254 supports width 4")
+    }
+}
+
+impl<F: PrimeField> PoseidonCipher<F> {
+    /// Cipher over the permutation of the given instance, which has to support width 4
+    /// over a field of at least 192 bits
+    pub fn with_poseidon(poseidon: Poseidon<F>) -> Result<PoseidonCipher<F>, PoseidonError> {
+        if F::MODULUS_BIT_SIZE < 192 {
+            return Err(PoseidonError::UnsupportedField);
+        }
+        if !poseidon.supports_width(4) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: 3,
+                max: poseidon.max_inputs(),
+            });
+        }
+        Ok(PoseidonCipher { poseidon })
+    }
+
+    /// Encrypt `msg` under `key`; `nonce` must be below 2^128 and never reused with the
+    /// same key
+    pub fn encrypt(&self, key: (F, F), nonce: F, msg: &[F]) -> Result<Vec<F>, PoseidonError> {
+        let mut state = self.initial_state(key, nonce, msg.len())?;
+        let mut ct = Vec::with_capacity(msg.len().div_ceil(3) * 3 + 1);
+        for block in msg.chunks(3) {
+            self.permute(&mut state);
+            for (i, s) in state[1..4].iter_mut().enumerate() {
+                *s += block.get(i).copied().unwrap_or_else(F::zero);
+            }
+            ct.extend_from_slice(&state[1..4]);
+        }
+        self.permute(&mut state);
+        ct.push(state[1]);
+        Ok(ct)
+    }
+
+    /// Decrypt a ciphertext of a `len`-element message, checking the padding and the
+    /// authentication tag
+    pub fn decrypt(
+        &self,
+        key: (F, F),
+        nonce: F,
+        ct: &[F],
+        len: usize,
+    ) -> Result<Vec<F>, PoseidonError> {
+        let blocks = len.div_ceil(3);
+        if ct.len() != blocks * 3 + 1 {
+            return Err(PoseidonError::InvalidInputLength {
+                got: ct.len(),
+                max: blocks * 3 + 1,
+            });
+        }
+        let mut state = self.initial_state(key, nonce, len)?;
+        let mut msg = Vec::with_capacity(blocks * 3);
+        for block in ct[..blocks * 3].chunks_exact(3) {
+            self.permute(&mut state);
+            for (s, c) in state[1..4].iter_mut().zip(block) {
+                msg.push(*c - *s);
+                *s = *c;
+            }
+        }
+        if msg[len..].iter().any(|x| !x.is_zero()) {
+            return Err(PoseidonError::AuthenticationFailed);
+        }
+        self.permute(&mut state);
+        if state[1] != ct[blocks * 3] {
+            return Err(PoseidonError::AuthenticationFailed);
+        }
+        msg.truncate(len);
+        Ok(msg)
+    }
+
+    fn initial_state(&self, key: (F, F), nonce: F, len: usize) -> Result<[F; 4], PoseidonError> {
+        let nonce_bits = nonce.into_bigint().num_bits();
+        if nonce_bits > 128 {
+            return Err(PoseidonError::InvalidNonce);
+        }
+        let two_128 = F::from(u128::MAX) + F::one();
+        Ok([
+            F::zero(),
+            key.0,
+            key.1,
+            nonce + F::from(len as u64) * two_128,
+        ])
+    }
+
+    fn permute(&self, state: &mut [F; 4]) {
+        self.poseidon.permute(state);
+    }
+}
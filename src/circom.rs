@@ -0,0 +1,143 @@
+//! circom input JSON for Merkle inclusion proofs.
+//!
+//! circomlib-style `MerkleTreeInclusionProof` templates take a proof as the signals
+//! `root`, `leaf`, `pathElements[depth]` and `pathIndices[depth]`, and snarkjs and the
+//! circom witness generators read signal values from a JSON object, field elements as
+//! decimal strings. `CircomMerkleProof` is that object, so a tree kept in Rust can feed
+//! a JS-side prover directly.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::merkle::MerklePath;
+use crate::{parse_field, Poseidon, PoseidonError};
+
+/// Merkle inclusion proof as the input signals of a circom circuit
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", bound = "F: PrimeField")]
+pub struct CircomMerkleProof<F = Fr> {
+    #[serde(with = "decimal")]
+    pub root: F,
+    #[serde(with = "decimal")]
+    pub leaf: F,
+    /// Siblings from the leaf level up to the root
+    #[serde(with = "decimals")]
+    pub path_elements: Vec<F>,
+    /// For each level, whether the node on the path is the right child, as 0 or 1
+    #[serde(with = "bits")]
+    pub path_indices: Vec<bool>,
+}
+
+impl<F: PrimeField> MerklePath<F> {
+    /// The proof of `leaf` under `root` in circom's signal layout
+    pub fn to_circom(&self, root: F, leaf: F) -> CircomMerkleProof<F> {
+        CircomMerkleProof {
+            root,
+            leaf,
+            path_elements: self.siblings.clone(),
+            path_indices: self.path_indices.clone(),
+        }
+    }
+}
+
+impl CircomMerkleProof {
+    /// Check the proof against its own root
+    pub fn verify(&self) -> bool {
+        self.verify_with(&Poseidon::new())
+    }
+}
+
+impl<F: PrimeField> CircomMerkleProof<F> {
+    /// Check the proof against its own root, hashing with `poseidon`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>) -> bool {
+        self.path().verify_with(poseidon, self.root, self.leaf)
+    }
+
+    pub fn path(&self) -> MerklePath<F> {
+        MerklePath {
+            siblings: self.path_elements.clone(),
+            path_indices: self.path_indices.clone(),
+        }
+    }
+
+    /// The circom input JSON, e.g. `{"root":"…","leaf":"…","pathElements":[…],
+    /// "pathIndices":[0,1,…]}`
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("the proof has no maps or non-finite numbers")
+    }
+
+    /// Parse the JSON of `to_json`; elements may also be `0x`-prefixed hex, as accepted by
+    /// `parse_field`. Fails with `InvalidEncoding` on malformed JSON, a missing signal or a
+    /// path index other than 0 or 1; other keys are ignored, so the proof can be read from
+    /// a circuit's full input file.
+    pub fn from_json(json: &str) -> Result<CircomMerkleProof<F>, PoseidonError> {
+        serde_json::from_str(json).map_err(|_| PoseidonError::InvalidEncoding)
+    }
+
+    /// The signal values in the order of the JSON object: `root`, `leaf`, then the path
+    /// elements and the path indices as 0 or 1
+    pub fn to_field_array(&self) -> Vec<F> {
+        [self.root, self.leaf]
+            .into_iter()
+            .chain(self.path_elements.iter().copied())
+            .chain(self.path_indices.iter().map(|right| F::from(*right)))
+            .collect()
+    }
+}
+
+fn decode<F: PrimeField, E: serde::de::Error>(s: &str) -> Result<F, E> {
+    parse_field(s).map_err(E::custom)
+}
+
+/// `F` as a decimal string
+mod decimal {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(x: &F, s: S) -> Result<S::Ok, S::Error> {
+        x.into_bigint().to_string().serialize(s)
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(d: D) -> Result<F, D::Error> {
+        decode(&String::deserialize(d)?)
+    }
+}
+
+/// `Vec<F>` as a sequence of decimal strings
+mod decimals {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(v: &[F], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(v.iter().map(|x| x.into_bigint().to_string()))
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(d: D) -> Result<Vec<F>, D::Error> {
+        Vec::<String>::deserialize(d)?
+            .iter()
+            .map(|s| decode(s))
+            .collect()
+    }
+}
+
+/// `Vec<bool>` as a sequence of 0 and 1
+mod bits {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &[bool], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(v.iter().map(|bit| *bit as u8))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<bool>, D::Error> {
+        Vec::<u8>::deserialize(d)?
+            .into_iter()
+            .map(|bit| match bit {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(D::Error::custom("path index is not 0 or 1")),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::sponge::PoseidonSponge;
+use crate::{Poseidon, PoseidonError};
+
+/// Capacity tag of commitment sponges; plain hashes and sponges start from zero
+const COMMIT_DOMAIN: u64 = 0x636f6d6d6974;
+
+/// Hiding Poseidon commitment to a list of field elements.
+///
+/// The commitment is squeezed from a rate-2, capacity-1 sponge whose capacity element
+/// starts at a commitment-specific domain tag, after absorbing the blinding factor
+/// followed by the values. The sponge padding keeps lists of different lengths apart,
+/// and the domain tag keeps commitments apart from `hash`, `hash_sponge` and MAC outputs,
+/// so a commitment cannot be reopened as a hash of other data. Hiding relies on a
+/// uniformly random blinding factor used only once.
+pub struct PoseidonCommitment<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+}
+
+#[cfg(feature = "width-3")]
+impl Default for PoseidonCommitment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonCommitment {
+    #[cfg(feature = "width-3")]
+    pub fn new() -> PoseidonCommitment {
+        PoseidonCommitment::with_poseidon(Poseidon::new()).expect("BN254 supports width 3")
+    }
+}
+
+impl<F: PrimeField> PoseidonCommitment<F> {
+    /// Commitments over the permutation of the given instance, which has to support
+    /// width 3
+    pub fn with_poseidon(poseidon: Poseidon<F>) -> Result<PoseidonCommitment<F>, PoseidonError> {
+        if !poseidon.supports_width(3) {
+            return Err(PoseidonError::InvalidSpongeParameters {
+                rate: 2,
+                capacity: 1,
+            });
+        }
+        Ok(PoseidonCommitment { poseidon })
+    }
+
+    /// Commit to `values` under `blinding`
+    pub fn commit(&self, values: &[F], blinding: &F) -> F {
+        let mut sponge =
+            PoseidonSponge::with_domain(self.poseidon.clone(), 2, 1, F::from(COMMIT_DOMAIN))
+                .expect("width 3 is checked on construction");
+        sponge.absorb_one(*blinding);
+        for x in values {
+            sponge.absorb_one(*x);
+        }
+        sponge.squeeze()
+    }
+
+    /// Check that `commitment` opens to `values` under `blinding`
+    pub fn verify_opening(&self, commitment: &F, values: &[F], blinding: &F) -> bool {
+        self.commit(values, blinding) == *commitment
+    }
+}
+
+#[cfg(all(test, feature = "width-3"))]
+mod tests {
+    use super::*;
+    use crate::PoseidonMac;
+
+    #[test]
+    fn opens_only_to_committed_values() {
+        let scheme = PoseidonCommitment::new();
+        let values = [Fr::from(1u64), Fr::from(2u64)];
+        let blinding = Fr::from(42u64);
+        let commitment = scheme.commit(&values, &blinding);
+
+        assert!(scheme.verify_opening(&commitment, &values, &blinding));
+        assert!(!scheme.verify_opening(&commitment, &values, &Fr::from(43u64)));
+        assert!(!scheme.verify_opening(&commitment, &[values[0], Fr::from(3u64)], &blinding));
+        assert!(!scheme.verify_opening(&commitment, &values[..1], &blinding));
+        assert!(!scheme.verify_opening(
+            &commitment,
+            &[values[0], values[1], Fr::from(0u64)],
+            &blinding
+        ));
+        assert!(!scheme.verify_opening(&(commitment + Fr::from(1u64)), &values, &blinding));
+    }
+
+    #[test]
+    fn domain_separated_from_hashing() {
+        let (m, r) = (Fr::from(7u64), Fr::from(42u64));
+        let commitment = PoseidonCommitment::new().commit(&[m], &r);
+
+        assert_ne!(commitment, crate::hash(&[r, m]).unwrap());
+        assert_ne!(commitment, Poseidon::new().hash_sponge(2, &[r, m]).unwrap());
+        assert_ne!(commitment, PoseidonMac::new().mac(&r, &[m]));
+    }
+}
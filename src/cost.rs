@@ -0,0 +1,54 @@
+//! Compute-unit estimates of `Poseidon::hash` over BN254 inside a Solana program, for
+//! sizing transactions before measuring them.
+//!
+//! The syscall price is the runtime's formula. The software estimate counts the field
+//! multiplications of the permutation and prices each at `UNCALIBRATED_CU_PER_MUL`, a
+//! rough guess at ark-ff's Montgomery multiplication on SBF, which has no 64x64 -> 128-bit
+//! multiply. It has not been measured, so the estimate is only good for orders of
+//! magnitude; measure with the `solana_tests` program when the budget is tight.
+
+use crate::static_constants::{N_ROUNDS_F, N_ROUNDS_P};
+use crate::MAX_WIDTH;
+
+/// Coefficient of `n^2` in the `sol_poseidon` price
+pub const SYSCALL_COEFFICIENT_A: u64 = 61;
+/// Constant term of the `sol_poseidon` price
+pub const SYSCALL_COEFFICIENT_C: u64 = 542;
+/// Most inputs the syscall accepts
+pub const SYSCALL_MAX_INPUTS: usize = 12;
+/// Placeholder for the compute units of one BN254 field multiplication in software: a
+/// guess, not calibrated against `solana_tests`
+pub const UNCALIBRATED_CU_PER_MUL: u64 = 1_000;
+
+/// Compute units charged by `sol_poseidon` for `n_inputs` inputs, `61 n^2 + 542`
+pub fn syscall_cu(n_inputs: usize) -> Option<u64> {
+    if n_inputs == 0 || n_inputs > SYSCALL_MAX_INPUTS {
+        return None;
+    }
+    let n = n_inputs as u64;
+    Some(SYSCALL_COEFFICIENT_A * n * n + SYSCALL_COEFFICIENT_C)
+}
+
+/// Field multiplications (squarings included) of one software hash of `n_inputs`
+/// inputs: `R_F (3t + t^2)` in the full rounds, `t^2` for the dense matrix before the
+/// partial rounds and `R_P (2t + 2)` in the sparse partial rounds
+pub fn field_multiplications(n_inputs: usize) -> Option<u64> {
+    if n_inputs == 0 || n_inputs >= MAX_WIDTH {
+        return None;
+    }
+    let t = n_inputs as u64 + 1;
+    let (r_f, r_p) = (N_ROUNDS_F as u64, N_ROUNDS_P[n_inputs - 1] as u64);
+    Some(r_f * (3 * t + t * t) + t * t + r_p * (2 * t + 2))
+}
+
+/// Estimated compute units of the software permutation for `n_inputs` inputs, priced at
+/// `UNCALIBRATED_CU_PER_MUL`
+pub fn software_cu(n_inputs: usize) -> Option<u64> {
+    field_multiplications(n_inputs).map(|muls| muls * UNCALIBRATED_CU_PER_MUL)
+}
+
+/// Estimated compute units of `Poseidon::hash` on Solana, which uses the syscall up to
+/// `SYSCALL_MAX_INPUTS` inputs and software above
+pub fn hash_cu(n_inputs: usize) -> Option<u64> {
+    syscall_cu(n_inputs).or_else(|| software_cu(n_inputs))
+}
@@ -0,0 +1,82 @@
+//! `ark-crypto-primitives` hash traits for the circomlib BN254 instance.
+//!
+//! `PoseidonCRH` hashes a leaf slice with `hash` and `PoseidonTwoToOneCRH` hashes two
+//! nodes as `Poseidon([left, right])`, so `PoseidonMerkleConfig` trees have the node
+//! hashing of `MerkleTree`. The parameters are the `Poseidon` instance itself.
+
+use ark_bn254::Fr;
+use ark_crypto_primitives::crh::{CRHScheme, TwoToOneCRHScheme};
+use ark_crypto_primitives::merkle_tree::{Config, IdentityDigestConverter};
+use ark_crypto_primitives::{CryptoError, Error};
+use ark_std::borrow::Borrow;
+use ark_std::boxed::Box;
+use ark_std::rand::Rng;
+
+use crate::{Poseidon, PoseidonError};
+
+/// Hash of a slice of field elements with `Poseidon::hash`
+pub struct PoseidonCRH;
+
+/// Hash of two field elements
+pub struct PoseidonTwoToOneCRH;
+
+/// Merkle tree configuration hashing leaves with `PoseidonCRH` and inner nodes with
+/// `PoseidonTwoToOneCRH`
+pub struct PoseidonMerkleConfig;
+
+impl CRHScheme for PoseidonCRH {
+    type Input = [Fr];
+    type Output = Fr;
+    type Parameters = Poseidon;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Poseidon, Error> {
+        Ok(Poseidon::new())
+    }
+
+    fn evaluate<T: Borrow<[Fr]>>(parameters: &Poseidon, input: T) -> Result<Fr, Error> {
+        let input = input.borrow();
+        parameters
+            .hash(input)
+            .map_err(|err| to_crypto_error(err, input.len()))
+    }
+}
+
+impl TwoToOneCRHScheme for PoseidonTwoToOneCRH {
+    type Input = Fr;
+    type Output = Fr;
+    type Parameters = Poseidon;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Poseidon, Error> {
+        Ok(Poseidon::new())
+    }
+
+    fn evaluate<T: Borrow<Fr>>(parameters: &Poseidon, left: T, right: T) -> Result<Fr, Error> {
+        parameters
+            .hash(&[*left.borrow(), *right.borrow()])
+            .map_err(|err| to_crypto_error(err, 2))
+    }
+
+    fn compress<T: Borrow<Fr>>(parameters: &Poseidon, left: T, right: T) -> Result<Fr, Error> {
+        Self::evaluate(parameters, left, right)
+    }
+}
+
+impl Config for PoseidonMerkleConfig {
+    type Leaf = [Fr];
+    type LeafDigest = Fr;
+    type LeafInnerDigestConverter = IdentityDigestConverter<Fr>;
+    type InnerDigest = Fr;
+    type LeafHash = PoseidonCRH;
+    type TwoToOneHash = PoseidonTwoToOneCRH;
+}
+
+// `ark_std::error::Error` is a separate trait without `std`, so errors are mapped onto
+// `CryptoError`. It has no variant for a failed Solana syscall, which is reported as an
+// incorrect length of the `len` inputs rather than panicking inside `evaluate`.
+fn to_crypto_error(err: PoseidonError, len: usize) -> Error {
+    let got = match err {
+        PoseidonError::InvalidInputLength { got, .. } => got,
+        _ => len,
+    };
+    Box::new(CryptoError::IncorrectInputLength(got))
+}
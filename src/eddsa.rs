@@ -0,0 +1,298 @@
+//! EdDSA over Baby Jubjub with Poseidon, compatible with circomlib's `EdDSAPoseidonVerifier`
+//! and circomlibjs' `signPoseidon`/`verifyPoseidon`.
+//!
+//! Baby Jubjub is the twisted Edwards curve `168700 x^2 + y^2 = 1 + 168696 x^2 y^2` over
+//! the BN254 scalar field. Keys are derived from a 32-byte seed with BLAKE-512 like
+//! circomlibjs, the challenge is `Poseidon([R8.x, R8.y, A.x, A.y, msg])`, and points and
+//! signatures serialize like circomlibjs' `packPoint` and `packSignature`.
+
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, Field, MontFp, One, PrimeField};
+use blake_hash::{Blake512, Digest};
+
+use crate::{bytes_to_field_strict, field_to_bytes, Poseidon, PoseidonError};
+
+pub use field::{Fs, FsConfig};
+
+// The ark-ff 0.4 derive expands to an impl inside a function body, and its 4-limb
+// multiplication checks ark-ff's `asm` feature in this crate's cfg
+#[allow(non_local_definitions, unexpected_cfgs)]
+mod field {
+    use ark_ff::{Fp256, MontBackend, MontConfig};
+
+    /// Order of the Baby Jubjub prime-order subgroup
+    #[derive(MontConfig)]
+    #[modulus = "2736030358979909402780800718157159386076813972158567259200215660948447373041"]
+    #[generator = "31"]
+    pub struct FsConfig;
+    pub type Fs = Fp256<MontBackend<FsConfig, 4>>;
+}
+
+const A: Fr = MontFp!("168700");
+const D: Fr = MontFp!("168696");
+
+/// Generator of the prime-order subgroup, eight times circomlib's `Generator`
+pub const BASE8: Point = Point {
+    x: MontFp!("5299619240641551281634865583518297030282874472190772894086521144482721001553"),
+    y: MontFp!("16950150798460657717958625567821834550301663161624707787222815936182638968203"),
+};
+
+/// Affine Baby Jubjub point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x: Fr,
+    pub y: Fr,
+}
+
+/// Projective `(X : Y : Z)` point for the ladder, with `x = X / Z` and `y = Y / Z`
+#[derive(Clone, Copy)]
+struct Projective {
+    x: Fr,
+    y: Fr,
+    z: Fr,
+}
+
+impl Projective {
+    const IDENTITY: Projective = Projective {
+        x: MontFp!("0"),
+        y: MontFp!("1"),
+        z: MontFp!("1"),
+    };
+
+    /// Complete twisted Edwards addition (add-2008-bbjlp)
+    fn add(&self, other: &Projective) -> Projective {
+        let a = self.z * other.z;
+        let b = a.square();
+        let c = self.x * other.x;
+        let d = self.y * other.y;
+        let e = D * c * d;
+        let f = b - e;
+        let g = b + e;
+        Projective {
+            x: a * f * ((self.x + self.y) * (other.x + other.y) - c - d),
+            y: a * g * (d - A * c),
+            z: f * g,
+        }
+    }
+
+    fn to_affine(self) -> Point {
+        let z_inv = self.z.inverse().expect("complete addition keeps Z nonzero");
+        Point {
+            x: self.x * z_inv,
+            y: self.y * z_inv,
+        }
+    }
+}
+
+impl Point {
+    pub const IDENTITY: Point = Point {
+        x: MontFp!("0"),
+        y: MontFp!("1"),
+    };
+
+    pub fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        A * x2 + y2 == Fr::one() + D * x2 * y2
+    }
+
+    pub fn add(&self, other: &Point) -> Point {
+        self.projective().add(&other.projective()).to_affine()
+    }
+
+    /// `scalar * self` for a little-endian integer of any size
+    pub fn mul_scalar<B: BigInteger>(&self, scalar: &B) -> Point {
+        let p = self.projective();
+        let mut acc = Projective::IDENTITY;
+        for i in (0..scalar.num_bits() as usize).rev() {
+            acc = acc.add(&acc);
+            if scalar.get_bit(i) {
+                acc = acc.add(&p);
+            }
+        }
+        acc.to_affine()
+    }
+
+    /// circomlibjs' `packPoint`: `y` in little-endian with the top bit set when `x` is
+    /// above `(p - 1) / 2`
+    pub fn compress(&self) -> [u8; 32] {
+        let mut out = field_to_bytes(&self.y);
+        if is_negative(&self.x) {
+            out[31] |= 0x80;
+        }
+        out
+    }
+
+    /// circomlibjs' `unpackPoint`; fails with `InvalidEncoding` if `y` is not canonical
+    /// or no point has it
+    pub fn decompress(bytes: &[u8; 32]) -> Result<Point, PoseidonError> {
+        let mut y_bytes = *bytes;
+        let sign = y_bytes[31] & 0x80 != 0;
+        y_bytes[31] &= 0x7f;
+        let y: Fr = bytes_to_field_strict(&y_bytes).map_err(|_| PoseidonError::InvalidEncoding)?;
+        let y2 = y.square();
+        let x2 = (Fr::one() - y2)
+            * (A - D * y2)
+                .inverse()
+                .ok_or(PoseidonError::InvalidEncoding)?;
+        let mut x = x2.sqrt().ok_or(PoseidonError::InvalidEncoding)?;
+        if is_negative(&x) != sign {
+            x = -x;
+        }
+        Ok(Point { x, y })
+    }
+
+    fn projective(&self) -> Projective {
+        Projective {
+            x: self.x,
+            y: self.y,
+            z: Fr::one(),
+        }
+    }
+}
+
+fn is_negative(x: &Fr) -> bool {
+    x.into_bigint() > Fr::MODULUS_MINUS_ONE_DIV_TWO
+}
+
+/// 32-byte EdDSA seed, from which the signing scalar and nonce key are derived
+#[derive(Clone)]
+pub struct PrivateKey([u8; 32]);
+
+/// Public key `A = (s >> 3) * BASE8`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey(pub Point);
+
+/// Signature `(R8, S)`; `S` is below the subgroup order by construction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r8: Point,
+    pub s: Fs,
+}
+
+impl PrivateKey {
+    pub const fn new(seed: [u8; 32]) -> PrivateKey {
+        PrivateKey(seed)
+    }
+
+    /// Random seed from a cryptographic RNG
+    #[cfg(feature = "rand")]
+    pub fn generate<R: rand_core::RngCore + rand_core::CryptoRng>(rng: &mut R) -> PrivateKey {
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed);
+        PrivateKey(seed)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// circomlibjs' `prv2pub`
+    pub fn public_key(&self) -> PublicKey {
+        let (s, _) = self.expand();
+        PublicKey(BASE8.mul_scalar(&shr3(&s)))
+    }
+
+    /// `(s >> 3) mod l`, the scalar behind `public_key`, like `deriveSecretScalar` of
+    /// zk-kit's eddsa-poseidon
+    pub fn secret_scalar(&self) -> Fs {
+        let (s, _) = self.expand();
+        // The pruned `s` is a multiple of 8, so the shift is a division
+        Fs::from_le_bytes_mod_order(&s) * Fs::from(8u64).inverse().expect("8 is invertible")
+    }
+
+    /// circomlibjs' `signPoseidon`
+    pub fn sign(&self, msg: Fr) -> Signature {
+        let (s, nonce_key) = self.expand();
+        let a = BASE8.mul_scalar(&shr3(&s));
+        let r_bytes = Blake512::new()
+            .chain(nonce_key)
+            .chain(field_to_bytes(&msg))
+            .finalize();
+        let r = Fs::from_le_bytes_mod_order(&r_bytes);
+        let r8 = BASE8.mul_scalar(&r.into_bigint());
+        let hm = challenge(&r8, &a, msg);
+        let s =
+            r + Fs::from_le_bytes_mod_order(&field_to_bytes(&hm)) * Fs::from_le_bytes_mod_order(&s);
+        Signature { r8, s }
+    }
+
+    /// The pruned BLAKE-512 of the seed: signing scalar and nonce key
+    fn expand(&self) -> ([u8; 32], [u8; 32]) {
+        let h = Blake512::digest(&self.0);
+        let mut s = [0u8; 32];
+        let mut nonce_key = [0u8; 32];
+        s.copy_from_slice(&h[..32]);
+        nonce_key.copy_from_slice(&h[32..]);
+        s[0] &= 0xf8;
+        s[31] &= 0x7f;
+        s[31] |= 0x40;
+        (s, nonce_key)
+    }
+}
+
+/// Wipes the seed
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for PrivateKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl PublicKey {
+    /// circomlibjs' `verifyPoseidon`: `S * BASE8 == R8 + 8 * hm * A`, with both points on
+    /// the curve
+    pub fn verify(&self, msg: Fr, sig: &Signature) -> bool {
+        if !sig.r8.is_on_curve() || !self.0.is_on_curve() {
+            return false;
+        }
+        let hm = challenge(&sig.r8, &self.0, msg);
+        let left = BASE8.mul_scalar(&sig.s.into_bigint());
+        let a8 = (0..3).fold(self.0, |p, _| p.add(&p));
+        let right = sig.r8.add(&a8.mul_scalar(&hm.into_bigint()));
+        left == right
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.compress()
+    }
+
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<PublicKey, PoseidonError> {
+        Point::decompress(bytes).map(PublicKey)
+    }
+}
+
+impl Signature {
+    /// circomlibjs' `packSignature`: the packed `R8` followed by `S` in little-endian
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.r8.compress());
+        out[32..].copy_from_slice(&field_to_bytes(&self.s));
+        out
+    }
+
+    /// circomlibjs' `unpackSignature`; fails with `InvalidEncoding` if `R8` does not
+    /// decompress or `S` is not below the subgroup order
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Signature, PoseidonError> {
+        let r8 = Point::decompress(bytes[..32].try_into().expect("32 bytes"))?;
+        let s = bytes_to_field_strict(bytes[32..].try_into().expect("32 bytes"))
+            .map_err(|_| PoseidonError::InvalidEncoding)?;
+        Ok(Signature { r8, s })
+    }
+}
+
+fn challenge(r8: &Point, a: &Point, msg: Fr) -> Fr {
+    Poseidon::new()
+        .hash(&[r8.x, r8.y, a.x, a.y, msg])
+        .expect("Poseidon width is not enabled")
+}
+
+/// `s >> 3` as an integer
+fn shr3(s: &[u8; 32]) -> <Fr as PrimeField>::BigInt {
+    let mut x = <Fr as PrimeField>::BigInt::default();
+    for (limb, chunk) in x.0.iter_mut().zip(s.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().expect("8 bytes"));
+    }
+    x.divn(3);
+    x
+}
@@ -0,0 +1,75 @@
+use core::fmt;
+
+/// Errors returned by the hashing, sponge and parameter APIs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoseidonError {
+    /// The number of inputs does not select a supported width; `max` is the most inputs
+    /// the instance accepts
+    InvalidInputLength { got: usize, max: usize },
+    /// The number of requested outputs is zero or exceeds the state width `max`
+    InvalidOutputLength { got: usize, max: usize },
+    /// A 32-byte input is not the canonical encoding of a field element
+    NonCanonicalBytes,
+    /// The Solana Poseidon syscall rejected the inputs
+    SyscallFailed,
+    /// `rate + capacity` is not a width supported by the permutation
+    InvalidSpongeParameters { rate: usize, capacity: usize },
+    /// `absorb` was called after squeezing started
+    AlreadySqueezed,
+    /// The MDS matrix is not square or its size is outside 2..=25
+    InvalidMdsMatrix,
+    /// The number of round constants does not match the rounds and width, or the number
+    /// of full rounds is odd
+    InvalidRoundConstants,
+    /// The s-box exponent is neither -1 nor at least 3 and coprime to `p - 1`
+    InvalidSboxExponent,
+    /// The field is too small for the construction
+    UnsupportedField,
+    /// An encryption nonce is not below 2^128
+    InvalidNonce,
+    /// A ciphertext failed its padding or authentication tag check
+    AuthenticationFailed,
+    /// Every leaf of a fixed-depth Merkle tree is already in use
+    MerkleTreeFull,
+    /// The key is already in the sparse Merkle tree
+    KeyAlreadyExists,
+    /// The key is not in the sparse Merkle tree
+    KeyNotFound,
+    /// A serialized value has the wrong length or layout
+    InvalidEncoding,
+}
+
+impl fmt::Display for PoseidonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoseidonError::InvalidInputLength { got, max } => {
+                write!(f, "Wrong inputs length: got {}, expected 1 to {}", got, max)
+            }
+            PoseidonError::InvalidOutputLength { got, max } => {
+                write!(
+                    f,
+                    "Wrong outputs length: got {}, expected 1 to {}",
+                    got, max
+                )
+            }
+            PoseidonError::NonCanonicalBytes => write!(f, "Non-canonical field element"),
+            PoseidonError::SyscallFailed => write!(f, "Poseidon syscall failed"),
+            PoseidonError::InvalidSpongeParameters { rate, capacity } => {
+                write!(f, "Wrong sponge rate/capacity: {}/{}", rate, capacity)
+            }
+            PoseidonError::AlreadySqueezed => write!(f, "Sponge already squeezed"),
+            PoseidonError::InvalidMdsMatrix => write!(f, "Wrong MDS matrix size"),
+            PoseidonError::InvalidRoundConstants => write!(f, "Wrong round constants length"),
+            PoseidonError::InvalidSboxExponent => write!(f, "Wrong s-box exponent"),
+            PoseidonError::UnsupportedField => write!(f, "Field too small"),
+            PoseidonError::InvalidNonce => write!(f, "Nonce must be below 2^128"),
+            PoseidonError::AuthenticationFailed => write!(f, "Ciphertext authentication failed"),
+            PoseidonError::MerkleTreeFull => write!(f, "Merkle tree is full"),
+            PoseidonError::KeyAlreadyExists => write!(f, "Key already exists"),
+            PoseidonError::KeyNotFound => write!(f, "Key not found"),
+            PoseidonError::InvalidEncoding => write!(f, "Invalid encoding"),
+        }
+    }
+}
+
+impl core::error::Error for PoseidonError {}
@@ -0,0 +1,70 @@
+//! Hashes matching the deployed Solidity `PoseidonT3` and `PoseidonT4` contracts
+//! (poseidon-solidity, and circomlibjs' generated `poseidon_gencontract`).
+//!
+//! Inputs and outputs are 32-byte big-endian `uint256` words. The contracts add inputs
+//! to the round constants with `addmod`, so words at or above the BN254 scalar modulus
+//! are reduced here as well instead of being rejected.
+
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{field_to_bytes_be, Poseidon};
+
+/// `PoseidonT3.hash([a, b])`; panics if the `width-3` feature is disabled
+pub fn poseidon_t3(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    hash_words(&[word(a), word(b)])
+}
+
+/// `PoseidonT4.hash([a, b, c])`; panics if the `width-4` feature is disabled
+pub fn poseidon_t4(a: &[u8; 32], b: &[u8; 32], c: &[u8; 32]) -> [u8; 32] {
+    hash_words(&[word(a), word(b), word(c)])
+}
+
+fn word(bytes: &[u8; 32]) -> Fr {
+    Fr::from_be_bytes_mod_order(bytes)
+}
+
+fn hash_words(inp: &[Fr]) -> [u8; 32] {
+    let hash = Poseidon::new()
+        .hash(inp)
+        .expect("Poseidon width is not enabled");
+    field_to_bytes_be(&hash)
+}
+
+#[cfg(all(test, feature = "width-3", feature = "width-4"))]
+mod tests {
+    use super::*;
+    use crate::decode_hex;
+
+    fn uint(x: u64) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&x.to_be_bytes());
+        word
+    }
+
+    // Outputs of the deployed PoseidonT3/PoseidonT4 contracts (and circomlibjs `poseidon`)
+    #[test]
+    fn solidity_vectors() {
+        assert_eq!(
+            poseidon_t3(&uint(1), &uint(2)),
+            decode_hex(b"0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a")
+                .unwrap()
+        );
+        assert_eq!(
+            poseidon_t4(&uint(1), &uint(2), &uint(3)),
+            decode_hex(b"0x0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn words_are_reduced_like_addmod() {
+        let p_plus_1 =
+            decode_hex(b"0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000002")
+                .unwrap();
+        assert_eq!(
+            poseidon_t3(&p_plus_1, &uint(2)),
+            poseidon_t3(&uint(1), &uint(2))
+        );
+    }
+}
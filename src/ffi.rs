@@ -0,0 +1,101 @@
+//! C ABI over the circomlib BN254 hash, declared in `include/poseidon_ark.h`.
+//!
+//! Build the shared library with
+//! `cargo rustc --release --features ffi --crate-type cdylib`. Inputs are `n`
+//! consecutive 32-byte field elements; the digest has the same encoding. The status codes
+//! are part of the ABI and keep their values across releases.
+
+use core::slice;
+
+use crate::{Poseidon, PoseidonError};
+
+/// Result of an FFI call
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoseidonStatus {
+    Ok = 0,
+    /// `n` does not select a supported width
+    InvalidInputLength = 1,
+    /// An input is not the canonical encoding of a field element
+    NonCanonicalBytes = 2,
+    /// A required pointer is null
+    NullPointer = 3,
+    /// An error without a dedicated code, e.g. from the Solana syscall
+    Other = 255,
+}
+
+impl From<PoseidonError> for PoseidonStatus {
+    fn from(e: PoseidonError) -> Self {
+        match e {
+            PoseidonError::InvalidInputLength { .. } => PoseidonStatus::InvalidInputLength,
+            PoseidonError::NonCanonicalBytes => PoseidonStatus::NonCanonicalBytes,
+            _ => PoseidonStatus::Other,
+        }
+    }
+}
+
+/// Most inputs `poseidon_hash_bytes` accepts
+#[no_mangle]
+pub extern "C" fn poseidon_max_inputs() -> usize {
+    Poseidon::new().max_inputs()
+}
+
+/// Hash `n` little-endian elements at `inputs` into the little-endian digest at `out`,
+/// like `Poseidon::hash_bytes`; `out` is left untouched on error.
+///
+/// # Safety
+///
+/// `inputs` must be valid for reading `32 * n` bytes (it may be null if `n` is zero) and
+/// `out` for writing 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_hash_bytes(
+    inputs: *const u8,
+    n: usize,
+    out: *mut u8,
+) -> PoseidonStatus {
+    hash(inputs, n, out, false)
+}
+
+/// `poseidon_hash_bytes` with big-endian inputs and digest, like `Poseidon::hash_bytes_be`
+///
+/// # Safety
+///
+/// As for `poseidon_hash_bytes`.
+#[no_mangle]
+pub unsafe extern "C" fn poseidon_hash_bytes_be(
+    inputs: *const u8,
+    n: usize,
+    out: *mut u8,
+) -> PoseidonStatus {
+    hash(inputs, n, out, true)
+}
+
+unsafe fn hash(inputs: *const u8, n: usize, out: *mut u8, big_endian: bool) -> PoseidonStatus {
+    if out.is_null() || (inputs.is_null() && n > 0) {
+        return PoseidonStatus::NullPointer;
+    }
+    let poseidon = Poseidon::new();
+    if n == 0 || n > poseidon.max_inputs() {
+        return PoseidonStatus::InvalidInputLength;
+    }
+    // SAFETY: the caller guarantees `32 * n` readable bytes at `inputs`
+    let bytes = unsafe { slice::from_raw_parts(inputs, 32 * n) };
+    let mut refs = [&[0u8; 32]; crate::MAX_WIDTH - 1];
+    for (r, chunk) in refs.iter_mut().zip(bytes.chunks_exact(32)) {
+        *r = chunk.try_into().expect("32-byte chunks");
+    }
+    let mut digest = [0u8; 32];
+    let res = if big_endian {
+        poseidon.hash_bytes_be_into(&refs[..n], &mut digest)
+    } else {
+        poseidon.hash_bytes_into(&refs[..n], &mut digest)
+    };
+    match res {
+        Ok(()) => {
+            // SAFETY: the caller guarantees 32 writable bytes at `out`
+            unsafe { out.copy_from_nonoverlapping(digest.as_ptr(), 32) };
+            PoseidonStatus::Ok
+        }
+        Err(e) => e.into(),
+    }
+}
@@ -0,0 +1,213 @@
+//! Permutation over a `[F; T]` state with the width known at compile time.
+//!
+//! Bounds are fixed, so the compiler unrolls the loops and drops the index checks that
+//! the slice-based `permute` pays for, and the matrix rows go through
+//! `Field::sum_of_products`, which reduces once per row instead of once per product. The
+//! schedule is the same as `rounds_optimized`, and the output is identical to `hash`.
+
+use ark_ff::PrimeField;
+
+use crate::{Poseidon, PoseidonError, Width};
+
+impl<F: PrimeField> Poseidon<F> {
+    /// `hash([a, b])`, the Merkle node hash, on the fixed-width path
+    pub fn hash2(&self, a: F, b: F) -> Result<F, PoseidonError> {
+        self.hash_array([F::zero(), a, b])
+    }
+
+    /// `hash([a, b, c])` on the fixed-width path
+    pub fn hash3(&self, a: F, b: F, c: F) -> Result<F, PoseidonError> {
+        self.hash_array([F::zero(), a, b, c])
+    }
+
+    /// `hash` of exactly `N` inputs, with the arity checked at compile time and the state
+    /// sized `[F; N + 1]`.
+    ///
+    /// `N` must be in 1..=24; panics if the constant set has no width `N + 1`, e.g. when
+    /// its `width-{N + 1}` feature is disabled.
+    pub fn hash_fixed<const N: usize>(&self, inputs: &[F; N]) -> F
+    where
+        Arity<N>: SupportedArity,
+    {
+        Arity::<N>::hash(self, inputs)
+    }
+
+    /// Permute an initialized state and return its first element, through the syscall
+    /// when `hash` would use it
+    pub(crate) fn hash_array<const T: usize>(&self, mut state: [F; T]) -> Result<F, PoseidonError> {
+        #[cfg(feature = "solana")]
+        if let Some(bn254) = self.syscall_instance(T - 1) {
+            if state[0].is_zero() {
+                return bn254.hash_fields_syscall(&state[1..]);
+            }
+        }
+
+        if !self.supports_width(T) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: T - 1,
+                max: self.max_inputs(),
+            });
+        }
+        self.permute_array(&mut state);
+        Ok(state[0])
+    }
+
+    /// `permute` for a supported width `T`
+    pub(crate) fn permute_array<const T: usize>(&self, state: &mut [F; T]) {
+        match self.array_rounds::<T>() {
+            Some(rounds) => self.permute_rounds(&rounds, state),
+            None => self.permute(state),
+        }
+    }
+
+    /// Hash `inputs[i]` into `out[i]` for every `i` in `group`, whose inputs all have
+    /// `T - 1` elements, looking the constants up once
+    #[cfg(feature = "alloc")]
+    pub(crate) fn hash_group<const T: usize>(
+        &self,
+        inputs: &[&[F]],
+        group: &[usize],
+        out: &mut [F],
+    ) {
+        let rounds = self.array_rounds::<T>();
+        let mut state = [F::zero(); T];
+        for &i in group {
+            state[0] = F::zero();
+            state[1..].copy_from_slice(inputs[i]);
+            match &rounds {
+                Some(rounds) => self.permute_rounds(rounds, &mut state),
+                None => self.permute(&mut state),
+            }
+            out[i] = state[0];
+        }
+        #[cfg(feature = "zeroize")]
+        state.iter_mut().for_each(zeroize::Zeroize::zeroize);
+    }
+
+    /// The optimized constants of width `T` in array form, if the instance has them
+    pub(crate) fn array_rounds<const T: usize>(&self) -> Option<ArrayRounds<F, T>> {
+        let k = self.constants()?;
+        let o = k.optimized?;
+        let i = Width::of::<T>().index();
+        Some(ArrayRounds {
+            n_rounds_f: k.n_rounds_f,
+            n_rounds_p: k.n_rounds_p[i],
+            c: k.c[i],
+            m: k.m[i],
+            m_i: o.m_i[i],
+            opt_c: o.c[i],
+            s: o.s[i],
+        })
+    }
+
+    fn permute_rounds<const T: usize>(&self, k: &ArrayRounds<F, T>, state: &mut [F; T]) {
+        let (n_rounds_f, n_rounds_p) = (k.n_rounds_f, k.n_rounds_p);
+        let (c, m, m_i, opt_c, s) = (k.c, k.m, k.m_i, k.opt_c, k.s);
+
+        let half_f = n_rounds_f / 2;
+        let full_round = |state: &mut [F; T], round: usize| {
+            let rc: &[F; T] = c[round * T..(round + 1) * T]
+                .try_into()
+                .expect("T constants");
+            for (x, rc) in state.iter_mut().zip(rc) {
+                *x += rc;
+                self.sbox_pow(x);
+            }
+            mix(state, m);
+        };
+
+        for round in 0..half_f {
+            full_round(state, round);
+        }
+
+        let rc: &[F; T] = opt_c[..T].try_into().expect("T constants");
+        state.iter_mut().zip(rc).for_each(|(x, rc)| *x += rc);
+        mix(state, m_i);
+        let m00 = m[0][0];
+        for (r, sparse) in s.chunks_exact(2 * (T - 1)).enumerate() {
+            self.sbox_pow(&mut state[0]);
+            if r + 1 < n_rounds_p {
+                state[0] += opt_c[T + r];
+            }
+            let (w_hat, v) = sparse.split_at(T - 1);
+            let mut row = [m00; T];
+            row[1..].copy_from_slice(w_hat);
+            let new_s0 = F::sum_of_products(&row, state);
+            let s0 = state[0];
+            for (x, v) in state[1..].iter_mut().zip(v) {
+                *x += s0 * v;
+            }
+            state[0] = new_s0;
+        }
+
+        for round in (half_f + n_rounds_p)..(n_rounds_f + n_rounds_p) {
+            full_round(state, round);
+        }
+    }
+}
+
+// Static optimized constants of one width `T`. The matrices are borrowed rather than
+// copied into `[[F; T]; T]` arrays, which take 40 KB of stack at t = 25, ten times a
+// Solana stack frame; rows are viewed as `[F; T]` where they are used.
+pub(crate) struct ArrayRounds<F: 'static, const T: usize> {
+    pub(crate) n_rounds_f: usize,
+    pub(crate) n_rounds_p: usize,
+    pub(crate) c: &'static [F],
+    pub(crate) m: &'static [&'static [F]],
+    pub(crate) m_i: &'static [&'static [F]],
+    pub(crate) opt_c: &'static [F],
+    pub(crate) s: &'static [F],
+}
+
+/// The arity `N` of `Poseidon::hash_fixed`
+pub struct Arity<const N: usize>;
+
+/// Implemented for the arities 1 to 24 that `Poseidon::hash_fixed` accepts
+pub trait SupportedArity: sealed::Sealed {
+    #[doc(hidden)]
+    fn hash<F: PrimeField>(poseidon: &Poseidon<F>, inputs: &[F]) -> F;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_arity {
+    ($($n:literal => $t:literal),*) => {
+        $(
+            impl sealed::Sealed for Arity<$n> {}
+
+            impl SupportedArity for Arity<$n> {
+                fn hash<F: PrimeField>(poseidon: &Poseidon<F>, inputs: &[F]) -> F {
+                    let mut state = [F::zero(); $t];
+                    state[1..].copy_from_slice(inputs);
+                    poseidon
+                        .hash_array(state)
+                        .expect("Poseidon width is not enabled")
+                }
+            }
+        )*
+    };
+}
+
+impl_arity!(
+    1 => 2, 2 => 3, 3 => 4, 4 => 5, 5 => 6, 6 => 7, 7 => 8, 8 => 9, 9 => 10, 10 => 11,
+    11 => 12, 12 => 13, 13 => 14, 14 => 15, 15 => 16, 16 => 17, 17 => 18, 18 => 19,
+    19 => 20, 20 => 21, 21 => 22, 22 => 23, 23 => 24, 24 => 25
+);
+
+/// Row `i` of a `T x T` matrix as an array
+pub(crate) fn row<'a, F, const T: usize>(m: &[&'a [F]], i: usize) -> &'a [F; T] {
+    m[i].try_into().expect("T x T matrix")
+}
+
+fn mix<F: PrimeField, const T: usize>(state: &mut [F; T], m: &[&[F]]) {
+    #[allow(unused_mut)]
+    let mut old = *state;
+    for (i, x) in state.iter_mut().enumerate() {
+        *x = F::sum_of_products(row(m, i), &old);
+    }
+    // As in `mix_inplace`, the copy holds a full state
+    #[cfg(feature = "zeroize")]
+    old.iter_mut().for_each(zeroize::Zeroize::zeroize);
+}
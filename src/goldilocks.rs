@@ -0,0 +1,1724 @@
+//! Poseidon over the 64-bit Goldilocks field (p = 2^64 - 2^32 + 1).
+//!
+//! Round constants and MDS matrices are the Grain LFSR instances of the Poseidon
+//! reference implementation (x^7, R_F = 8, R_P = 22) for widths 8, 12 and 16, so `hash`
+//! accepts 7, 11 or 15 inputs.
+
+use ark_ff::MontFp;
+
+use crate::{Poseidon, PoseidonConstants};
+
+pub use field::{Goldilocks, GoldilocksConfig};
+
+// The ark-ff 0.4 derive expands to an impl inside a function body
+#[allow(non_local_definitions)]
+mod field {
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+
+    #[derive(MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    pub struct GoldilocksConfig;
+    pub type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+}
+
+pub static GOLDILOCKS_CONSTANTS: PoseidonConstants<Goldilocks> = PoseidonConstants {
+    c: C,
+    m: M,
+    alpha: 7,
+    n_rounds_f: 8,
+    n_rounds_p: &[0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 22, 0, 0, 0, 22],
+    optimized: None,
+};
+
+impl Poseidon<Goldilocks> {
+    pub const fn new_goldilocks() -> Poseidon<Goldilocks> {
+        Poseidon::with_constants(&GOLDILOCKS_CONSTANTS)
+    }
+}
+
+static C: &[&[Goldilocks]] = &[
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[
+        MontFp!("6270525064469221351"),
+        MontFp!("4949779046890457733"),
+        MontFp!("10272327018519096618"),
+        MontFp!("14764988483175512595"),
+        MontFp!("5185330617915428979"),
+        MontFp!("18270894613234151438"),
+        MontFp!("16433452215787040439"),
+        MontFp!("8697983751326958148"),
+        MontFp!("10550619437542862091"),
+        MontFp!("12777378036353475180"),
+        MontFp!("17543298363765507795"),
+        MontFp!("3550615019965689596"),
+        MontFp!("336256277693767259"),
+        MontFp!("16023872902564925980"),
+        MontFp!("9476350552096844929"),
+        MontFp!("5784151728563997576"),
+        MontFp!("4953296199946825179"),
+        MontFp!("16485792283962971575"),
+        MontFp!("9200507885350191702"),
+        MontFp!("8595374012782096647"),
+        MontFp!("11199237879397868947"),
+        MontFp!("15312213056817364691"),
+        MontFp!("18221315778024805037"),
+        MontFp!("10909780657925695982"),
+        MontFp!("13938261539535381607"),
+        MontFp!("1736205310559972283"),
+        MontFp!("203701527218976766"),
+        MontFp!("11017144826759654127"),
+        MontFp!("14056176273409186966"),
+        MontFp!("7636127245465896254"),
+        MontFp!("2153866859502668333"),
+        MontFp!("13604978967015196316"),
+        MontFp!("10056098683802945401"),
+        MontFp!("5584462921552333071"),
+        MontFp!("9022418047805495159"),
+        MontFp!("2146515045821007543"),
+        MontFp!("7648060141114184928"),
+        MontFp!("5555923406659219975"),
+        MontFp!("5092244780996155930"),
+        MontFp!("8712030857564816775"),
+        MontFp!("17180815551962126500"),
+        MontFp!("811329478383043854"),
+        MontFp!("2250913065049606414"),
+        MontFp!("15367815712629933985"),
+        MontFp!("4124246343808477726"),
+        MontFp!("4547302004605842680"),
+        MontFp!("3964846241625791716"),
+        MontFp!("4503761267186734193"),
+        MontFp!("7948997438598748145"),
+        MontFp!("8147630472549608504"),
+        MontFp!("4770991855037284502"),
+        MontFp!("12329657065485576741"),
+        MontFp!("15789328871305695811"),
+        MontFp!("15139273464994060297"),
+        MontFp!("6569822481306191958"),
+        MontFp!("10251780329634324332"),
+        MontFp!("4582773295081419117"),
+        MontFp!("4738554613938875504"),
+        MontFp!("6642624487472573547"),
+        MontFp!("10506814177529580154"),
+        MontFp!("9309627145200555510"),
+        MontFp!("474424612764088642"),
+        MontFp!("9153601558496206948"),
+        MontFp!("8387918857077118688"),
+        MontFp!("4082962963618188416"),
+        MontFp!("2768145881746018950"),
+        MontFp!("15104721029286575993"),
+        MontFp!("11465531539763277051"),
+        MontFp!("2526287850185569095"),
+        MontFp!("16000404004252260946"),
+        MontFp!("18419205176494028218"),
+        MontFp!("2855577222808325968"),
+        MontFp!("9908741810879851399"),
+        MontFp!("9942180869824882308"),
+        MontFp!("2782044668877568804"),
+        MontFp!("14807751967126537807"),
+        MontFp!("361989777231453810"),
+        MontFp!("12059147984503989848"),
+        MontFp!("4135146801067230951"),
+        MontFp!("5914922349157298129"),
+        MontFp!("11427092572372112358"),
+        MontFp!("8220554058519948122"),
+        MontFp!("5011243620889558363"),
+        MontFp!("15595912897310796505"),
+        MontFp!("3221790358689199180"),
+        MontFp!("5798468569837860839"),
+        MontFp!("6908199894727473799"),
+        MontFp!("7657975062032481040"),
+        MontFp!("13003937998902962494"),
+        MontFp!("11174342074869718564"),
+        MontFp!("12875619347447793873"),
+        MontFp!("12049236627580391918"),
+        MontFp!("156058680175070869"),
+        MontFp!("15327637937857497741"),
+        MontFp!("15508472687190193771"),
+        MontFp!("9361693005282877548"),
+        MontFp!("900614338132420845"),
+        MontFp!("8801911872307293506"),
+        MontFp!("16192746868975617496"),
+        MontFp!("18365277104604858195"),
+        MontFp!("12211570686032454755"),
+        MontFp!("11956579410638685995"),
+        MontFp!("16606777656842479039"),
+        MontFp!("4189001094199086239"),
+        MontFp!("15671440460342708007"),
+        MontFp!("2680487065375253079"),
+        MontFp!("1392668470131825173"),
+        MontFp!("16997855754044338972"),
+        MontFp!("1518255750898796614"),
+        MontFp!("6031552801952998305"),
+        MontFp!("2449372712228859011"),
+        MontFp!("13478625198810960340"),
+        MontFp!("7731191439098737274"),
+        MontFp!("15051019727134725955"),
+        MontFp!("15360407181628566954"),
+        MontFp!("18249941783710020410"),
+        MontFp!("16805928070218220209"),
+        MontFp!("18226502753253946982"),
+        MontFp!("423696445337904646"),
+        MontFp!("1152103869807234919"),
+        MontFp!("7576311324651553115"),
+        MontFp!("4227945779377949754"),
+        MontFp!("6419942998329954279"),
+        MontFp!("4350547218468297971"),
+        MontFp!("5889904335112834180"),
+        MontFp!("13638698882475058236"),
+        MontFp!("12036906365081782299"),
+        MontFp!("681114407883984697"),
+        MontFp!("2591219461001077599"),
+        MontFp!("15300115526431431263"),
+        MontFp!("7249616921982881325"),
+        MontFp!("12674883147406738376"),
+        MontFp!("15747519212224947129"),
+        MontFp!("10632574864036989807"),
+        MontFp!("15340283537329811518"),
+        MontFp!("11017285624830412812"),
+        MontFp!("8941667583269237573"),
+        MontFp!("471681000371485985"),
+        MontFp!("1705427414736540986"),
+        MontFp!("549419788950940916"),
+        MontFp!("1381538406442495266"),
+        MontFp!("5469062726338050709"),
+        MontFp!("17028639718844942926"),
+        MontFp!("13307091106492690227"),
+        MontFp!("8191292330471187739"),
+        MontFp!("8477829548912780968"),
+        MontFp!("8261549439360674791"),
+        MontFp!("486043133327407780"),
+        MontFp!("9301028920422315101"),
+        MontFp!("12046078875701012935"),
+        MontFp!("4765101011079555890"),
+        MontFp!("13868284618991137852"),
+        MontFp!("2797088355906900247"),
+        MontFp!("17928471647062669632"),
+        MontFp!("17737705493301224891"),
+        MontFp!("13859708325168448403"),
+        MontFp!("17567547313463058822"),
+        MontFp!("455487269193106107"),
+        MontFp!("6076459249653811734"),
+        MontFp!("11168744895741012700"),
+        MontFp!("7271107597998991255"),
+        MontFp!("8977231888225970353"),
+        MontFp!("1558481633469729516"),
+        MontFp!("9066799344566640050"),
+        MontFp!("9560823647905231791"),
+        MontFp!("6948990241721704646"),
+        MontFp!("14932915342979756079"),
+        MontFp!("16136273284991270021"),
+        MontFp!("5216188480211607511"),
+        MontFp!("5785846974438336180"),
+        MontFp!("12534993648211998359"),
+        MontFp!("9537477994836212818"),
+        MontFp!("10513379858548319441"),
+        MontFp!("15485005505024583001"),
+        MontFp!("8456177395079503152"),
+        MontFp!("16573996119574248843"),
+        MontFp!("4608728938211776135"),
+        MontFp!("8017523965857379220"),
+        MontFp!("5998712988175904004"),
+        MontFp!("4921806413835777216"),
+        MontFp!("3334665386169913477"),
+        MontFp!("3122105907321480058"),
+        MontFp!("12015743495272157205"),
+        MontFp!("6098459880073473361"),
+        MontFp!("8874132282265331513"),
+        MontFp!("18179887586644819401"),
+        MontFp!("15482643301778927782"),
+        MontFp!("4658301535227994956"),
+        MontFp!("3722100616927738812"),
+        MontFp!("12089872121228297255"),
+        MontFp!("14577022721442045101"),
+        MontFp!("10339234378434416804"),
+        MontFp!("6241046200592098513"),
+        MontFp!("6167412925821881846"),
+        MontFp!("4990611145762346035"),
+        MontFp!("18331988365218275701"),
+        MontFp!("6343884580428914489"),
+        MontFp!("15558132517179100299"),
+        MontFp!("16396885061493539071"),
+        MontFp!("7068503456789865115"),
+        MontFp!("17875062906701376746"),
+        MontFp!("2440320524798421112"),
+        MontFp!("17918549345889279324"),
+        MontFp!("8556079512330873558"),
+        MontFp!("7074877432127083604"),
+        MontFp!("8644166800525257466"),
+        MontFp!("17108213212781846227"),
+        MontFp!("7265553158208575167"),
+        MontFp!("1752387154901187024"),
+        MontFp!("15035284164655027986"),
+        MontFp!("9687323751941662601"),
+        MontFp!("3229077704312978229"),
+        MontFp!("7931244637097796977"),
+        MontFp!("11970203507280032967"),
+        MontFp!("8175647055046866068"),
+        MontFp!("10447024179014579333"),
+        MontFp!("13734331433688501078"),
+        MontFp!("14014678945665017210"),
+        MontFp!("7763565693108683002"),
+        MontFp!("6581006358586537951"),
+        MontFp!("8355650214723676770"),
+        MontFp!("16740840472299878876"),
+        MontFp!("17171622679586176253"),
+        MontFp!("3920064705360602636"),
+        MontFp!("14052910064625177950"),
+        MontFp!("11022094563885410046"),
+        MontFp!("17107100647063174357"),
+        MontFp!("8915585398540086925"),
+        MontFp!("16980591642818722722"),
+        MontFp!("3311391421729488474"),
+        MontFp!("8771337111099247681"),
+        MontFp!("10927328971526717035"),
+        MontFp!("2517956016386269121"),
+        MontFp!("5259241064301314995"),
+        MontFp!("12758710644398378546"),
+        MontFp!("1406512657599997015"),
+        MontFp!("15179238370608282884"),
+        MontFp!("5926535525763039137"),
+        MontFp!("12961674547666182746"),
+        MontFp!("5842176257020874234"),
+    ],
+    &[],
+    &[],
+    &[],
+    &[
+        MontFp!("16155722998241985703"),
+        MontFp!("16304788901777513344"),
+        MontFp!("326303055793236234"),
+        MontFp!("16478478269450032460"),
+        MontFp!("10021634705548873610"),
+        MontFp!("16427982278607342947"),
+        MontFp!("7141098707100445688"),
+        MontFp!("9870689195068261977"),
+        MontFp!("8416418478851049929"),
+        MontFp!("16242863134820941661"),
+        MontFp!("6327104027136993954"),
+        MontFp!("15861536960721072209"),
+        MontFp!("13511964081854188922"),
+        MontFp!("17923299753665969517"),
+        MontFp!("17410946659846792165"),
+        MontFp!("17119882063229367435"),
+        MontFp!("1989478460659218702"),
+        MontFp!("1785743976492065086"),
+        MontFp!("7429455035932242628"),
+        MontFp!("5802155804275255146"),
+        MontFp!("5561057694804616592"),
+        MontFp!("11857434558348873739"),
+        MontFp!("9392974546019173419"),
+        MontFp!("17686663702877428840"),
+        MontFp!("9066856129084235275"),
+        MontFp!("14703992557645950886"),
+        MontFp!("2130210044088337791"),
+        MontFp!("5117767698913212382"),
+        MontFp!("6585036398284031359"),
+        MontFp!("9772109803644401703"),
+        MontFp!("4442181697997369571"),
+        MontFp!("13014675529203448923"),
+        MontFp!("13159723438668682909"),
+        MontFp!("2991790588220676448"),
+        MontFp!("14786609435527731101"),
+        MontFp!("5157143142952423365"),
+        MontFp!("15813058378248894932"),
+        MontFp!("6552109476996846090"),
+        MontFp!("6781255692740307952"),
+        MontFp!("4108620527965729916"),
+        MontFp!("13063269215505866189"),
+        MontFp!("13890173299290004282"),
+        MontFp!("12925902034296305641"),
+        MontFp!("2390449593627563450"),
+        MontFp!("2390651405084432573"),
+        MontFp!("15944112470316353977"),
+        MontFp!("9885738318329029568"),
+        MontFp!("14291347636222456766"),
+        MontFp!("16423708012065153553"),
+        MontFp!("8139608879069838262"),
+        MontFp!("16669526914996925822"),
+        MontFp!("12668661124202952302"),
+        MontFp!("3791833354021773565"),
+        MontFp!("3933871451879415309"),
+        MontFp!("8590006817817204992"),
+        MontFp!("6871183211558958696"),
+        MontFp!("7229742734520285657"),
+        MontFp!("4770106529533929128"),
+        MontFp!("13368697496512519477"),
+        MontFp!("7902672713254912110"),
+        MontFp!("3886175644001017152"),
+        MontFp!("7912074743131344835"),
+        MontFp!("8209285590742098819"),
+        MontFp!("10046213324682517359"),
+        MontFp!("14929024588625854376"),
+        MontFp!("11537447210697327194"),
+        MontFp!("14766850636193323911"),
+        MontFp!("8133341667441442795"),
+        MontFp!("7272198928659444828"),
+        MontFp!("8027887777051471415"),
+        MontFp!("4067690427064494959"),
+        MontFp!("3832859937148517991"),
+        MontFp!("5040624355524016402"),
+        MontFp!("8977913216172724037"),
+        MontFp!("8418783839475011042"),
+        MontFp!("18382949846127330943"),
+        MontFp!("11550991263641583081"),
+        MontFp!("2085085175517304846"),
+        MontFp!("17763852519110411437"),
+        MontFp!("7063013751320144596"),
+        MontFp!("9396134749077226560"),
+        MontFp!("5724227617941480322"),
+        MontFp!("14971667730757582759"),
+        MontFp!("11244390208953713414"),
+        MontFp!("5550368952742844914"),
+        MontFp!("7069484895242593888"),
+        MontFp!("3763566716468915799"),
+        MontFp!("12060904672173351832"),
+        MontFp!("16361876444034046090"),
+        MontFp!("1069139055374303895"),
+        MontFp!("3103233193349773760"),
+        MontFp!("17268088792054965839"),
+        MontFp!("16991987365409366710"),
+        MontFp!("17540339271778713130"),
+        MontFp!("11608094792925817502"),
+        MontFp!("4680335220202123450"),
+        MontFp!("14204285289064528661"),
+        MontFp!("16176901221309506719"),
+        MontFp!("14540764086211766442"),
+        MontFp!("7243477993031961102"),
+        MontFp!("5661365215444790713"),
+        MontFp!("15811472258324162"),
+        MontFp!("2123670402769423839"),
+        MontFp!("13321472511986475571"),
+        MontFp!("12080443806635249050"),
+        MontFp!("10411552052628160619"),
+        MontFp!("4314307521526578071"),
+        MontFp!("2763762344643051768"),
+        MontFp!("17396556813317674516"),
+        MontFp!("8210190879671585095"),
+        MontFp!("3559512372177549879"),
+        MontFp!("14569422242993045062"),
+        MontFp!("5509084683599050693"),
+        MontFp!("4404250292522732041"),
+        MontFp!("13889913281749406081"),
+        MontFp!("2184143210703059127"),
+        MontFp!("3036105726390678122"),
+        MontFp!("16579782843154550390"),
+        MontFp!("10835492387345204708"),
+        MontFp!("16309237496620751227"),
+        MontFp!("5265048605511750596"),
+        MontFp!("9847076680924692850"),
+        MontFp!("9025738296451011731"),
+        MontFp!("926027952926056185"),
+        MontFp!("16670257742251762270"),
+        MontFp!("11651255707592147462"),
+        MontFp!("3116219966406541182"),
+        MontFp!("12078310251023553909"),
+        MontFp!("9179667876024132675"),
+        MontFp!("1052435551468126930"),
+        MontFp!("14947297269260626682"),
+        MontFp!("4599513150284541629"),
+        MontFp!("11201225350677596329"),
+        MontFp!("16470806799883061955"),
+        MontFp!("7039824439425940595"),
+        MontFp!("3386007053668868579"),
+        MontFp!("9623872391379616578"),
+        MontFp!("3286537768282113303"),
+        MontFp!("5845694577252884220"),
+        MontFp!("7132727139459816540"),
+        MontFp!("11806562510236171987"),
+        MontFp!("11024094583410641204"),
+        MontFp!("5314452970094607071"),
+        MontFp!("3155280797211094006"),
+        MontFp!("4880096344289762502"),
+        MontFp!("4353197237800884112"),
+        MontFp!("4898943374644556480"),
+        MontFp!("2412538995798114047"),
+        MontFp!("11604909529945470387"),
+        MontFp!("14736320113819173742"),
+        MontFp!("7195101097200433534"),
+        MontFp!("10085625372621100371"),
+        MontFp!("3980312545009203896"),
+        MontFp!("3393216922772355152"),
+        MontFp!("11550276478422026011"),
+        MontFp!("5343336541547383157"),
+        MontFp!("4437695295717769253"),
+        MontFp!("12514561506706866489"),
+        MontFp!("1867582118652158796"),
+        MontFp!("10446046628725797265"),
+        MontFp!("10225004910525424582"),
+        MontFp!("14466932044764927653"),
+        MontFp!("2991114923663530534"),
+        MontFp!("17066819917886539509"),
+        MontFp!("18313577340329655352"),
+        MontFp!("3808018833431694097"),
+        MontFp!("3481954670513230821"),
+        MontFp!("11345700121571344866"),
+        MontFp!("11220192019341589189"),
+        MontFp!("4153138027928138165"),
+        MontFp!("8771172305400117402"),
+        MontFp!("15947686079628446166"),
+        MontFp!("14702816009468238167"),
+        MontFp!("7625819558483127529"),
+        MontFp!("14607698388387137986"),
+        MontFp!("11741614826294968272"),
+        MontFp!("3576236445423717035"),
+        MontFp!("4427680173673364507"),
+        MontFp!("14974978018498285213"),
+        MontFp!("15182748537097089902"),
+        MontFp!("13958283757588342623"),
+        MontFp!("12583728158093402326"),
+        MontFp!("14948655452058571142"),
+        MontFp!("12775779329882083442"),
+        MontFp!("10391772401587242967"),
+        MontFp!("15352437041534494874"),
+        MontFp!("7482044741599978663"),
+        MontFp!("215983801296583462"),
+        MontFp!("14428082878948957492"),
+        MontFp!("14812488666874188710"),
+        MontFp!("14419343546586557544"),
+        MontFp!("15009286532938543277"),
+        MontFp!("1649187814127814402"),
+        MontFp!("1635324592314515870"),
+        MontFp!("5762432295389839920"),
+        MontFp!("8533827908297072062"),
+        MontFp!("8956598421898417532"),
+        MontFp!("6317894930588847520"),
+        MontFp!("14298778424815418556"),
+        MontFp!("7938239316991212564"),
+        MontFp!("16901191789766572131"),
+        MontFp!("14329131136411133402"),
+        MontFp!("10705151653550669722"),
+        MontFp!("5057508066612063129"),
+        MontFp!("11158620744469127486"),
+        MontFp!("16605471527091660284"),
+        MontFp!("9204752516253486893"),
+        MontFp!("9367048988202286102"),
+        MontFp!("10770059920714377178"),
+        MontFp!("18173817436116188100"),
+        MontFp!("18275311158630613880"),
+        MontFp!("15426981926353685873"),
+        MontFp!("7329613202777907790"),
+        MontFp!("12238274389594527451"),
+        MontFp!("14041837207846522860"),
+        MontFp!("12873362750920834328"),
+        MontFp!("13634724192484518533"),
+        MontFp!("4048936737750822713"),
+        MontFp!("12542481439250838154"),
+        MontFp!("4247492702316512968"),
+        MontFp!("14279545162373717231"),
+        MontFp!("15576406957757064505"),
+        MontFp!("11135742556824508385"),
+        MontFp!("7127445621326169225"),
+        MontFp!("6576067362306704363"),
+        MontFp!("946602962697277522"),
+        MontFp!("1259668650264931283"),
+        MontFp!("15858854658082862335"),
+        MontFp!("14651872422273329660"),
+        MontFp!("5703649314220638284"),
+        MontFp!("11108722321128328942"),
+        MontFp!("10677663535887199250"),
+        MontFp!("3972495914328029312"),
+        MontFp!("8257895667573631884"),
+        MontFp!("14174747921527606592"),
+        MontFp!("6116299359259254402"),
+        MontFp!("11122181545049742262"),
+        MontFp!("16365236366001396060"),
+        MontFp!("10754216204174486370"),
+        MontFp!("15509822120818630279"),
+        MontFp!("14673119752275731511"),
+        MontFp!("17115458895531581467"),
+        MontFp!("5216160269358238943"),
+        MontFp!("13779263142626728087"),
+        MontFp!("14693834553795732811"),
+        MontFp!("11176891536324360342"),
+        MontFp!("3793150526752338466"),
+        MontFp!("4072546577787714020"),
+        MontFp!("2084804684587221049"),
+        MontFp!("14498473712312910013"),
+        MontFp!("6062043677335787157"),
+        MontFp!("2484782370719546151"),
+        MontFp!("161230517980773333"),
+        MontFp!("11231208211873163945"),
+        MontFp!("13426791150751619782"),
+        MontFp!("5036434510885405216"),
+        MontFp!("14389905430454564958"),
+        MontFp!("1712390582097660604"),
+        MontFp!("16690434538232254158"),
+        MontFp!("8076272085852837418"),
+        MontFp!("17412386670155356834"),
+        MontFp!("5649276088402083760"),
+        MontFp!("7137644808058255893"),
+        MontFp!("1673914277468475910"),
+        MontFp!("12636037903699324951"),
+        MontFp!("5395605707435546114"),
+        MontFp!("24774670282520210"),
+        MontFp!("11838836609621364851"),
+        MontFp!("10685467897758836775"),
+        MontFp!("14137802067515634454"),
+        MontFp!("15285067561899006042"),
+        MontFp!("11166001946460757244"),
+        MontFp!("4483013570456086365"),
+        MontFp!("14391979983581714755"),
+        MontFp!("14174089751436333504"),
+        MontFp!("8715357324560845"),
+        MontFp!("11743986965929857694"),
+        MontFp!("4192590601368536470"),
+        MontFp!("4971159485600358100"),
+        MontFp!("13921367283905064876"),
+        MontFp!("689321252627024930"),
+        MontFp!("17117833254103720466"),
+        MontFp!("13813403025337848849"),
+        MontFp!("12534263029338516493"),
+        MontFp!("8058622368596534171"),
+        MontFp!("11701712237496032033"),
+        MontFp!("3171751102631847220"),
+        MontFp!("18043181141801290763"),
+        MontFp!("7699550709618895610"),
+        MontFp!("1525624284894595778"),
+        MontFp!("7012126224540085680"),
+        MontFp!("14778321791166499585"),
+        MontFp!("13436232182310874618"),
+        MontFp!("11586155924027260404"),
+        MontFp!("17478671359447887714"),
+        MontFp!("15857144998952020518"),
+        MontFp!("13645918751962629314"),
+        MontFp!("1032348376377436597"),
+        MontFp!("8674033288443505638"),
+        MontFp!("16897013801380231065"),
+        MontFp!("17670647677336229670"),
+        MontFp!("231641228274102049"),
+        MontFp!("13576938595611703452"),
+        MontFp!("13196119917339366550"),
+        MontFp!("13594028383360009250"),
+        MontFp!("17836225990159975451"),
+        MontFp!("10120031586916646584"),
+        MontFp!("17841033830553996804"),
+        MontFp!("17214131204860362052"),
+        MontFp!("5954389151028845420"),
+        MontFp!("11512667533789106435"),
+        MontFp!("10849403450631389844"),
+        MontFp!("324584764814764947"),
+        MontFp!("17495674834715009788"),
+        MontFp!("3466706872427564069"),
+        MontFp!("15135342960820255187"),
+        MontFp!("3722425046750698915"),
+        MontFp!("1370024870614260207"),
+        MontFp!("14969088487777856782"),
+        MontFp!("4555290710615476887"),
+        MontFp!("13132451349683045122"),
+        MontFp!("4858010187644483114"),
+        MontFp!("7661940504763283257"),
+        MontFp!("17563314901502343445"),
+        MontFp!("4720243707463018270"),
+        MontFp!("5320113885362432763"),
+        MontFp!("2321252731089087136"),
+        MontFp!("8809820230662358455"),
+        MontFp!("15396930838956654214"),
+        MontFp!("1736798744339400082"),
+        MontFp!("8626219990323854134"),
+        MontFp!("2196862942513693048"),
+        MontFp!("5507486550916994078"),
+        MontFp!("17633468246758804425"),
+        MontFp!("6977190147693229114"),
+        MontFp!("15757098780728622168"),
+        MontFp!("604799821141438905"),
+        MontFp!("13167470287680329483"),
+        MontFp!("15306419893761931281"),
+        MontFp!("9523389858052559768"),
+        MontFp!("2304562164324675923"),
+        MontFp!("9240656977685287928"),
+        MontFp!("12978986056929316934"),
+        MontFp!("686595338136571237"),
+        MontFp!("16796730299407878971"),
+        MontFp!("7567197485411889651"),
+        MontFp!("7308660718050779079"),
+        MontFp!("17489915453949792135"),
+        MontFp!("9961846900353627438"),
+        MontFp!("14852586624220973458"),
+        MontFp!("6507489384393482345"),
+        MontFp!("13748960490556399170"),
+        MontFp!("9741173841544213844"),
+        MontFp!("18265677680000088200"),
+        MontFp!("11876651835124100445"),
+        MontFp!("7478682368884114454"),
+        MontFp!("5822492504110044574"),
+        MontFp!("17025343537201465426"),
+        MontFp!("2695912960031257660"),
+        MontFp!("7465453045746224308"),
+    ],
+    &[],
+    &[],
+    &[],
+    &[
+        MontFp!("14988215097606552697"),
+        MontFp!("7088162180504390836"),
+        MontFp!("1267778940084306889"),
+        MontFp!("13003861504106825895"),
+        MontFp!("9154159613338396276"),
+        MontFp!("10040404595969542119"),
+        MontFp!("142147308625630836"),
+        MontFp!("10778370182339790761"),
+        MontFp!("922520993232668717"),
+        MontFp!("12997350243971061403"),
+        MontFp!("14389584530911650918"),
+        MontFp!("9339051977788752551"),
+        MontFp!("11385800367886371419"),
+        MontFp!("863458325905918899"),
+        MontFp!("16977430691307390201"),
+        MontFp!("12571357000999052886"),
+        MontFp!("15709207915051524168"),
+        MontFp!("11786516924657753222"),
+        MontFp!("12065985855519652746"),
+        MontFp!("1556493698094025732"),
+        MontFp!("11223174864129054370"),
+        MontFp!("8323202070756464495"),
+        MontFp!("6012921850263022117"),
+        MontFp!("9788829222343629874"),
+        MontFp!("14315619157294643002"),
+        MontFp!("4292713227957790535"),
+        MontFp!("17623670903372441694"),
+        MontFp!("14170992520531815256"),
+        MontFp!("16612272418316134741"),
+        MontFp!("12889357111987223958"),
+        MontFp!("7036213272296983821"),
+        MontFp!("232222670468790729"),
+        MontFp!("1960700870847322927"),
+        MontFp!("7661757675711242715"),
+        MontFp!("13204119886742760797"),
+        MontFp!("6941978304792841592"),
+        MontFp!("1698739981429815669"),
+        MontFp!("5397205347193204469"),
+        MontFp!("4291694131671473429"),
+        MontFp!("4224168970303931577"),
+        MontFp!("2289649210037525412"),
+        MontFp!("14543989843173761031"),
+        MontFp!("1828348597292365688"),
+        MontFp!("12081597713741518017"),
+        MontFp!("4396507529414388832"),
+        MontFp!("1265201962474906676"),
+        MontFp!("13657719391756057615"),
+        MontFp!("7796467717867819889"),
+        MontFp!("6967345606581934777"),
+        MontFp!("17699778479134690827"),
+        MontFp!("3396096882076402776"),
+        MontFp!("11068958808435637001"),
+        MontFp!("9098556209679395818"),
+        MontFp!("9750193758059472689"),
+        MontFp!("8878763989389332604"),
+        MontFp!("11481789826306825307"),
+        MontFp!("4558453226878869265"),
+        MontFp!("6792389119509674017"),
+        MontFp!("9939257078296796739"),
+        MontFp!("13260295364324334635"),
+        MontFp!("16021881271053141143"),
+        MontFp!("9446726266009249515"),
+        MontFp!("17401034384428106426"),
+        MontFp!("9674216180661821101"),
+        MontFp!("17142538774139640458"),
+        MontFp!("14684148561843106734"),
+        MontFp!("15636766659712776245"),
+        MontFp!("8156017278630355583"),
+        MontFp!("3625104238130014566"),
+        MontFp!("1467149966262480684"),
+        MontFp!("5678512139436424764"),
+        MontFp!("13705798822354169612"),
+        MontFp!("7083620358908381238"),
+        MontFp!("13147706905768186948"),
+        MontFp!("14084789238891190977"),
+        MontFp!("49998550049922092"),
+        MontFp!("16763568700792708719"),
+        MontFp!("1198073201996800889"),
+        MontFp!("5755180161342459474"),
+        MontFp!("9513110370593542081"),
+        MontFp!("9856548504947780730"),
+        MontFp!("2813723928963131061"),
+        MontFp!("16184846724792487519"),
+        MontFp!("12109228608966519522"),
+        MontFp!("11777647023104136295"),
+        MontFp!("2395175910857378680"),
+        MontFp!("12359677570000334924"),
+        MontFp!("4331680803910445855"),
+        MontFp!("12249039898362422781"),
+        MontFp!("4621328025046390535"),
+        MontFp!("16572405817517345476"),
+        MontFp!("10543534906469573286"),
+        MontFp!("1242854367733171406"),
+        MontFp!("16550585955096385878"),
+        MontFp!("8144966760986618085"),
+        MontFp!("4219124023196722223"),
+        MontFp!("3038968815144776049"),
+        MontFp!("3370454468713853087"),
+        MontFp!("9466407984738818229"),
+        MontFp!("8185055438097544986"),
+        MontFp!("3652763033101280643"),
+        MontFp!("5671490157870405417"),
+        MontFp!("7270545405819354918"),
+        MontFp!("5487272069196852980"),
+        MontFp!("3235985277141783076"),
+        MontFp!("4409045690999827041"),
+        MontFp!("5814897312880248485"),
+        MontFp!("12181168157717281272"),
+        MontFp!("1600626557432301846"),
+        MontFp!("8340272605389367060"),
+        MontFp!("4420262215015981883"),
+        MontFp!("9152491609786559033"),
+        MontFp!("17134642161835927448"),
+        MontFp!("15753773661704433587"),
+        MontFp!("11373290858967975356"),
+        MontFp!("2851337709687808722"),
+        MontFp!("7788775981433145472"),
+        MontFp!("571312946441775075"),
+        MontFp!("2558073653629805734"),
+        MontFp!("8970181073789647285"),
+        MontFp!("1884610358518601644"),
+        MontFp!("8360713842707122838"),
+        MontFp!("12780903258993671938"),
+        MontFp!("3790007803897820898"),
+        MontFp!("10725981197943507360"),
+        MontFp!("3257575731333087562"),
+        MontFp!("17053456235808251325"),
+        MontFp!("6783640276953667802"),
+        MontFp!("15556460399106084125"),
+        MontFp!("3482024781521848838"),
+        MontFp!("6419365244882383634"),
+        MontFp!("13134194971213783229"),
+        MontFp!("5947615091776550464"),
+        MontFp!("8518426793382931778"),
+        MontFp!("6477545971106983625"),
+        MontFp!("15137140478677548461"),
+        MontFp!("6372389797197847232"),
+        MontFp!("8698569565665319659"),
+        MontFp!("14847915176655923298"),
+        MontFp!("1361818777839179109"),
+        MontFp!("10706119564959544923"),
+        MontFp!("15353650846569522061"),
+        MontFp!("3193205891921113294"),
+        MontFp!("44229662378598985"),
+        MontFp!("18229094147914863777"),
+        MontFp!("5546069323187642837"),
+        MontFp!("5049216661244765140"),
+        MontFp!("11249437197346618494"),
+        MontFp!("5834541410924696086"),
+        MontFp!("2517433926297319759"),
+        MontFp!("1662586308367159594"),
+        MontFp!("3059004388322138981"),
+        MontFp!("11840569265689571326"),
+        MontFp!("8776171823289284614"),
+        MontFp!("7798093031261212785"),
+        MontFp!("13975895039808582995"),
+        MontFp!("12131981428303791278"),
+        MontFp!("10246029002879242393"),
+        MontFp!("10161354487927029177"),
+        MontFp!("7129620581995516425"),
+        MontFp!("502038388581674219"),
+        MontFp!("6706898484899582095"),
+        MontFp!("1874171869138013240"),
+        MontFp!("17016866014548352361"),
+        MontFp!("2117423632238848654"),
+        MontFp!("12008599190718636903"),
+        MontFp!("16620893831103002100"),
+        MontFp!("8007742409136458789"),
+        MontFp!("13934857512289400011"),
+        MontFp!("9649719512992854022"),
+        MontFp!("16393672343698990808"),
+        MontFp!("7094704599038414218"),
+        MontFp!("10605101355145271985"),
+        MontFp!("11645637890649752286"),
+        MontFp!("2897664464558978381"),
+        MontFp!("2366486306838082659"),
+        MontFp!("11490374300093233924"),
+        MontFp!("6749630306648488328"),
+        MontFp!("17771451647722916135"),
+        MontFp!("3646420130020579045"),
+        MontFp!("6311393718655650943"),
+        MontFp!("9898391683857154441"),
+        MontFp!("12622672896621767997"),
+        MontFp!("15056119235799834630"),
+        MontFp!("12563355391068374960"),
+        MontFp!("9566194878043859768"),
+        MontFp!("4660022552939913958"),
+        MontFp!("17261761329489131594"),
+        MontFp!("11618349944785873703"),
+        MontFp!("12424999392283667832"),
+        MontFp!("3089477804357743174"),
+        MontFp!("10674330140713834856"),
+        MontFp!("7657445308850476058"),
+        MontFp!("3969455905979021465"),
+        MontFp!("14429826307403453661"),
+        MontFp!("6781731294519756406"),
+        MontFp!("3647419951153081540"),
+        MontFp!("11754678506570200252"),
+        MontFp!("3879314651846240398"),
+        MontFp!("13110046949845122827"),
+        MontFp!("18441320887566130227"),
+        MontFp!("14696492086023094924"),
+        MontFp!("1848036722635463022"),
+        MontFp!("1259728147198903516"),
+        MontFp!("8715004268803561303"),
+        MontFp!("1683519514599490416"),
+        MontFp!("9683312671948375815"),
+        MontFp!("16402903260115953724"),
+        MontFp!("16602066389077158024"),
+        MontFp!("13441227664628092852"),
+        MontFp!("483886163570076074"),
+        MontFp!("12276715784838762213"),
+        MontFp!("11150542816810095408"),
+        MontFp!("2260087198354468629"),
+        MontFp!("15168401884487704334"),
+        MontFp!("8966987578699584258"),
+        MontFp!("8482642903595868459"),
+        MontFp!("14930023799666175267"),
+        MontFp!("6435497103334494083"),
+        MontFp!("12259103287151271723"),
+        MontFp!("16890312985574416894"),
+        MontFp!("12273283775200595646"),
+        MontFp!("13286258170733475071"),
+        MontFp!("9585301712991131720"),
+        MontFp!("16091207544763491011"),
+        MontFp!("9282666717197776484"),
+        MontFp!("3678400385339039558"),
+        MontFp!("9639634563089214917"),
+        MontFp!("5471806425231607455"),
+        MontFp!("1372110541810550004"),
+        MontFp!("233446252286455440"),
+        MontFp!("8234171301205696259"),
+        MontFp!("7228975397009874909"),
+        MontFp!("4338542337764942774"),
+        MontFp!("5505643359058795641"),
+        MontFp!("13466782370131999434"),
+        MontFp!("7036915981322291029"),
+        MontFp!("10721193581520518385"),
+        MontFp!("11771742016728737031"),
+        MontFp!("1383291936828248830"),
+        MontFp!("15816053748850339991"),
+        MontFp!("8795383490187185841"),
+        MontFp!("15508234690354464742"),
+        MontFp!("14732630530075587204"),
+        MontFp!("6334371525379243609"),
+        MontFp!("4426764143904930799"),
+        MontFp!("5195817219775647197"),
+        MontFp!("13854692529404829025"),
+        MontFp!("4595416333009627390"),
+        MontFp!("4944281585508294884"),
+        MontFp!("11876821449586985409"),
+        MontFp!("5832466155961648286"),
+        MontFp!("16930113220766060676"),
+        MontFp!("13899346514081693257"),
+        MontFp!("16523053918296306050"),
+        MontFp!("6453282373700786790"),
+        MontFp!("2926727345815980875"),
+        MontFp!("3880857210015106085"),
+        MontFp!("2370159501423545191"),
+        MontFp!("15948195717575726373"),
+        MontFp!("2591695203241359818"),
+        MontFp!("934925363651905140"),
+        MontFp!("3818811084776341744"),
+        MontFp!("5083661944077501732"),
+        MontFp!("13256904327827418168"),
+        MontFp!("17350156255484057702"),
+        MontFp!("5077037916691607951"),
+        MontFp!("2528483624661736853"),
+        MontFp!("9599971026972505771"),
+        MontFp!("2955594581441557336"),
+        MontFp!("4206735647072469961"),
+        MontFp!("12394811506766730978"),
+        MontFp!("2626831507555346029"),
+        MontFp!("17183204242799424342"),
+        MontFp!("2463474456641220192"),
+        MontFp!("1755978000010382744"),
+        MontFp!("13729537789751417139"),
+        MontFp!("7304644513107088748"),
+        MontFp!("3653199396467105838"),
+        MontFp!("13056811247643448169"),
+        MontFp!("16655656364144027546"),
+        MontFp!("15720522521988640511"),
+        MontFp!("4394439909598227456"),
+        MontFp!("11512283592496480721"),
+        MontFp!("1732556228003130521"),
+        MontFp!("14444377224755764809"),
+        MontFp!("16719181652508103993"),
+        MontFp!("3429670369932338962"),
+        MontFp!("17846927298195671264"),
+        MontFp!("7027021815761482561"),
+        MontFp!("9058832102175986124"),
+        MontFp!("4759741286172025783"),
+        MontFp!("15244859549761693235"),
+        MontFp!("13882735491085316986"),
+        MontFp!("7235003234611667289"),
+        MontFp!("13607748933422710825"),
+        MontFp!("9679244401895375472"),
+        MontFp!("16479082478743854626"),
+        MontFp!("3289023727303324219"),
+        MontFp!("4787374403950870134"),
+        MontFp!("8891042382918883625"),
+        MontFp!("15561457801946963384"),
+        MontFp!("5603344928999172046"),
+        MontFp!("16742247955352235919"),
+        MontFp!("17266655938423716473"),
+        MontFp!("12371113468785829923"),
+        MontFp!("668040572637163663"),
+        MontFp!("4795111821436112646"),
+        MontFp!("13703345978056153034"),
+        MontFp!("5190976229883216618"),
+        MontFp!("14961054530075255938"),
+        MontFp!("13569411680846869984"),
+        MontFp!("14589822149533859243"),
+        MontFp!("3611327052404120933"),
+        MontFp!("14657583643281233486"),
+        MontFp!("15776212815869267266"),
+        MontFp!("11601362608370630585"),
+        MontFp!("4859810967898993539"),
+        MontFp!("4362500289477730723"),
+        MontFp!("4522035351254052109"),
+        MontFp!("15247839437553886014"),
+        MontFp!("5241391553212896188"),
+        MontFp!("15512707599236327409"),
+        MontFp!("3704376852856849260"),
+        MontFp!("583181634596043878"),
+        MontFp!("2659561506454596983"),
+        MontFp!("14726937411121337734"),
+        MontFp!("7242515334271450555"),
+        MontFp!("3628714562944650279"),
+        MontFp!("5289826804164117522"),
+        MontFp!("16801208264084395491"),
+        MontFp!("1756269132489685389"),
+        MontFp!("13046178788015042154"),
+        MontFp!("16880695212018210"),
+        MontFp!("5589637958304536800"),
+        MontFp!("9570635577408420395"),
+        MontFp!("10174033296198423841"),
+        MontFp!("10921408282043145751"),
+        MontFp!("11196990656426699100"),
+        MontFp!("2338940739594238011"),
+        MontFp!("17196782392983630109"),
+        MontFp!("10301084487124805112"),
+        MontFp!("2108559373236051737"),
+        MontFp!("17635374152465262620"),
+        MontFp!("945943404782726418"),
+        MontFp!("10824749840205753675"),
+        MontFp!("5785642868238620610"),
+        MontFp!("827488224712025802"),
+        MontFp!("7561365658911600328"),
+        MontFp!("3823381459999516071"),
+        MontFp!("6358392860130202045"),
+        MontFp!("15350435045289295320"),
+        MontFp!("11239447369797367807"),
+        MontFp!("3144371485873494051"),
+        MontFp!("14630328625366954576"),
+        MontFp!("15759522728469699104"),
+        MontFp!("3573681892693726107"),
+        MontFp!("11993921624332666824"),
+        MontFp!("5637703804742514875"),
+        MontFp!("8753552326812808939"),
+        MontFp!("6971293843562328783"),
+        MontFp!("4004628118448689322"),
+        MontFp!("4405467326570877937"),
+        MontFp!("14243337039016534419"),
+        MontFp!("718473357210099513"),
+        MontFp!("15545672716525863256"),
+        MontFp!("16658676627923930510"),
+        MontFp!("18268306349672206381"),
+        MontFp!("17971230472628852368"),
+        MontFp!("4820982429787540718"),
+        MontFp!("13849685052691485645"),
+        MontFp!("8500726556254398660"),
+        MontFp!("15769747084693521698"),
+        MontFp!("10985991283050418315"),
+        MontFp!("14198380726148119603"),
+        MontFp!("7491376156396984767"),
+        MontFp!("6688558629828821577"),
+        MontFp!("6366871303030837292"),
+        MontFp!("9755570427187471148"),
+        MontFp!("12484804632970370289"),
+        MontFp!("7579943380681704858"),
+        MontFp!("17713341626987836694"),
+        MontFp!("18227104100080540215"),
+        MontFp!("8652392812816368472"),
+        MontFp!("15036801530860758424"),
+        MontFp!("11364481599946226290"),
+        MontFp!("13028919369754160215"),
+        MontFp!("2535138656163017670"),
+        MontFp!("408858885489589288"),
+        MontFp!("9272686049584046061"),
+        MontFp!("6158658033209804210"),
+        MontFp!("2486621353205232151"),
+        MontFp!("6022622336639281797"),
+        MontFp!("818121086790687195"),
+        MontFp!("5686233274481850042"),
+        MontFp!("8989039603667525996"),
+        MontFp!("4740338910351605865"),
+        MontFp!("6876610064534652880"),
+        MontFp!("14967092118483138715"),
+        MontFp!("1958544583414854194"),
+        MontFp!("232407203431400950"),
+        MontFp!("1660484151633809438"),
+        MontFp!("403524699068106984"),
+        MontFp!("4550342962158154983"),
+        MontFp!("1058710352857569256"),
+        MontFp!("2570790160034472974"),
+        MontFp!("5404982856510634709"),
+        MontFp!("444138355015712257"),
+        MontFp!("486443741091992221"),
+        MontFp!("12844204528053478867"),
+        MontFp!("3460656590681231290"),
+        MontFp!("8006024306065096586"),
+        MontFp!("4630645032397881496"),
+        MontFp!("11357826928965215719"),
+        MontFp!("14258797803907196257"),
+        MontFp!("804558783875260503"),
+        MontFp!("11249494819796054706"),
+        MontFp!("2298855957713180162"),
+        MontFp!("9000274104837041245"),
+        MontFp!("5704336107994895645"),
+        MontFp!("11514510336586879463"),
+        MontFp!("11034533589926514278"),
+        MontFp!("13916809890705588599"),
+        MontFp!("3887531771301640859"),
+        MontFp!("3443948051452484398"),
+        MontFp!("6488196143845297281"),
+        MontFp!("10006110199649747279"),
+        MontFp!("3709618034933489652"),
+        MontFp!("2532593334644491055"),
+        MontFp!("8307056101406439027"),
+        MontFp!("17023059400465515467"),
+        MontFp!("1605196943540621094"),
+        MontFp!("2640940445266755335"),
+        MontFp!("15752087865609967492"),
+        MontFp!("8306405116738467388"),
+        MontFp!("16440981528566183469"),
+        MontFp!("10879524422539629432"),
+        MontFp!("6610816026512457417"),
+        MontFp!("5381633885056867029"),
+        MontFp!("14898097740062189279"),
+        MontFp!("5068568620848769097"),
+        MontFp!("10568746898045234106"),
+        MontFp!("4684211539213695287"),
+        MontFp!("9318672923223296635"),
+        MontFp!("6183493388078110681"),
+        MontFp!("10915072732429081520"),
+        MontFp!("14065610776309716095"),
+        MontFp!("825257713916521276"),
+        MontFp!("5882075489918154452"),
+        MontFp!("8343882501740142991"),
+        MontFp!("12176608802583695556"),
+        MontFp!("6414691005422886307"),
+        MontFp!("5371790108351507737"),
+        MontFp!("15136724167859416702"),
+        MontFp!("6022827038585154852"),
+        MontFp!("11076276801358708803"),
+        MontFp!("2686118076354818524"),
+        MontFp!("6458106127759048842"),
+        MontFp!("340364351364400435"),
+        MontFp!("3432340262630731084"),
+        MontFp!("2060637982148787654"),
+        MontFp!("14146183403683262842"),
+        MontFp!("5277367123156885684"),
+        MontFp!("15810402000147731866"),
+        MontFp!("7745991728312525142"),
+        MontFp!("15361052319696844642"),
+        MontFp!("3316400334260555540"),
+        MontFp!("16975167973154123329"),
+        MontFp!("16355376352421219485"),
+        MontFp!("6602996711117985547"),
+        MontFp!("17246593712800049673"),
+        MontFp!("1069779324280129963"),
+        MontFp!("2768103642381928348"),
+        MontFp!("7528140303114627458"),
+        MontFp!("2796218606310260548"),
+        MontFp!("14100245493181779641"),
+        MontFp!("14147416491283815139"),
+        MontFp!("11993818573696772723"),
+        MontFp!("2172416327774107663"),
+        MontFp!("12523372941547658843"),
+    ],
+];
+
+static M: &[&[&[Goldilocks]]] = &[
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[
+        &[
+            MontFp!("16211073532281865910"),
+            MontFp!("960857963967567752"),
+            MontFp!("17680937644252730833"),
+            MontFp!("18118527565789082852"),
+            MontFp!("2044505702268288475"),
+            MontFp!("12833526662378045093"),
+            MontFp!("1087151065958510096"),
+            MontFp!("6551534331601306365"),
+        ],
+        &[
+            MontFp!("3453311229634769244"),
+            MontFp!("6598804427317860107"),
+            MontFp!("12983599481795517031"),
+            MontFp!("8209657316819230139"),
+            MontFp!("11902972116786630908"),
+            MontFp!("4947700171508827644"),
+            MontFp!("2123959021988622312"),
+            MontFp!("5158861436897480331"),
+        ],
+        &[
+            MontFp!("17741132329511859826"),
+            MontFp!("2472725654904524865"),
+            MontFp!("8114171098551766464"),
+            MontFp!("10367784796031518264"),
+            MontFp!("485562958222894222"),
+            MontFp!("16161079849718009435"),
+            MontFp!("9446564380795591112"),
+            MontFp!("16969346249496837455"),
+        ],
+        &[
+            MontFp!("11557756717266971695"),
+            MontFp!("585422873812430346"),
+            MontFp!("14948729041716526530"),
+            MontFp!("772933340418745776"),
+            MontFp!("4716130717636822080"),
+            MontFp!("16450784631458966269"),
+            MontFp!("8860788226261554516"),
+            MontFp!("13450545008481765195"),
+        ],
+        &[
+            MontFp!("15235292118143324798"),
+            MontFp!("18399704590424857136"),
+            MontFp!("7727826173908103596"),
+            MontFp!("13129829808738328288"),
+            MontFp!("7020925249513582695"),
+            MontFp!("7155415857204584250"),
+            MontFp!("9021698666231475194"),
+            MontFp!("6220291678723846922"),
+        ],
+        &[
+            MontFp!("937681197017324186"),
+            MontFp!("4347464586189053808"),
+            MontFp!("755252484468243985"),
+            MontFp!("2988256536778393327"),
+            MontFp!("4026693014051249845"),
+            MontFp!("13941480612626205240"),
+            MontFp!("10504833900372317773"),
+            MontFp!("8410410891802060888"),
+        ],
+        &[
+            MontFp!("12791633989595279946"),
+            MontFp!("11629557115741773461"),
+            MontFp!("3001110535293363391"),
+            MontFp!("12427783218811044489"),
+            MontFp!("15269399404096831210"),
+            MontFp!("18021832263031183900"),
+            MontFp!("16499743075763659802"),
+            MontFp!("12132058804372064266"),
+        ],
+        &[
+            MontFp!("5466044363519414851"),
+            MontFp!("12786754586830405697"),
+            MontFp!("9993559460501212005"),
+            MontFp!("15663217274580508730"),
+            MontFp!("5059381506872559014"),
+            MontFp!("2141686235351294900"),
+            MontFp!("6637706025896936057"),
+            MontFp!("15266026752175088593"),
+        ],
+    ],
+    &[],
+    &[],
+    &[],
+    &[
+        &[
+            MontFp!("6853718788660640378"),
+            MontFp!("910372981460109511"),
+            MontFp!("4550383980844809404"),
+            MontFp!("14941891827681268059"),
+            MontFp!("6700593706368380205"),
+            MontFp!("1464592701418716781"),
+            MontFp!("16651522092327800490"),
+            MontFp!("12867715825780505384"),
+            MontFp!("8669977342621876645"),
+            MontFp!("4723170528088121657"),
+            MontFp!("8710286643867689786"),
+            MontFp!("9746509195838454492"),
+        ],
+        &[
+            MontFp!("17534148720471719593"),
+            MontFp!("16501700047218847596"),
+            MontFp!("12176542155139968289"),
+            MontFp!("6776922426991034753"),
+            MontFp!("388948971764643408"),
+            MontFp!("8468911540258843445"),
+            MontFp!("5665724660620329522"),
+            MontFp!("3841394515536869598"),
+            MontFp!("18304854186328102525"),
+            MontFp!("8283226384455625949"),
+            MontFp!("1640572935338549955"),
+            MontFp!("9189740103274159432"),
+        ],
+        &[
+            MontFp!("15885440949797630117"),
+            MontFp!("13700089194741970752"),
+            MontFp!("18434689073508243075"),
+            MontFp!("4573049114793120775"),
+            MontFp!("6817421031600357000"),
+            MontFp!("12169049003755364769"),
+            MontFp!("2789837381812140911"),
+            MontFp!("13928070979711514942"),
+            MontFp!("5015373060416880170"),
+            MontFp!("1130633449046674112"),
+            MontFp!("11936556242596564166"),
+            MontFp!("10495518399441228331"),
+        ],
+        &[
+            MontFp!("12114458062990589916"),
+            MontFp!("6837494007443998671"),
+            MontFp!("10256081535056591253"),
+            MontFp!("5474959738939117561"),
+            MontFp!("9185209234620958806"),
+            MontFp!("7956997586890409506"),
+            MontFp!("864574603153753835"),
+            MontFp!("2460617395475561691"),
+            MontFp!("15374537200716967788"),
+            MontFp!("12921892677653109921"),
+            MontFp!("9292490799047808682"),
+            MontFp!("6828311706182668584"),
+        ],
+        &[
+            MontFp!("8714231174896348249"),
+            MontFp!("17158071451457865734"),
+            MontFp!("8331385750664261427"),
+            MontFp!("14571845473045733606"),
+            MontFp!("7569449836838902374"),
+            MontFp!("13911912681306308939"),
+            MontFp!("9205080427373717720"),
+            MontFp!("1743761171174977213"),
+            MontFp!("1353618593974326258"),
+            MontFp!("10593395473021986321"),
+            MontFp!("11406770709260409940"),
+            MontFp!("1281802631895221414"),
+        ],
+        &[
+            MontFp!("11878741271330623911"),
+            MontFp!("3617997770469871787"),
+            MontFp!("7936919037061334656"),
+            MontFp!("7337976745285651816"),
+            MontFp!("9850218742075313766"),
+            MontFp!("8005797694759276986"),
+            MontFp!("11246026287821772530"),
+            MontFp!("13458325721170739451"),
+            MontFp!("11057807154561152913"),
+            MontFp!("5108551474999035936"),
+            MontFp!("10701700654124992994"),
+            MontFp!("5954771989030183638"),
+        ],
+        &[
+            MontFp!("1712733824915643859"),
+            MontFp!("8581243190179609597"),
+            MontFp!("2515534077992085245"),
+            MontFp!("16402205559682681488"),
+            MontFp!("3260579159819969596"),
+            MontFp!("13603716810309541946"),
+            MontFp!("6261950639506657212"),
+            MontFp!("2785126509649057588"),
+            MontFp!("13015800402736844386"),
+            MontFp!("855264854240983256"),
+            MontFp!("10483402262331761050"),
+            MontFp!("12867896954097176594"),
+        ],
+        &[
+            MontFp!("2470774152485139080"),
+            MontFp!("3276453967826994856"),
+            MontFp!("5495533482355462962"),
+            MontFp!("8728645264944194268"),
+            MontFp!("4866419766418347242"),
+            MontFp!("17395516865529065109"),
+            MontFp!("15263958447641562266"),
+            MontFp!("17600661697103100196"),
+            MontFp!("15238598366644826929"),
+            MontFp!("7153098992009453185"),
+            MontFp!("8346138553557062680"),
+            MontFp!("8600979660918065437"),
+        ],
+        &[
+            MontFp!("16088416846474101928"),
+            MontFp!("11207519751580700770"),
+            MontFp!("16232789375744707231"),
+            MontFp!("8067245457152964266"),
+            MontFp!("11834230726605153435"),
+            MontFp!("6911783353514344597"),
+            MontFp!("3284105323375304726"),
+            MontFp!("8865997525545061888"),
+            MontFp!("8889163813615369982"),
+            MontFp!("11175934016127298490"),
+            MontFp!("952832666818869564"),
+            MontFp!("9828145811865858610"),
+        ],
+        &[
+            MontFp!("11452796896963873196"),
+            MontFp!("1244882335891983199"),
+            MontFp!("14589993205061479164"),
+            MontFp!("15355911332729478823"),
+            MontFp!("5475274764259909192"),
+            MontFp!("4723565067206733975"),
+            MontFp!("1506113552906133885"),
+            MontFp!("10583059908407515452"),
+            MontFp!("17298946993822376179"),
+            MontFp!("12101928416288486013"),
+            MontFp!("6206830788573127128"),
+            MontFp!("11140075365818657060"),
+        ],
+        &[
+            MontFp!("18344131245273663554"),
+            MontFp!("15429365888853856009"),
+            MontFp!("16340289182969648206"),
+            MontFp!("5402089840723955674"),
+            MontFp!("13129304382161040604"),
+            MontFp!("10995461074882464783"),
+            MontFp!("10338624606361200953"),
+            MontFp!("473041289220121887"),
+            MontFp!("782312025169664245"),
+            MontFp!("1574921844348808248"),
+            MontFp!("4893754044960854050"),
+            MontFp!("14404933647501297131"),
+        ],
+        &[
+            MontFp!("14946394021413836919"),
+            MontFp!("11231550316066059901"),
+            MontFp!("6310661115675756580"),
+            MontFp!("16971328325701727478"),
+            MontFp!("13792948201586765449"),
+            MontFp!("14609530978078317620"),
+            MontFp!("6837695522853330781"),
+            MontFp!("11032366439203412396"),
+            MontFp!("17130344300153721578"),
+            MontFp!("1207683133808174865"),
+            MontFp!("15268718954399350124"),
+            MontFp!("6150255161517905092"),
+        ],
+    ],
+    &[],
+    &[],
+    &[],
+    &[
+        &[
+            MontFp!("15129610490767522552"),
+            MontFp!("8907593192140349748"),
+            MontFp!("3355978792099444424"),
+            MontFp!("751529599163033551"),
+            MontFp!("14769285995003855803"),
+            MontFp!("8681453877165531792"),
+            MontFp!("2112421767589247402"),
+            MontFp!("6398471097320582366"),
+            MontFp!("1002745640352256386"),
+            MontFp!("6834865471474545178"),
+            MontFp!("5269275254435286232"),
+            MontFp!("6459564172268430585"),
+            MontFp!("12289938998225462278"),
+            MontFp!("11391093624666509273"),
+            MontFp!("2884484951304822818"),
+            MontFp!("3253042961406896921"),
+        ],
+        &[
+            MontFp!("14783485469509501144"),
+            MontFp!("16086756078961570658"),
+            MontFp!("933992860657051552"),
+            MontFp!("5524660554870317016"),
+            MontFp!("12906747088309090989"),
+            MontFp!("6217969775842319558"),
+            MontFp!("10980175737766419199"),
+            MontFp!("344092583621378981"),
+            MontFp!("16318620673052137586"),
+            MontFp!("8729332942844604365"),
+            MontFp!("3782741073595818117"),
+            MontFp!("12638583925614908059"),
+            MontFp!("4734640548337541690"),
+            MontFp!("191074049009674765"),
+            MontFp!("1879887944144403246"),
+            MontFp!("17676414722669077765"),
+        ],
+        &[
+            MontFp!("2852551216564945879"),
+            MontFp!("7320424108537236744"),
+            MontFp!("1367346643199195824"),
+            MontFp!("6652331509047806320"),
+            MontFp!("7236299161342191122"),
+            MontFp!("5814261537549057656"),
+            MontFp!("9250101806157577837"),
+            MontFp!("2994513161768874642"),
+            MontFp!("7829550062108376685"),
+            MontFp!("3521846558561283353"),
+            MontFp!("17193599851975286612"),
+            MontFp!("10681500888469146583"),
+            MontFp!("15679142906547511650"),
+            MontFp!("12344046175959783211"),
+            MontFp!("14891732105800432810"),
+            MontFp!("10671812106488626529"),
+        ],
+        &[
+            MontFp!("5046307646489718650"),
+            MontFp!("9712366728624635464"),
+            MontFp!("4709609202781787909"),
+            MontFp!("13880264570414061326"),
+            MontFp!("6895640925705904436"),
+            MontFp!("14804414639437675157"),
+            MontFp!("10442115430489361829"),
+            MontFp!("13373919874475538745"),
+            MontFp!("9476961318744058682"),
+            MontFp!("1632105957706137255"),
+            MontFp!("3021120587482306209"),
+            MontFp!("6395952314579129834"),
+            MontFp!("17774870547415362171"),
+            MontFp!("5673270692631857270"),
+            MontFp!("4188768837976717849"),
+            MontFp!("5652893503277992211"),
+        ],
+        &[
+            MontFp!("129022353912219446"),
+            MontFp!("2528209054385063993"),
+            MontFp!("9018846144977440188"),
+            MontFp!("13495119626408384161"),
+            MontFp!("17579808014059579639"),
+            MontFp!("13020510764367912251"),
+            MontFp!("17243399052224999948"),
+            MontFp!("13176785230660375199"),
+            MontFp!("1034113552095176848"),
+            MontFp!("3991530691326326646"),
+            MontFp!("17745805649769794416"),
+            MontFp!("6608906183027663319"),
+            MontFp!("13197199693978089857"),
+            MontFp!("13043045220536753914"),
+            MontFp!("4927368450686584110"),
+            MontFp!("1963184895576576368"),
+        ],
+        &[
+            MontFp!("13426142391498942503"),
+            MontFp!("10864723189889088864"),
+            MontFp!("4994765806528411033"),
+            MontFp!("9939460523706084408"),
+            MontFp!("6804443468480825589"),
+            MontFp!("17398508752963974035"),
+            MontFp!("4101376459848384199"),
+            MontFp!("4453305866988489269"),
+            MontFp!("5652654093250399469"),
+            MontFp!("13609362505070960469"),
+            MontFp!("11824685627723181647"),
+            MontFp!("11812814817967256033"),
+            MontFp!("18374336661259259132"),
+            MontFp!("18309208086379622597"),
+            MontFp!("7385436729320528865"),
+            MontFp!("1062140114937663209"),
+        ],
+        &[
+            MontFp!("8592393934787320257"),
+            MontFp!("5028370286222058522"),
+            MontFp!("7250859373329563459"),
+            MontFp!("5332895343188540893"),
+            MontFp!("3405135021028070486"),
+            MontFp!("2637220085369926146"),
+            MontFp!("9233572157982683065"),
+            MontFp!("1730360836390426063"),
+            MontFp!("12447664367529822292"),
+            MontFp!("7408292333947822542"),
+            MontFp!("9542340397503665338"),
+            MontFp!("336161175595616898"),
+            MontFp!("7994989291935369326"),
+            MontFp!("14973090240541595035"),
+            MontFp!("6469788235817837549"),
+            MontFp!("9667387753361812631"),
+        ],
+        &[
+            MontFp!("12232021795406440585"),
+            MontFp!("1321357507136575755"),
+            MontFp!("7743251337645942497"),
+            MontFp!("9440451209687242802"),
+            MontFp!("17472923192065483523"),
+            MontFp!("2741181294149728809"),
+            MontFp!("14750395478413172467"),
+            MontFp!("14492203654388793940"),
+            MontFp!("9452360261026083599"),
+            MontFp!("12303466686129707146"),
+            MontFp!("7556350309793552249"),
+            MontFp!("3277058400227536684"),
+            MontFp!("5392177098633356202"),
+            MontFp!("8825100980650063862"),
+            MontFp!("11223622830573161248"),
+            MontFp!("4998220384910100317"),
+        ],
+        &[
+            MontFp!("10090798272316853467"),
+            MontFp!("7787901273206426476"),
+            MontFp!("12129046477368932572"),
+            MontFp!("1506720173969544924"),
+            MontFp!("11953836555676991017"),
+            MontFp!("14376512502298911219"),
+            MontFp!("8694164355568721288"),
+            MontFp!("13161916314656848993"),
+            MontFp!("957928413208924858"),
+            MontFp!("8320136591859198680"),
+            MontFp!("6971227762894698883"),
+            MontFp!("3727631977790907013"),
+            MontFp!("18351958477921104408"),
+            MontFp!("5726900110963218785"),
+            MontFp!("4605629544204385960"),
+            MontFp!("17272895674176695336"),
+        ],
+        &[
+            MontFp!("2259729382478666963"),
+            MontFp!("6925596483504249863"),
+            MontFp!("18120712708043674863"),
+            MontFp!("7628127241858929153"),
+            MontFp!("2651497738846484298"),
+            MontFp!("9409701523609854510"),
+            MontFp!("17414237077048852588"),
+            MontFp!("16091885159194342016"),
+            MontFp!("9153893971599350701"),
+            MontFp!("11234068083876348183"),
+            MontFp!("9974892137562803286"),
+            MontFp!("6992763593286550194"),
+            MontFp!("7190300112520097049"),
+            MontFp!("17878926008302943706"),
+            MontFp!("14067582676204031923"),
+            MontFp!("9439592530682468671"),
+        ],
+        &[
+            MontFp!("2245875741534588057"),
+            MontFp!("8826930349078748965"),
+            MontFp!("3539977803196216813"),
+            MontFp!("6913339467062203276"),
+            MontFp!("16954917277673247571"),
+            MontFp!("12412024990230981298"),
+            MontFp!("6003512672331165104"),
+            MontFp!("13082826585377463232"),
+            MontFp!("5170646360371225691"),
+            MontFp!("16120033424534666466"),
+            MontFp!("12138428381280359463"),
+            MontFp!("15384808046974442346"),
+            MontFp!("10512045825539260247"),
+            MontFp!("6414147375698751244"),
+            MontFp!("6209002823559534532"),
+            MontFp!("13857690864367284371"),
+        ],
+        &[
+            MontFp!("673385807069493018"),
+            MontFp!("1848350162272059191"),
+            MontFp!("13286670094450821352"),
+            MontFp!("1388342663859586523"),
+            MontFp!("10936370210109187641"),
+            MontFp!("10424604397133983412"),
+            MontFp!("4896820806255719856"),
+            MontFp!("10011222942365268997"),
+            MontFp!("7563313071056481288"),
+            MontFp!("5686728199077928825"),
+            MontFp!("15863080952752557496"),
+            MontFp!("431820859559938025"),
+            MontFp!("12127218609984348373"),
+            MontFp!("2271879547442707684"),
+            MontFp!("17914340849352699740"),
+            MontFp!("14880746468489434555"),
+        ],
+        &[
+            MontFp!("12368695362965937563"),
+            MontFp!("786168054986266683"),
+            MontFp!("2829272194806661559"),
+            MontFp!("3637918810070565948"),
+            MontFp!("10391397665410588101"),
+            MontFp!("7343282320168139351"),
+            MontFp!("7484409491560516076"),
+            MontFp!("8658430928880678602"),
+            MontFp!("2027724399800295633"),
+            MontFp!("5863827973842792166"),
+            MontFp!("4688540673602085112"),
+            MontFp!("16700725537144337069"),
+            MontFp!("13525830004242015616"),
+            MontFp!("11188532384985048794"),
+            MontFp!("16003889383786144467"),
+            MontFp!("9611798734640539396"),
+        ],
+        &[
+            MontFp!("708406431342424769"),
+            MontFp!("13083374680690460701"),
+            MontFp!("14304053137424518433"),
+            MontFp!("17195258942492766616"),
+            MontFp!("7407977859809623734"),
+            MontFp!("6718104907970848852"),
+            MontFp!("10744948760788198789"),
+            MontFp!("1660128737200437846"),
+            MontFp!("9254103448056419231"),
+            MontFp!("953064926769133713"),
+            MontFp!("2198044031984488873"),
+            MontFp!("8748931119541337242"),
+            MontFp!("7229556324392978229"),
+            MontFp!("6956406772102170907"),
+            MontFp!("4781301531386405537"),
+            MontFp!("17243697465337967782"),
+        ],
+        &[
+            MontFp!("12466353422840831243"),
+            MontFp!("14844642432336467905"),
+            MontFp!("3905877341507842914"),
+            MontFp!("15370301668337848775"),
+            MontFp!("14323232148936239877"),
+            MontFp!("13328456795983242791"),
+            MontFp!("4114968534563107092"),
+            MontFp!("4711501048426441755"),
+            MontFp!("1806532971451930994"),
+            MontFp!("1872249664132273050"),
+            MontFp!("17962684674995796644"),
+            MontFp!("4530648784172915562"),
+            MontFp!("15032390009098785112"),
+            MontFp!("1819665021985840519"),
+            MontFp!("13810171955109534370"),
+            MontFp!("2741406298046643985"),
+        ],
+        &[
+            MontFp!("7737731468889875452"),
+            MontFp!("2539065478486993486"),
+            MontFp!("16012826881332237627"),
+            MontFp!("3269512515095471693"),
+            MontFp!("7015659011487778052"),
+            MontFp!("17640442206414891190"),
+            MontFp!("2256395499953737556"),
+            MontFp!("15295291376449402064"),
+            MontFp!("4941064269623863067"),
+            MontFp!("6820531621224546094"),
+            MontFp!("14462229071182361598"),
+            MontFp!("5372099812487789561"),
+            MontFp!("14714788230654377226"),
+            MontFp!("10849799657642295150"),
+            MontFp!("1234476128343057273"),
+            MontFp!("2081555054331018315"),
+        ],
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_permutation;
+
+    // Poseidon reference implementation (zkhash `POSEIDON_GOLDILOCKS_12_PARAMS`)
+    #[test]
+    fn kats() {
+        assert_permutation(
+            &Poseidon::new_goldilocks(),
+            &[
+                "0xe9ad770762f48ef5",
+                "0xc12796961ddc7859",
+                "0xa61b71de9595e016",
+                "0xead9e6aa583aafa3",
+                "0x93e297beff76e95b",
+                "0x53abd3c5c2a0e924",
+                "0xf3bc50e655c74f51",
+                "0x246cac41b9a45d84",
+                "0xcc7f9314b2341f4f",
+                "0xf5f071587c83415c",
+                "0x09486cf35116fba3",
+                "0x9d82aaf136b5c38a",
+            ],
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Grain LFSR parameter generation from the Poseidon reference implementation.
+//!
+//! Mirrors `generate_parameters_grain.sage` for prime fields: the LFSR is seeded with the
+//! field, s-box, field size, width and round numbers, round constants are rejection
+//! sampled below the modulus and the MDS matrix is the Cauchy matrix `1 / (x_i + y_j)`
+//! over the next `2t` distinct samples. This reproduces every constant set shipped in
+//! this crate from its (field, t, R_F, R_P) parameters.
+//!
+//! The reference script additionally re-draws the matrix until it passes its
+//! subspace-trail checks; those checks are not performed here, so matrices for new
+//! parameter sets should be validated with the reference script before use.
+
+use alloc::vec::Vec;
+use ark_ff::{BigInteger, PrimeField};
+
+const STATE_BITS: usize = 80;
+
+pub struct GrainLfsr {
+    state: [bool; STATE_BITS],
+    head: usize,
+}
+
+impl GrainLfsr {
+    /// Seed the LFSR for a prime field of `n` bits, width `t` and the given round numbers.
+    ///
+    /// `sbox` is the 4-bit s-box code of the seed: circomlib's BN254 constants were
+    /// generated with 0, the reference instances shipped for the other fields with 1.
+    pub fn new(n: usize, t: usize, r_f: usize, r_p: usize, sbox: u8) -> GrainLfsr {
+        let mut state = [false; STATE_BITS];
+        let mut pos = 0;
+        let fields = [
+            (1, 2),
+            (sbox as usize, 4),
+            (n, 12),
+            (t, 12),
+            (r_f, 10),
+            (r_p, 10),
+        ];
+        for (value, bits) in fields {
+            for i in (0..bits).rev() {
+                state[pos] = (value >> i) & 1 == 1;
+                pos += 1;
+            }
+        }
+        for bit in state.iter_mut().skip(pos) {
+            *bit = true;
+        }
+
+        let mut lfsr = GrainLfsr { state, head: 0 };
+        for _ in 0..160 {
+            lfsr.step();
+        }
+        lfsr
+    }
+
+    fn step(&mut self) -> bool {
+        let s = |i: usize| self.state[(self.head + i) % STATE_BITS];
+        let bit = s(62) ^ s(51) ^ s(38) ^ s(23) ^ s(13) ^ s(0);
+        self.state[self.head] = bit;
+        self.head = (self.head + 1) % STATE_BITS;
+        bit
+    }
+
+    /// Next output bit of the self-shrinking generator
+    pub fn next_bit(&mut self) -> bool {
+        loop {
+            let select = self.step();
+            let bit = self.step();
+            if select {
+                return bit;
+            }
+        }
+    }
+
+    /// Next `n` output bits, most significant first
+    pub fn next_bits(&mut self, n: usize) -> Vec<bool> {
+        (0..n).map(|_| self.next_bit()).collect()
+    }
+
+    /// Next field element below the modulus, rejecting larger samples
+    pub fn next_field_element<F: PrimeField>(&mut self) -> F {
+        loop {
+            let bits = self.next_bits(F::MODULUS_BIT_SIZE as usize);
+            if let Some(x) = F::from_bigint(F::BigInt::from_bits_be(&bits)) {
+                return x;
+            }
+        }
+    }
+
+    /// Next sample reduced modulo the field order
+    pub fn next_field_element_reduced<F: PrimeField>(&mut self) -> F {
+        let bits = self.next_bits(F::MODULUS_BIT_SIZE as usize);
+        F::from_be_bytes_mod_order(&F::BigInt::from_bits_be(&bits).to_bytes_be())
+    }
+}
+
+/// Round constants (`t` per round, rounds back to back) and MDS matrix for width `t`,
+/// seeding the LFSR with the s-box code `sbox` (see `GrainLfsr::new`)
+pub fn generate_constants<F: PrimeField>(
+    t: usize,
+    r_f: usize,
+    r_p: usize,
+    sbox: u8,
+) -> (Vec<F>, Vec<Vec<F>>) {
+    let mut lfsr = GrainLfsr::new(F::MODULUS_BIT_SIZE as usize, t, r_f, r_p, sbox);
+    let c = (0..(r_f + r_p) * t)
+        .map(|_| lfsr.next_field_element())
+        .collect();
+    let m = mds_matrix(&mut lfsr, t);
+    (c, m)
+}
+
+/// Cauchy MDS matrix from the next `2t` distinct LFSR samples
+pub fn mds_matrix<F: PrimeField>(lfsr: &mut GrainLfsr, t: usize) -> Vec<Vec<F>> {
+    loop {
+        let mut samples: Vec<F> = (0..2 * t)
+            .map(|_| lfsr.next_field_element_reduced())
+            .collect();
+        while has_duplicates(&samples) {
+            samples = (0..2 * t)
+                .map(|_| lfsr.next_field_element_reduced())
+                .collect();
+        }
+        let (xs, ys) = samples.split_at(t);
+
+        let m: Option<Vec<Vec<F>>> = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| (*x + y).inverse()).collect())
+            .collect();
+        if let Some(m) = m {
+            return m;
+        }
+    }
+}
+
+fn has_duplicates<F: PrimeField>(xs: &[F]) -> bool {
+    xs.iter().enumerate().any(|(i, x)| xs[i + 1..].contains(x))
+}
@@ -0,0 +1,94 @@
+//! `hash_to_field` from RFC 9380 (hashing to elliptic curves), with
+//! `expand_message_xmd` over SHA-256 and 128-bit security.
+//!
+//! Each element is reduced from `L = ceil((ceil(log2 p) + 128) / 8)` uniform bytes, 48
+//! for BN254, so its distance from uniform is about 2^-128; reducing 32 bytes instead
+//! would be biased.
+
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use sha2::{Digest, Sha256};
+
+use crate::PoseidonError;
+
+const B_IN_BYTES: usize = 32;
+const S_IN_BYTES: usize = 64;
+/// Largest `L` supported, enough for fields up to 896 bits
+const MAX_L: usize = 128;
+
+/// Map `msg` to a BN254 scalar, separated from other uses by the domain tag `dst`
+pub fn hash_to_field(msg: &[u8], dst: &[u8]) -> Fr {
+    let mut out = [Fr::from(0u64)];
+    hash_to_field_elems(msg, dst, &mut out).expect("one element is in range");
+    out[0]
+}
+
+/// Fill `out` with independent elements of `F` derived from `msg` and `dst`.
+///
+/// Fails with `InvalidOutputLength` if `out` needs more than the 8160 bytes
+/// `expand_message_xmd` can produce.
+pub fn hash_to_field_elems<F: PrimeField>(
+    msg: &[u8],
+    dst: &[u8],
+    out: &mut [F],
+) -> Result<(), PoseidonError> {
+    let l = (F::MODULUS_BIT_SIZE as usize + 128).div_ceil(8);
+    assert!(l <= MAX_L, "field too large for hash_to_field");
+    let len_in_bytes = out.len() * l;
+    if out.is_empty() || len_in_bytes > 255 * B_IN_BYTES {
+        return Err(PoseidonError::InvalidOutputLength {
+            got: out.len(),
+            max: 255 * B_IN_BYTES / l,
+        });
+    }
+
+    let oversize: [u8; B_IN_BYTES];
+    let dst = if dst.len() > 255 {
+        oversize = Sha256::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize()
+            .into();
+        &oversize[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+    let b_0: [u8; B_IN_BYTES] = Sha256::new()
+        .chain_update([0u8; S_IN_BYTES])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize()
+        .into();
+
+    // Stream b_1, b_2, ... into one element's worth of bytes at a time; b_1 hashes
+    // b_0 itself, which is b_0 xor the all-zero initial block
+    let mut b_i = [0u8; B_IN_BYTES];
+    let mut used = B_IN_BYTES;
+    let mut i = 0u8;
+    let mut buf = [0u8; MAX_L];
+    for x in out.iter_mut() {
+        for byte in &mut buf[..l] {
+            if used == B_IN_BYTES {
+                let mut input = b_0;
+                input.iter_mut().zip(&b_i).for_each(|(a, b)| *a ^= b);
+                i += 1;
+                b_i = Sha256::new()
+                    .chain_update(input)
+                    .chain_update([i])
+                    .chain_update(dst)
+                    .chain_update(dst_len)
+                    .finalize()
+                    .into();
+                used = 0;
+            }
+            *byte = b_i[used];
+            used += 1;
+        }
+        *x = F::from_be_bytes_mod_order(&buf[..l]);
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::sponge::PoseidonSponge;
+use crate::{Poseidon, PoseidonError};
+
+/// Incremental Poseidon hasher.
+///
+/// Inputs fed through `update` are absorbed into a capacity-1 sponge as they arrive, so
+/// no input buffer is kept; `finalize` pads and squeezes a single element. The digest
+/// only depends on the concatenation of all updates and equals
+/// `Poseidon::hash_sponge(rate, inputs)`.
+pub struct PoseidonHasher<F: 'static = Fr> {
+    sponge: PoseidonSponge<F>,
+}
+
+#[cfg(feature = "width-3")]
+impl Default for PoseidonHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonHasher {
+    /// Hasher with rate 2 (t = 3), the cheapest width per absorbed element
+    #[cfg(feature = "width-3")]
+    pub fn new() -> PoseidonHasher {
+        PoseidonHasher::with_rate(2).unwrap()
+    }
+
+    pub fn with_rate(rate: usize) -> Result<PoseidonHasher, PoseidonError> {
+        PoseidonHasher::with_poseidon(Poseidon::new(), rate)
+    }
+}
+
+impl<F: PrimeField> PoseidonHasher<F> {
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        rate: usize,
+    ) -> Result<PoseidonHasher<F>, PoseidonError> {
+        Ok(PoseidonHasher {
+            sponge: PoseidonSponge::with_poseidon(poseidon, rate, 1)?,
+        })
+    }
+
+    pub fn update(&mut self, inp: &[F]) {
+        for x in inp {
+            self.sponge.absorb_one(*x);
+        }
+    }
+
+    pub fn finalize(mut self) -> F {
+        self.sponge.squeeze()
+    }
+}
+
+/// Wipes the absorbed state
+#[cfg(feature = "zeroize")]
+impl<F: PrimeField> zeroize::Zeroize for PoseidonHasher<F> {
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+    }
+}
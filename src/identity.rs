@@ -0,0 +1,94 @@
+//! Semaphore identity commitments and nullifiers.
+//!
+//! `IdentityV3` follows Semaphore v3, where an identity is a random trapdoor and
+//! nullifier; `Identity` (with the `eddsa` feature) follows v4, where it is a Baby Jubjub
+//! EdDSA key. Scopes and external nullifiers are taken as field elements: the Semaphore
+//! contracts and libraries derive them from arbitrary data with `keccak256(x) >> 8`,
+//! which is left to the caller.
+
+use ark_bn254::Fr;
+#[cfg(feature = "eddsa")]
+use ark_ff::PrimeField;
+
+#[cfg(feature = "eddsa")]
+use crate::eddsa::{PrivateKey, PublicKey};
+use crate::Poseidon;
+
+/// Semaphore v3 identity
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IdentityV3 {
+    pub trapdoor: Fr,
+    pub nullifier: Fr,
+}
+
+impl IdentityV3 {
+    pub fn new(trapdoor: Fr, nullifier: Fr) -> IdentityV3 {
+        IdentityV3 {
+            trapdoor,
+            nullifier,
+        }
+    }
+
+    /// `Poseidon([nullifier, trapdoor])`
+    pub fn secret(&self) -> Fr {
+        hash(&[self.nullifier, self.trapdoor])
+    }
+
+    /// `Poseidon([secret])`, the leaf added to the group
+    pub fn commitment(&self) -> Fr {
+        hash(&[self.secret()])
+    }
+
+    /// `Poseidon([external_nullifier, nullifier])`, the circuit's `nullifierHash`
+    pub fn nullifier_hash(&self, external_nullifier: Fr) -> Fr {
+        hash(&[external_nullifier, self.nullifier])
+    }
+}
+
+/// Semaphore v4 identity: an EdDSA key whose secret scalar is the circuit's `secret`
+#[cfg(feature = "eddsa")]
+pub struct Identity {
+    private_key: PrivateKey,
+    secret_scalar: Fr,
+    public_key: PublicKey,
+}
+
+#[cfg(feature = "eddsa")]
+impl Identity {
+    pub fn new(private_key: PrivateKey) -> Identity {
+        let scalar = private_key.secret_scalar();
+        Identity {
+            secret_scalar: Fr::from_bigint(scalar.into_bigint()).expect("l is below p"),
+            public_key: private_key.public_key(),
+            private_key,
+        }
+    }
+
+    pub fn private_key(&self) -> &PrivateKey {
+        &self.private_key
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    pub fn secret_scalar(&self) -> Fr {
+        self.secret_scalar
+    }
+
+    /// `Poseidon([A.x, A.y])`, the leaf added to the group
+    pub fn commitment(&self) -> Fr {
+        hash(&[self.public_key.0.x, self.public_key.0.y])
+    }
+
+    /// `Poseidon([scope, secret])`, the circuit's `nullifier`
+    pub fn nullifier(&self, scope: Fr) -> Fr {
+        hash(&[scope, self.secret_scalar])
+    }
+}
+
+fn hash(inp: &[Fr]) -> Fr {
+    Poseidon::new()
+        .hash(inp)
+        .expect("Poseidon width is not enabled")
+}
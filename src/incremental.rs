@@ -0,0 +1,200 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+#[cfg(feature = "alloc")]
+use crate::field_to_bytes;
+use crate::{bytes_to_field_strict, Poseidon, PoseidonError};
+
+/// Append-only Merkle tree of depth `DEPTH` storing only the rightmost filled subtree of
+/// each level, as in Tornado Cash's `MerkleTreeWithHistory`.
+///
+/// The state has a constant size, so it fits in a fixed-size Solana account. Nodes are
+/// `Poseidon([left, right])` and empty leaves are zero, so the root equals that of a
+/// `MerkleTree` over the appended leaves padded to `2^DEPTH` leaves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncrementalMerkleTree<const DEPTH: usize, F = Fr> {
+    filled_subtrees: [F; DEPTH],
+    // Roots of empty subtrees of height 0..DEPTH
+    zeros: [F; DEPTH],
+    root: F,
+    next_index: u64,
+}
+
+#[cfg(feature = "width-3")]
+impl<const DEPTH: usize> Default for IncrementalMerkleTree<DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const DEPTH: usize> IncrementalMerkleTree<DEPTH> {
+    #[cfg(feature = "width-3")]
+    pub fn new() -> IncrementalMerkleTree<DEPTH> {
+        IncrementalMerkleTree::with_poseidon(&Poseidon::new()).expect("BN254 supports width 3")
+    }
+
+    /// Append `leaf`, returning its index
+    pub fn append(&mut self, leaf: Fr) -> Result<u64, PoseidonError> {
+        self.append_with(&Poseidon::new(), leaf)
+    }
+
+    /// Tree saved by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<IncrementalMerkleTree<DEPTH>, PoseidonError> {
+        IncrementalMerkleTree::from_bytes_with(&Poseidon::new(), bytes)
+    }
+}
+
+impl<const DEPTH: usize, F: PrimeField> IncrementalMerkleTree<DEPTH, F> {
+    /// Empty tree hashed with the given Poseidon instance, which has to support two inputs
+    pub fn with_poseidon(
+        poseidon: &Poseidon<F>,
+    ) -> Result<IncrementalMerkleTree<DEPTH, F>, PoseidonError> {
+        const {
+            assert!(
+                DEPTH >= 1 && DEPTH < 64,
+                "Merkle tree depth must be in 1..64"
+            )
+        };
+        if !poseidon.supports_width(3) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: 2,
+                max: poseidon.max_inputs(),
+            });
+        }
+        let mut zeros = [F::zero(); DEPTH];
+        let mut root = F::zero();
+        for zero in zeros.iter_mut() {
+            *zero = root;
+            root = hash_pair(poseidon, root, root);
+        }
+        Ok(IncrementalMerkleTree {
+            filled_subtrees: zeros,
+            zeros,
+            root,
+            next_index: 0,
+        })
+    }
+
+    /// Append `leaf` hashing with `poseidon`, which has to be the instance the tree was
+    /// created with; returns the index of the leaf
+    pub fn append_with(&mut self, poseidon: &Poseidon<F>, leaf: F) -> Result<u64, PoseidonError> {
+        let index = self.next_index;
+        if index >= 1 << DEPTH {
+            return Err(PoseidonError::MerkleTreeFull);
+        }
+        let mut node = leaf;
+        for level in 0..DEPTH {
+            node = if (index >> level) & 1 == 0 {
+                self.filled_subtrees[level] = node;
+                hash_pair(poseidon, node, self.zeros[level])
+            } else {
+                hash_pair(poseidon, self.filled_subtrees[level], node)
+            };
+        }
+        self.root = node;
+        self.next_index = index + 1;
+        Ok(index)
+    }
+
+    /// Version of the `to_bytes` layout, its first byte
+    pub const STATE_VERSION: u8 = 1;
+
+    /// Encode the state for `from_bytes`: `STATE_VERSION`, `DEPTH` (u8), the leaf count
+    /// (u64, little-endian), the root and the filled subtrees from the leaves up, as
+    /// 32-byte little-endian field elements. The empty subtree roots are left out, as
+    /// they only depend on the Poseidon instance.
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(10 + 32 * (DEPTH + 1));
+        out.push(Self::STATE_VERSION);
+        out.push(DEPTH as u8);
+        out.extend_from_slice(&self.next_index.to_le_bytes());
+        for x in core::iter::once(&self.root).chain(&self.filled_subtrees) {
+            out.extend_from_slice(&field_to_bytes(x));
+        }
+        out
+    }
+
+    /// Restore the state saved by `to_bytes`, recomputing the empty subtree roots with
+    /// `poseidon`, which has to be the instance the tree was built with; fails with
+    /// `InvalidEncoding` on another version, depth or length, or a leaf count above the
+    /// capacity
+    pub fn from_bytes_with(
+        poseidon: &Poseidon<F>,
+        bytes: &[u8],
+    ) -> Result<IncrementalMerkleTree<DEPTH, F>, PoseidonError> {
+        let mut tree = IncrementalMerkleTree::with_poseidon(poseidon)?;
+        let (header, rest) = bytes
+            .split_first_chunk::<10>()
+            .ok_or(PoseidonError::InvalidEncoding)?;
+        let next_index = u64::from_le_bytes(header[2..].try_into().expect("8 bytes"));
+        if header[0] != Self::STATE_VERSION
+            || header[1] as usize != DEPTH
+            || rest.len() != 32 * (DEPTH + 1)
+            || next_index > tree.capacity()
+        {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+        let mut fields = rest
+            .chunks_exact(32)
+            .map(|chunk| bytes_to_field_strict(chunk.try_into().expect("chunks of 32 bytes")));
+        tree.root = fields.next().expect("DEPTH + 1 elements")?;
+        for (x, field) in tree.filled_subtrees.iter_mut().zip(fields) {
+            *x = field?;
+        }
+        tree.next_index = next_index;
+        Ok(tree)
+    }
+
+    pub fn root(&self) -> F {
+        self.root
+    }
+
+    /// Number of appended leaves
+    pub fn len(&self) -> u64 {
+        self.next_index
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next_index == 0
+    }
+
+    /// Number of leaves the tree holds
+    pub fn capacity(&self) -> u64 {
+        1 << DEPTH
+    }
+
+    // Stored state, for the serialized encodings
+    #[cfg(feature = "borsh")]
+    pub(crate) fn raw_parts(&self) -> (&[F; DEPTH], &[F; DEPTH], &F, u64) {
+        (
+            &self.filled_subtrees,
+            &self.zeros,
+            &self.root,
+            self.next_index,
+        )
+    }
+
+    #[cfg(feature = "borsh")]
+    pub(crate) fn from_raw_parts(
+        filled_subtrees: [F; DEPTH],
+        zeros: [F; DEPTH],
+        root: F,
+        next_index: u64,
+    ) -> Option<IncrementalMerkleTree<DEPTH, F>> {
+        (next_index <= 1 << DEPTH).then_some(IncrementalMerkleTree {
+            filled_subtrees,
+            zeros,
+            root,
+            next_index,
+        })
+    }
+}
+
+fn hash_pair<F: PrimeField>(poseidon: &Poseidon<F>, left: F, right: F) -> F {
+    poseidon
+        .hash(&[left, right])
+        .expect("width 3 is checked on construction")
+}
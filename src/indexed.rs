@@ -0,0 +1,346 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{MerklePath, Poseidon, PoseidonError};
+
+/// Leaf of an `IndexedMerkleTree`: a value and a pointer to the next larger value in
+/// the tree, or zeros if it holds the largest value
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct IndexedLeaf<F = Fr> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elem"))]
+    pub value: F,
+    pub next_index: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elem"))]
+    pub next_value: F,
+}
+
+impl IndexedLeaf {
+    /// `Poseidon([value, next_index, next_value])`
+    #[cfg(feature = "width-4")]
+    pub fn hash(&self) -> Fr {
+        self.hash_with(&Poseidon::new())
+            .expect("BN254 supports width 4")
+    }
+}
+
+impl<F: PrimeField> IndexedLeaf<F> {
+    /// `hash` with the given Poseidon instance, which has to support three inputs
+    pub fn hash_with(&self, poseidon: &Poseidon<F>) -> Result<F, PoseidonError> {
+        poseidon.hash3(self.value, F::from(self.next_index), self.next_value)
+    }
+
+    /// Whether `value` falls strictly between this leaf's value and the next, so it is
+    /// not in the tree and would be inserted after this leaf
+    pub fn is_low_leaf_of(&self, value: &F) -> bool {
+        self.value < *value && (self.next_value.is_zero() || *value < self.next_value)
+    }
+}
+
+/// Indexed Merkle tree of depth `DEPTH`, as used for Aztec-style nullifier sets.
+///
+/// Leaves are `IndexedLeaf`s appended in insertion order, and each points to the leaf
+/// with the next larger value, so the leaves form a sorted linked list starting at the
+/// zero leaf at index 0. A value is proven absent by the leaf whose range it falls
+/// into, its low leaf. Leaves hash as `Poseidon([value, next_index, next_value])`,
+/// nodes as `Poseidon([left, right])` and empty leaves are zero, as in a `MerkleTree`
+/// padded to `2^DEPTH` leaves. Values are ordered as integers below the modulus.
+#[derive(Clone)]
+pub struct IndexedMerkleTree<const DEPTH: usize, F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+    leaves: Vec<IndexedLeaf<F>>,
+    // layers[level] holds the nodes over the filled leaves, the rest are zeros[level];
+    // layers[DEPTH] is the root
+    layers: Vec<Vec<F>>,
+    zeros: Vec<F>,
+    indices: BTreeMap<F, u64>,
+}
+
+/// Proof that a value is not in an `IndexedMerkleTree`: its low leaf and that leaf's
+/// inclusion path
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct NonMembershipProof<F = Fr> {
+    pub low_leaf: IndexedLeaf<F>,
+    pub path: MerklePath<F>,
+}
+
+/// Proof that inserting a value turned one `IndexedMerkleTree` root into another
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct InsertionProof<F = Fr> {
+    /// The low leaf before its pointer was moved to the new leaf
+    pub low_leaf: IndexedLeaf<F>,
+    /// Path of the low leaf in the old tree
+    pub low_path: MerklePath<F>,
+    /// Path of the new leaf's slot, once the low leaf is updated
+    pub new_path: MerklePath<F>,
+}
+
+#[cfg(all(feature = "width-3", feature = "width-4"))]
+impl<const DEPTH: usize> Default for IndexedMerkleTree<DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const DEPTH: usize> IndexedMerkleTree<DEPTH> {
+    #[cfg(all(feature = "width-3", feature = "width-4"))]
+    pub fn new() -> IndexedMerkleTree<DEPTH> {
+        IndexedMerkleTree::with_poseidon(Poseidon::new()).expect("BN254 supports widths 3 and 4")
+    }
+}
+
+impl<const DEPTH: usize, F: PrimeField> IndexedMerkleTree<DEPTH, F> {
+    /// Tree holding only the zero leaf, over the given Poseidon instance, which has to
+    /// support two and three inputs
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+    ) -> Result<IndexedMerkleTree<DEPTH, F>, PoseidonError> {
+        const {
+            assert!(
+                DEPTH >= 1 && DEPTH < 64,
+                "Merkle tree depth must be in 1..64"
+            )
+        };
+        for t in [3, 4] {
+            if !poseidon.supports_width(t) {
+                return Err(PoseidonError::InvalidInputLength {
+                    got: t - 1,
+                    max: poseidon.max_inputs(),
+                });
+            }
+        }
+        let mut zeros = vec![F::zero()];
+        for level in 0..DEPTH {
+            zeros.push(hash_pair(&poseidon, zeros[level], zeros[level]));
+        }
+        let mut tree = IndexedMerkleTree {
+            poseidon,
+            leaves: Vec::new(),
+            layers: vec![Vec::new(); DEPTH + 1],
+            zeros,
+            indices: BTreeMap::new(),
+        };
+        tree.set_leaf(
+            0,
+            IndexedLeaf {
+                value: F::zero(),
+                next_index: 0,
+                next_value: F::zero(),
+            },
+        );
+        tree.indices.insert(F::zero(), 0);
+        Ok(tree)
+    }
+
+    pub fn root(&self) -> F {
+        self.layers[DEPTH][0]
+    }
+
+    /// Index the next inserted value goes to, the number of leaves including the zero
+    /// leaf
+    pub fn next_index(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    /// Number of leaves the tree holds
+    pub fn capacity(&self) -> u64 {
+        1 << DEPTH
+    }
+
+    pub fn contains(&self, value: &F) -> bool {
+        self.indices.contains_key(value)
+    }
+
+    /// Index of the leaf holding `value`
+    pub fn find(&self, value: &F) -> Option<u64> {
+        self.indices.get(value).copied()
+    }
+
+    pub fn leaf(&self, index: u64) -> Option<&IndexedLeaf<F>> {
+        self.leaves.get(usize::try_from(index).ok()?)
+    }
+
+    /// The leaf with the largest value below `value` and its index, or `None` for zero
+    pub fn low_leaf(&self, value: &F) -> Option<(u64, &IndexedLeaf<F>)> {
+        let (_, &index) = self.indices.range(..value).next_back()?;
+        Some((index, &self.leaves[index as usize]))
+    }
+
+    /// Inclusion proof of leaf `index`, or `None` if the index is out of range
+    pub fn prove(&self, index: u64) -> Option<MerklePath<F>> {
+        (index < self.next_index()).then(|| self.path(index))
+    }
+
+    /// Proof that `value` is not in the tree; fails if it is
+    pub fn prove_non_membership(&self, value: &F) -> Result<NonMembershipProof<F>, PoseidonError> {
+        if self.contains(value) {
+            return Err(PoseidonError::KeyAlreadyExists);
+        }
+        let (index, low_leaf) = self.low_leaf(value).expect("zero is in the tree");
+        Ok(NonMembershipProof {
+            low_leaf: *low_leaf,
+            path: self.path(index),
+        })
+    }
+
+    /// Insert `value` at `next_index`, pointing its low leaf at it; fails if the value is
+    /// already in the tree or the tree is full
+    pub fn insert(&mut self, value: F) -> Result<InsertionProof<F>, PoseidonError> {
+        if self.contains(&value) {
+            return Err(PoseidonError::KeyAlreadyExists);
+        }
+        let new_index = self.next_index();
+        if new_index >= self.capacity() {
+            return Err(PoseidonError::MerkleTreeFull);
+        }
+        let (low_index, &low_leaf) = self.low_leaf(&value).expect("zero is in the tree");
+        let low_path = self.path(low_index);
+        self.set_leaf(
+            low_index,
+            IndexedLeaf {
+                next_index: new_index,
+                next_value: value,
+                ..low_leaf
+            },
+        );
+        let new_path = self.path(new_index);
+        self.set_leaf(new_index, IndexedLeaf { value, ..low_leaf });
+        self.indices.insert(value, new_index);
+        Ok(InsertionProof {
+            low_leaf,
+            low_path,
+            new_path,
+        })
+    }
+
+    fn path(&self, index: u64) -> MerklePath<F> {
+        let (siblings, path_indices) = (0..DEPTH)
+            .map(|level| {
+                let i = (index >> level) as usize;
+                (self.node(level, i ^ 1), i & 1 == 1)
+            })
+            .unzip();
+        MerklePath {
+            siblings,
+            path_indices,
+        }
+    }
+
+    fn node(&self, level: usize, i: usize) -> F {
+        self.layers[level]
+            .get(i)
+            .copied()
+            .unwrap_or(self.zeros[level])
+    }
+
+    /// Store `leaf` at `index`, at most one past the last leaf, and rehash its path
+    fn set_leaf(&mut self, index: u64, leaf: IndexedLeaf<F>) {
+        let mut i = index as usize;
+        set(&mut self.leaves, i, leaf);
+        let mut node = leaf
+            .hash_with(&self.poseidon)
+            .expect("width 4 is checked on construction");
+        for level in 0..DEPTH {
+            set(&mut self.layers[level], i, node);
+            let sibling = self.node(level, i ^ 1);
+            node = if i & 1 == 1 {
+                hash_pair(&self.poseidon, sibling, node)
+            } else {
+                hash_pair(&self.poseidon, node, sibling)
+            };
+            i >>= 1;
+        }
+        set(&mut self.layers[DEPTH], 0, node);
+    }
+}
+
+impl NonMembershipProof {
+    /// Check that `value` is not in the tree with the given root
+    pub fn verify(&self, root: Fr, value: Fr) -> bool {
+        self.verify_with(&Poseidon::new(), root, value)
+    }
+}
+
+impl<F: PrimeField> NonMembershipProof<F> {
+    /// `verify` hashing with `poseidon`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>, root: F, value: F) -> bool {
+        self.low_leaf.is_low_leaf_of(&value)
+            && leaf_root(poseidon, &self.path, &self.low_leaf) == Some(root)
+    }
+}
+
+impl InsertionProof {
+    /// Check that inserting `value` into the tree with root `old_root` gives `new_root`
+    pub fn verify(&self, old_root: Fr, new_root: Fr, value: Fr) -> bool {
+        self.verify_with(&Poseidon::new(), old_root, new_root, value)
+    }
+}
+
+impl<F: PrimeField> InsertionProof<F> {
+    /// `verify` hashing with `poseidon`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>, old_root: F, new_root: F, value: F) -> bool {
+        self.compute_new_root(poseidon, old_root, value) == Some(new_root)
+    }
+
+    /// Root after inserting `value` into the tree with root `old_root`, or `None` if the
+    /// proof does not show `value` absent from it or the new slot empty.
+    ///
+    /// The slot of the new leaf is taken from `new_path`; callers tracking the tree size
+    /// should check that it is the next free index.
+    pub fn compute_new_root(&self, poseidon: &Poseidon<F>, old_root: F, value: F) -> Option<F> {
+        let low_leaf = &self.low_leaf;
+        if !low_leaf.is_low_leaf_of(&value)
+            || leaf_root(poseidon, &self.low_path, low_leaf)? != old_root
+        {
+            return None;
+        }
+        let updated = IndexedLeaf {
+            next_index: self.new_path.index(),
+            next_value: value,
+            ..*low_leaf
+        };
+        let root = leaf_root(poseidon, &self.low_path, &updated)?;
+        if self.new_path.compute_root(poseidon, F::zero())? != root {
+            return None;
+        }
+        let new_leaf = IndexedLeaf { value, ..*low_leaf };
+        leaf_root(poseidon, &self.new_path, &new_leaf)
+    }
+}
+
+fn leaf_root<F: PrimeField>(
+    poseidon: &Poseidon<F>,
+    path: &MerklePath<F>,
+    leaf: &IndexedLeaf<F>,
+) -> Option<F> {
+    path.compute_root(poseidon, leaf.hash_with(poseidon).ok()?)
+}
+
+fn set<T>(v: &mut Vec<T>, i: usize, x: T) {
+    if i == v.len() {
+        v.push(x);
+    } else {
+        v[i] = x;
+    }
+}
+
+fn hash_pair<F: PrimeField>(poseidon: &Poseidon<F>, left: F, right: F) -> F {
+    poseidon
+        .hash2(left, right)
+        .expect("width 3 is checked on construction")
+}
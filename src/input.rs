@@ -0,0 +1,170 @@
+//! Hash inputs from primitive integers and decimal or hex strings.
+//!
+//! `Fr` is a foreign type, so the conversions live on the `FieldInput` wrapper, whose
+//! `.0` is the element: `FieldInput::<Fr>::try_from("0x01")?.0`.
+//!
+//! 256-bit integers (`primitive_types::U256` and `num_bigint::BigUint`, behind the
+//! features of the same names) convert with `TryFrom`, which rejects values not below the
+//! modulus with `NonCanonicalBytes`, or with `from_u256_reduced`/`from_biguint_reduced`,
+//! which reduce them like the EVM's `addmod`. Elements convert back to their canonical
+//! integer with `From`.
+
+use core::str::FromStr;
+
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+#[cfg(feature = "primitive-types")]
+use crate::field_to_bytes_be;
+use crate::{bytes_to_field_be, PoseidonError};
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+#[cfg(feature = "primitive-types")]
+use primitive_types::U256;
+
+/// A field element converted from an integer or a string. Negative integers map to
+/// `p - |x|`, like circomlibjs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldInput<F = Fr>(pub F);
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl<F: PrimeField> From<$t> for FieldInput<F> {
+                fn from(x: $t) -> Self {
+                    FieldInput(F::from(x))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<F: PrimeField> From<$t> for FieldInput<F> {
+                fn from(x: $t) -> Self {
+                    let abs = F::from(x.unsigned_abs());
+                    FieldInput(if x < 0 { -abs } else { abs })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(bool, u8, u16, u32, u64, u128);
+impl_from_signed!(i8, i16, i32, i64, i128);
+
+/// Canonical: fails with `NonCanonicalBytes` if `x` is not below the modulus
+#[cfg(feature = "primitive-types")]
+impl<F: PrimeField> TryFrom<U256> for FieldInput<F> {
+    type Error = PoseidonError;
+
+    fn try_from(x: U256) -> Result<Self, Self::Error> {
+        bytes_to_field_be(&u256_bytes(x)).map(FieldInput)
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+impl<F: PrimeField> From<FieldInput<F>> for U256 {
+    fn from(x: FieldInput<F>) -> Self {
+        U256::from_big_endian(&field_to_bytes_be(&x.0))
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+fn u256_bytes(x: U256) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    x.to_big_endian(&mut bytes);
+    bytes
+}
+
+/// Canonical: fails with `NonCanonicalBytes` if `x` is not below the modulus
+#[cfg(feature = "num-bigint")]
+impl<F: PrimeField> TryFrom<&BigUint> for FieldInput<F> {
+    type Error = PoseidonError;
+
+    fn try_from(x: &BigUint) -> Result<Self, Self::Error> {
+        let modulus: BigUint = F::MODULUS.into();
+        if *x >= modulus {
+            return Err(PoseidonError::NonCanonicalBytes);
+        }
+        Ok(FieldInput::from_biguint_reduced(x))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<F: PrimeField> TryFrom<BigUint> for FieldInput<F> {
+    type Error = PoseidonError;
+
+    fn try_from(x: BigUint) -> Result<Self, Self::Error> {
+        FieldInput::try_from(&x)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<F: PrimeField> From<FieldInput<F>> for BigUint {
+    fn from(x: FieldInput<F>) -> Self {
+        x.0.into_bigint().into()
+    }
+}
+
+impl<F: PrimeField> FieldInput<F> {
+    /// `x mod p`, for words that may exceed the modulus, such as EVM storage slots
+    #[cfg(feature = "primitive-types")]
+    pub fn from_u256_reduced(x: U256) -> FieldInput<F> {
+        FieldInput(F::from_be_bytes_mod_order(&u256_bytes(x)))
+    }
+
+    /// `x mod p`
+    #[cfg(feature = "num-bigint")]
+    pub fn from_biguint_reduced(x: &BigUint) -> FieldInput<F> {
+        FieldInput(F::from_le_bytes_mod_order(&x.to_bytes_le()))
+    }
+}
+
+/// Parses like `parse_field`
+impl<F: PrimeField> TryFrom<&str> for FieldInput<F> {
+    type Error = PoseidonError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        parse_field(s).map(FieldInput)
+    }
+}
+
+impl<F: PrimeField> FromStr for FieldInput<F> {
+    type Err = PoseidonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_field(s).map(FieldInput)
+    }
+}
+
+/// Parse a decimal or `0x`-prefixed big-endian hex element of a field of at most 256
+/// bits.
+///
+/// Fails with `InvalidEncoding` if the string is empty, has other characters or exceeds
+/// 256 bits, and with `NonCanonicalBytes` if the value is not below the modulus.
+pub fn parse_field<F: PrimeField>(s: &str) -> Result<F, PoseidonError> {
+    let (digits, radix) = match s.strip_prefix("0x") {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() {
+        return Err(PoseidonError::InvalidEncoding);
+    }
+    // Big-endian accumulator: bytes = bytes * radix + digit
+    let mut bytes = [0u8; 32];
+    for c in digits.chars() {
+        let mut carry = c.to_digit(radix).ok_or(PoseidonError::InvalidEncoding)?;
+        for b in bytes.iter_mut().rev() {
+            let v = *b as u32 * radix + carry;
+            *b = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+    }
+    bytes_to_field_be(&bytes)
+}
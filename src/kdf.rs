@@ -0,0 +1,84 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::sponge::PoseidonSponge;
+use crate::{field_to_bytes, pack_bytes, Poseidon, PoseidonError};
+
+/// Capacity tag of KDF sponges, distinct from the MAC tag and the zero of plain hashing
+const KDF_DOMAIN: u64 = 0x6b6466;
+
+/// Poseidon key derivation.
+///
+/// Output elements are squeezed from a rate-2, capacity-1 sponge tagged with a KDF domain
+/// after absorbing the input keying material and the `info` context string, each preceded
+/// by its length so that different (ikm, info) splits never collide. `info` is packed into
+/// little-endian chunks of `(MODULUS_BIT_SIZE - 1) / 8` bytes, which always fit the field.
+pub struct PoseidonKdf<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+}
+
+#[cfg(feature = "width-3")]
+impl Default for PoseidonKdf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonKdf {
+    #[cfg(feature = "width-3")]
+    pub fn new() -> PoseidonKdf {
+        PoseidonKdf::with_poseidon(Poseidon::new()).expect("BN254 supports width 3")
+    }
+}
+
+impl<F: PrimeField> PoseidonKdf<F> {
+    /// KDF over the permutation of the given instance, which has to support width 3
+    pub fn with_poseidon(poseidon: Poseidon<F>) -> Result<PoseidonKdf<F>, PoseidonError> {
+        if !poseidon.supports_width(3) {
+            return Err(PoseidonError::InvalidSpongeParameters {
+                rate: 2,
+                capacity: 1,
+            });
+        }
+        Ok(PoseidonKdf { poseidon })
+    }
+
+    /// Fill `out` with elements derived from `ikm` and `info`; a shorter `out` yields a
+    /// prefix of a longer one
+    pub fn derive_into(&self, ikm: &[F], info: &[u8], out: &mut [F]) {
+        let mut sponge = self.sponge();
+        sponge.absorb_one(F::from(ikm.len() as u64));
+        for x in ikm {
+            sponge.absorb_one(*x);
+        }
+        sponge.absorb_one(F::from(info.len() as u64));
+        for x in pack_bytes(info) {
+            sponge.absorb_one(x);
+        }
+        for x in out.iter_mut() {
+            *x = sponge.squeeze();
+        }
+    }
+
+    /// `n` elements derived from `ikm` and `info`
+    #[cfg(feature = "alloc")]
+    pub fn derive(&self, ikm: &[F], info: &[u8], n: usize) -> Vec<F> {
+        let mut out = alloc::vec![F::zero(); n];
+        self.derive_into(ikm, info, &mut out);
+        out
+    }
+
+    /// A 32-byte key: the little-endian encoding of the first derived element
+    pub fn derive_key(&self, ikm: &[F], info: &[u8]) -> [u8; 32] {
+        let mut out = [F::zero()];
+        self.derive_into(ikm, info, &mut out);
+        field_to_bytes(&out[0])
+    }
+
+    fn sponge(&self) -> PoseidonSponge<F> {
+        PoseidonSponge::with_domain(self.poseidon.clone(), 2, 1, F::from(KDF_DOMAIN))
+            .expect("width 3 is checked on construction")
+    }
+}
@@ -0,0 +1,276 @@
+//! Multi-lane permutation: `L` independent states of the same width advanced in
+//! lock-step.
+//!
+//! With `std` on x86-64 CPUs with AVX-512 IFMA, the circomlib BN254 instance packs 8
+//! lanes into 512-bit registers of 52-bit limbs, see `avx512`. arkworks 0.4 has no
+//! vectorized field backend, so every other instance applies each step of the
+//! permutation to all lanes before the next one: the `L` Montgomery multiplications of
+//! a step have no data dependencies between them and the CPU overlaps them, and the
+//! round constants and matrix entries are loaded once per step instead of once per
+//! state.
+
+use ark_ff::PrimeField;
+
+use crate::fixed::{row, ArrayRounds};
+use crate::{Poseidon, PoseidonError};
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+mod avx512;
+
+impl<F: PrimeField> Poseidon<F> {
+    /// `hash` of each of `L` inputs of the same length, running the `L` permutations in
+    /// lock-step; fails if the lengths differ or are unsupported
+    pub fn hash_lanes<const L: usize>(&self, inputs: &[&[F]; L]) -> Result<[F; L], PoseidonError> {
+        let Some(n) = inputs.first().map(|inp| inp.len()) else {
+            return Ok([F::zero(); L]);
+        };
+        if let Some(got) = inputs
+            .iter()
+            .map(|inp| inp.len())
+            .find(|len| *len != n || !self.supports_width(len + 1))
+        {
+            return Err(PoseidonError::InvalidInputLength {
+                got,
+                max: self.max_inputs(),
+            });
+        }
+        #[cfg(feature = "solana")]
+        if let Some(bn254) = self.syscall_instance(n) {
+            let mut out = [F::zero(); L];
+            for (out, inp) in out.iter_mut().zip(inputs) {
+                *out = bn254.hash_fields_syscall(inp)?;
+            }
+            return Ok(out);
+        }
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        if let Some(out) = self.hash_lanes_avx512(n, inputs) {
+            return Ok(out);
+        }
+        macro_rules! hash_lanes {
+            ($($t:literal)*) => {
+                match n + 1 {
+                    $($t => Ok(self.hash_lanes_array::<L, $t>(inputs)),)*
+                    _ => unreachable!("width is checked above"),
+                }
+            };
+        }
+        hash_lanes!(2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25)
+    }
+
+    /// `hash_lanes` on the AVX-512 kernel, if `self` is the circomlib BN254 instance and
+    /// the CPU supports it
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    fn hash_lanes_avx512<const L: usize>(&self, n: usize, inputs: &[&[F]; L]) -> Option<[F; L]> {
+        use crate::{Constants, BN254_CONSTANTS, MAX_WIDTH};
+        use ark_bn254::Fr;
+        use ark_ff::BigInt;
+        use core::{any::Any, ptr};
+
+        match self.constants {
+            Constants::Static(k) if ptr::addr_eq(k, &BN254_CONSTANTS) => {}
+            _ => return None,
+        }
+        let mut out = [F::zero(); L];
+        let digests = (&mut out as &mut dyn Any).downcast_mut::<[Fr; L]>()?;
+        for (digests, lanes) in digests
+            .chunks_mut(avx512::LANES)
+            .zip(inputs.chunks(avx512::LANES))
+        {
+            let mut limbs = [[[0u64; 4]; avx512::LANES]; MAX_WIDTH - 1];
+            for (l, inp) in lanes.iter().enumerate() {
+                for (limbs, x) in limbs.iter_mut().zip(*inp) {
+                    limbs[l].copy_from_slice(x.into_bigint().as_ref());
+                }
+            }
+            let hashes = avx512::hash(&limbs[..n]);
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut limbs);
+            for (digest, hash) in digests.iter_mut().zip(hashes?) {
+                *digest = Fr::from_bigint(BigInt(hash)).expect("the kernel reduces its output");
+            }
+        }
+        Some(out)
+    }
+
+    fn hash_lanes_array<const L: usize, const T: usize>(&self, inputs: &[&[F]; L]) -> [F; L] {
+        let mut states = [[F::zero(); T]; L];
+        for (state, inp) in states.iter_mut().zip(inputs) {
+            state[1..].copy_from_slice(inp);
+        }
+        match self.array_rounds::<T>() {
+            Some(rounds) => self.permute_lanes(&rounds, &mut states),
+            None => states.iter_mut().for_each(|state| self.permute(state)),
+        }
+        let out = core::array::from_fn(|l| states[l][0]);
+        #[cfg(feature = "zeroize")]
+        states
+            .iter_mut()
+            .flatten()
+            .for_each(zeroize::Zeroize::zeroize);
+        out
+    }
+
+    // `fixed::permute_rounds` with every step applied to all lanes in turn
+    fn permute_lanes<const L: usize, const T: usize>(
+        &self,
+        k: &ArrayRounds<F, T>,
+        states: &mut [[F; T]; L],
+    ) {
+        let (n_rounds_f, n_rounds_p) = (k.n_rounds_f, k.n_rounds_p);
+        let half_f = n_rounds_f / 2;
+        let full_round = |states: &mut [[F; T]; L], round: usize| {
+            for (j, rc) in k.c[round * T..(round + 1) * T].iter().enumerate() {
+                for state in states.iter_mut() {
+                    state[j] += rc;
+                    self.sbox_pow(&mut state[j]);
+                }
+            }
+            mix_lanes(states, k.m);
+        };
+
+        for round in 0..half_f {
+            full_round(states, round);
+        }
+
+        for (j, rc) in k.opt_c[..T].iter().enumerate() {
+            for state in states.iter_mut() {
+                state[j] += rc;
+            }
+        }
+        mix_lanes(states, k.m_i);
+        let m00 = k.m[0][0];
+        for (r, sparse) in k.s.chunks_exact(2 * (T - 1)).enumerate() {
+            for state in states.iter_mut() {
+                self.sbox_pow(&mut state[0]);
+            }
+            if r + 1 < n_rounds_p {
+                let rc = k.opt_c[T + r];
+                for state in states.iter_mut() {
+                    state[0] += rc;
+                }
+            }
+            let (w_hat, v) = sparse.split_at(T - 1);
+            let mut row = [m00; T];
+            row[1..].copy_from_slice(w_hat);
+            let new_s0: [F; L] = core::array::from_fn(|l| F::sum_of_products(&row, &states[l]));
+            for (j, v) in v.iter().enumerate() {
+                for state in states.iter_mut() {
+                    let s0 = state[0];
+                    state[j + 1] += s0 * v;
+                }
+            }
+            for (state, s0) in states.iter_mut().zip(new_s0) {
+                state[0] = s0;
+            }
+        }
+
+        for round in (half_f + n_rounds_p)..(n_rounds_f + n_rounds_p) {
+            full_round(states, round);
+        }
+    }
+}
+
+fn mix_lanes<F: PrimeField, const L: usize, const T: usize>(states: &mut [[F; T]; L], m: &[&[F]]) {
+    #[allow(unused_mut)]
+    let mut old = *states;
+    for i in 0..T {
+        let row = row(m, i);
+        for (state, old) in states.iter_mut().zip(&old) {
+            state[i] = F::sum_of_products(row, old);
+        }
+    }
+    // As in `fixed::mix`, the copy holds full states
+    #[cfg(feature = "zeroize")]
+    old.iter_mut().flatten().for_each(zeroize::Zeroize::zeroize);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    // Every enabled width, with lane `l` mixing the edge values 0, 1 and p - 1 with small
+    // integers; `hash` has to agree with `crate::hash` on each lane
+    fn check<const L: usize>(hash: impl Fn(usize, &[&[Fr]; L]) -> [Fr; L]) {
+        let poseidon = Poseidon::new();
+        for n in (1..=24).filter(|n| poseidon.supports_width(n + 1)) {
+            let lanes: [[Fr; 24]; L] = core::array::from_fn(|l| {
+                core::array::from_fn(|j| match (l + j) % 4 {
+                    0 => Fr::from(0u64),
+                    1 => Fr::from(1u64),
+                    2 => -Fr::from(1u64),
+                    _ => Fr::from((l * 25 + j) as u64 + 2),
+                })
+            });
+            let inputs: [&[Fr]; L] = core::array::from_fn(|l| &lanes[l][..n]);
+            let expected: [Fr; L] = core::array::from_fn(|l| crate::hash(inputs[l]).unwrap());
+            assert_eq!(hash(n, &inputs), expected, "{} inputs, {} lanes", n, L);
+        }
+    }
+
+    // Lane counts below, at and between multiples of the kernel's 8 lanes
+    macro_rules! for_lane_counts {
+        ($check:ident) => {
+            $check::<1>();
+            $check::<3>();
+            $check::<8>();
+            $check::<11>();
+            $check::<16>();
+            $check::<19>();
+        };
+    }
+
+    fn check_hash_lanes<const L: usize>() {
+        check::<L>(|_, inputs| Poseidon::new().hash_lanes(inputs).unwrap());
+    }
+
+    fn check_portable<const L: usize>() {
+        check::<L>(|n, inputs| {
+            let poseidon = Poseidon::new();
+            macro_rules! portable {
+                ($($t:literal)*) => {
+                    match n + 1 {
+                        $($t => poseidon.hash_lanes_array::<L, $t>(inputs),)*
+                        _ => unreachable!(),
+                    }
+                };
+            }
+            portable!(2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25)
+        });
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    fn check_avx512<const L: usize>() {
+        check::<L>(|n, inputs| {
+            Poseidon::new()
+                .hash_lanes_avx512(n, inputs)
+                .expect("the kernel runs on this CPU")
+        });
+    }
+
+    #[test]
+    fn hash_lanes_matches_hash() {
+        for_lane_counts!(check_hash_lanes);
+    }
+
+    #[test]
+    fn portable_lanes_match_hash() {
+        for_lane_counts!(check_portable);
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[test]
+    fn avx512_kernel_matches_hash() {
+        if !avx512::available() {
+            std::eprintln!("skipping: the CPU lacks AVX-512 IFMA");
+            return;
+        }
+        for_lane_counts!(check_avx512);
+    }
+
+    #[test]
+    fn hash_lanes_rejects_mismatched_lengths() {
+        let (a, b) = ([Fr::from(1u64); 2], [Fr::from(1u64); 3]);
+        assert!(Poseidon::new().hash_lanes(&[&a[..], &b[..]]).is_err());
+    }
+}
@@ -0,0 +1,311 @@
+//! Eight-lane permutation of the circomlib BN254 instance on AVX-512 IFMA.
+//!
+//! A lane vector holds one state element of each of 8 states in five 52-bit limbs,
+//! limb `j` of all lanes in one 512-bit register, in Montgomery form with `R = 2^260`.
+//! `vpmadd52luq` and `vpmadd52huq` add the low and high halves of 52x52-bit products
+//! to 64-bit accumulators, so a multiplication is a 5x5 schoolbook product followed
+//! by a word-by-word Montgomery reduction, with carries propagated only at the end.
+//!
+//! Elements stay below `2p`. As `p < R / 64`, reducing a product of two elements below
+//! `4p`, or a sum of up to 24 products of elements below `2p` with reduced constants,
+//! again gives an element below `2p`; only the additions that are not followed by such
+//! a reduction need a conditional subtraction of `2p`.
+
+use ark_bn254::Fr;
+use ark_ff::{Field, PrimeField};
+use core::arch::x86_64::*;
+use std::sync::OnceLock;
+use std::vec::Vec;
+
+use crate::{Width, BN254_CONSTANTS, MAX_WIDTH};
+
+/// States per permutation
+pub(crate) const LANES: usize = 8;
+
+const MASK: u64 = (1 << 52) - 1;
+
+type Limbs = [u64; 5];
+
+/// One element of each lane, limb-sliced
+type Lanes = [__m512i; 5];
+
+const P: Limbs = to_limbs(Fr::MODULUS.0);
+
+const TWO_P: Limbs = to_limbs(double(Fr::MODULUS.0));
+
+/// `-p^-1 mod 2^52`
+const P_INV: u64 = {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(Fr::MODULUS.0[0].wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg() & MASK
+};
+
+const fn to_limbs(x: [u64; 4]) -> Limbs {
+    [
+        x[0] & MASK,
+        (x[0] >> 52 | x[1] << 12) & MASK,
+        (x[1] >> 40 | x[2] << 24) & MASK,
+        (x[2] >> 28 | x[3] << 36) & MASK,
+        x[3] >> 16,
+    ]
+}
+
+const fn from_limbs(x: Limbs) -> [u64; 4] {
+    [
+        x[0] | x[1] << 52,
+        x[1] >> 12 | x[2] << 40,
+        x[2] >> 24 | x[3] << 28,
+        x[3] >> 36 | x[4] << 16,
+    ]
+}
+
+const fn double(x: [u64; 4]) -> [u64; 4] {
+    [
+        x[0] << 1,
+        x[1] << 1 | x[0] >> 63,
+        x[2] << 1 | x[1] >> 63,
+        x[3] << 1 | x[2] >> 63,
+    ]
+}
+
+/// The optimized constants of one width in Montgomery form
+struct Tables {
+    n_rounds_f: usize,
+    n_rounds_p: usize,
+    /// `R^2 mod p`, multiplying into Montgomery form
+    r2: Limbs,
+    c: Vec<Limbs>,
+    m: Vec<Limbs>,
+    m_i: Vec<Limbs>,
+    opt_c: Vec<Limbs>,
+    s: Vec<Limbs>,
+}
+
+static TABLES: [OnceLock<Tables>; Width::COUNT] = [const { OnceLock::new() }; Width::COUNT];
+
+impl Tables {
+    fn get(t: usize) -> Option<&'static Tables> {
+        let k = &BN254_CONSTANTS;
+        let o = k.optimized?;
+        let w = Width::new(t).filter(|w| k.supports(*w))?;
+        let i = w.index();
+        Some(TABLES[i].get_or_init(|| {
+            let r = Fr::from(2u64).pow([260]);
+            let mont = |xs: &[Fr]| -> Vec<Limbs> {
+                xs.iter()
+                    .map(|x| to_limbs((*x * r).into_bigint().0))
+                    .collect()
+            };
+            Tables {
+                n_rounds_f: k.n_rounds_f,
+                n_rounds_p: k.n_rounds_p[i],
+                r2: to_limbs(r.square().into_bigint().0),
+                c: mont(k.c[i]),
+                m: k.m[i].iter().flat_map(|row| mont(row)).collect(),
+                m_i: o.m_i[i].iter().flat_map(|row| mont(row)).collect(),
+                opt_c: mont(o.c[i]),
+                s: mont(o.s[i]),
+            }
+        }))
+    }
+}
+
+/// Whether the CPU supports the kernel
+pub(crate) fn available() -> bool {
+    std::is_x86_feature_detected!("avx512f") && std::is_x86_feature_detected!("avx512ifma")
+}
+
+/// Hash the canonical little-endian limbs in `inputs[j][l]`, element `j` of the input of
+/// lane `l`, with the width `inputs.len() + 1` instance, if the CPU supports it and the
+/// width is enabled
+pub(crate) fn hash(inputs: &[[[u64; 4]; LANES]]) -> Option<[[u64; 4]; LANES]> {
+    if !available() {
+        return None;
+    }
+    let k = Tables::get(inputs.len() + 1)?;
+    // SAFETY: the target features are detected above
+    Some(unsafe { hash_avx512(k, inputs) })
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn hash_avx512(k: &Tables, inputs: &[[[u64; 4]; LANES]]) -> [[u64; 4]; LANES] {
+    let t = inputs.len() + 1;
+    let mut state = [[_mm512_setzero_si512(); 5]; MAX_WIDTH];
+    let r2 = splat(&k.r2);
+    for (x, inp) in state[1..t].iter_mut().zip(inputs) {
+        *x = mul(&load(inp), &r2);
+    }
+    permute(k, &mut state[..t]);
+
+    // Multiplying by 1 leaves Montgomery form
+    let out = reduce_once(mul(&state[0], &splat(&[1, 0, 0, 0, 0])), &P);
+    let mut limbs = [[0u64; LANES]; 5];
+    for (limb, v) in limbs.iter_mut().zip(out) {
+        // SAFETY: `limb` holds 8 u64s
+        unsafe { _mm512_storeu_epi64(limb.as_mut_ptr() as *mut i64, v) };
+    }
+    core::array::from_fn(|l| from_limbs(core::array::from_fn(|j| limbs[j][l])))
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn permute(k: &Tables, state: &mut [Lanes]) {
+    let t = state.len();
+    let half_f = k.n_rounds_f / 2;
+    let full_round = |state: &mut [Lanes], round: usize| {
+        for (x, rc) in state.iter_mut().zip(&k.c[round * t..]) {
+            *x = pow5(&normalize(&add(x, &splat(rc))));
+        }
+        mix(state, &k.m);
+    };
+
+    for round in 0..half_f {
+        full_round(state, round);
+    }
+
+    for (x, rc) in state.iter_mut().zip(&k.opt_c) {
+        *x = add_reduce(x, &splat(rc));
+    }
+    mix(state, &k.m_i);
+    let m00 = splat(&k.m[0]);
+    for (r, sparse) in k.s.chunks_exact(2 * (t - 1)).enumerate() {
+        state[0] = pow5(&state[0]);
+        if r + 1 < k.n_rounds_p {
+            state[0] = add_reduce(&state[0], &splat(&k.opt_c[t + r]));
+        }
+        let (w_hat, v) = sparse.split_at(t - 1);
+        let mut acc = [_mm512_setzero_si512(); 10];
+        mac(&mut acc, &state[0], &m00);
+        for (x, w) in state[1..].iter().zip(w_hat) {
+            mac(&mut acc, x, &splat(w));
+        }
+        let new_s0 = redc(acc);
+        let s0 = state[0];
+        for (x, v) in state[1..].iter_mut().zip(v) {
+            *x = add_reduce(x, &mul(&s0, &splat(v)));
+        }
+        state[0] = new_s0;
+    }
+
+    for round in (half_f + k.n_rounds_p)..(k.n_rounds_f + k.n_rounds_p) {
+        full_round(state, round);
+    }
+}
+
+/// `state = m * state` with one reduction per row
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn mix(state: &mut [Lanes], m: &[Limbs]) {
+    let t = state.len();
+    let mut old = [[_mm512_setzero_si512(); 5]; MAX_WIDTH];
+    old[..t].copy_from_slice(state);
+    for (x, row) in state.iter_mut().zip(m.chunks_exact(t)) {
+        let mut acc = [_mm512_setzero_si512(); 10];
+        for (y, m) in old.iter().zip(row) {
+            mac(&mut acc, y, &splat(m));
+        }
+        *x = redc(acc);
+    }
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn pow5(x: &Lanes) -> Lanes {
+    let x2 = mul(x, x);
+    let x4 = mul(&x2, &x2);
+    mul(&x4, x)
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn mul(a: &Lanes, b: &Lanes) -> Lanes {
+    let mut acc = [_mm512_setzero_si512(); 10];
+    mac(&mut acc, a, b);
+    redc(acc)
+}
+
+/// Add the 520-bit product `a * b` to `acc`, in unnormalized 52-bit limbs
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn mac(acc: &mut [__m512i; 10], a: &Lanes, b: &Lanes) {
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            acc[i + j] = _mm512_madd52lo_epu64(acc[i + j], *a, *b);
+            acc[i + j + 1] = _mm512_madd52hi_epu64(acc[i + j + 1], *a, *b);
+        }
+    }
+}
+
+/// Montgomery reduction `acc / R mod p`, below `p + acc / R`
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn redc(mut acc: [__m512i; 10]) -> Lanes {
+    let zero = _mm512_setzero_si512();
+    let p_inv = _mm512_set1_epi64(P_INV as i64);
+    let p = splat(&P);
+    for i in 0..5 {
+        let q = _mm512_madd52lo_epu64(zero, acc[i], p_inv);
+        for (j, p) in p.iter().enumerate() {
+            acc[i + j] = _mm512_madd52lo_epu64(acc[i + j], q, *p);
+            acc[i + j + 1] = _mm512_madd52hi_epu64(acc[i + j + 1], q, *p);
+        }
+        acc[i + 1] = _mm512_add_epi64(acc[i + 1], _mm512_srli_epi64::<52>(acc[i]));
+    }
+    normalize(&[acc[5], acc[6], acc[7], acc[8], acc[9]])
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn add(a: &Lanes, b: &Lanes) -> Lanes {
+    core::array::from_fn(|j| _mm512_add_epi64(a[j], b[j]))
+}
+
+/// `a + b` for `a, b < 2p`, below `2p`
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn add_reduce(a: &Lanes, b: &Lanes) -> Lanes {
+    reduce_once(normalize(&add(a, b)), &TWO_P)
+}
+
+/// Propagate the carries of limbs below `2^63`
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn normalize(x: &Lanes) -> Lanes {
+    let mask = _mm512_set1_epi64(MASK as i64);
+    let mut out = *x;
+    for j in 0..4 {
+        out[j + 1] = _mm512_add_epi64(out[j + 1], _mm512_srli_epi64::<52>(out[j]));
+        out[j] = _mm512_and_si512(out[j], mask);
+    }
+    out
+}
+
+/// `x - q` in the lanes where `x >= q`, for normalized `x`
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn reduce_once(x: Lanes, q: &Limbs) -> Lanes {
+    let mask = _mm512_set1_epi64(MASK as i64);
+    let mut borrow = _mm512_setzero_si512();
+    let mut d = [_mm512_setzero_si512(); 5];
+    for j in 0..5 {
+        let y = _mm512_sub_epi64(
+            _mm512_sub_epi64(x[j], _mm512_set1_epi64(q[j] as i64)),
+            borrow,
+        );
+        borrow = _mm512_srli_epi64::<63>(y);
+        d[j] = _mm512_and_si512(y, mask);
+    }
+    let keep = _mm512_test_epi64_mask(borrow, borrow);
+    core::array::from_fn(|j| _mm512_mask_blend_epi64(keep, d[j], x[j]))
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn splat(x: &Limbs) -> Lanes {
+    core::array::from_fn(|j| _mm512_set1_epi64(x[j] as i64))
+}
+
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn load(x: &[[u64; 4]; LANES]) -> Lanes {
+    let limbs = x.map(to_limbs);
+    let mut out = [_mm512_setzero_si512(); 5];
+    for (j, v) in out.iter_mut().enumerate() {
+        let limb: [u64; LANES] = core::array::from_fn(|l| limbs[l][j]);
+        // SAFETY: `limb` holds 8 u64s
+        *v = unsafe { _mm512_loadu_epi64(limb.as_ptr() as *const i64) };
+    }
+    out
+}
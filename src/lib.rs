@@ -1,25 +1,1188 @@
-use solana_program::poseidon::{hashv, Endianness, Parameters};
+#![no_std]
 
-pub struct Poseidon;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{sync::Arc, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::{Field, PrimeField};
+#[cfg(feature = "solana")]
+use core::{any::Any, ptr};
+#[cfg(feature = "solana")]
+use solana_program::{
+    poseidon::{hashv, Endianness, Parameters},
+    pubkey::Pubkey,
+};
+
+#[cfg(feature = "ark-sponge")]
+pub mod ark_sponge;
+#[cfg(feature = "bls12-381")]
+pub mod bls12_381;
+#[cfg(feature = "borsh")]
+mod borsh_impls;
+pub mod chain;
+#[cfg(feature = "alloc")]
+pub mod cipher;
+#[cfg(feature = "serde_json")]
+pub mod circom;
+pub mod commitment;
+pub mod constants;
+pub mod cost;
+#[cfg(feature = "ark-crh")]
+pub mod crh;
+#[cfg(feature = "eddsa")]
+pub mod eddsa;
+pub mod error;
+pub mod evm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fixed;
+#[cfg(feature = "goldilocks")]
+pub mod goldilocks;
+#[cfg(feature = "alloc")]
+pub mod grain;
+#[cfg(feature = "hash-to-field")]
+pub mod hash_to_field;
+pub mod hasher;
+pub mod identity;
+pub mod incremental;
+#[cfg(feature = "alloc")]
+pub mod indexed;
+pub mod input;
+pub mod kdf;
+#[cfg(feature = "lanes")]
+mod lanes;
+pub mod light_poseidon;
+pub mod mac;
+#[cfg(feature = "alloc")]
+pub mod merkle;
+#[cfg(feature = "alloc")]
+pub mod mmr;
+#[cfg(feature = "neptune-compat")]
+pub mod neptune;
+pub mod optimized;
+pub mod output;
+#[cfg(feature = "alloc")]
+pub mod params;
+#[cfg(feature = "pasta")]
+pub mod pasta;
+pub mod poseidon2;
+#[cfg(all(feature = "digest", feature = "width-3"))]
+pub mod poseidon256;
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+#[cfg(feature = "std")]
+pub mod reader;
+pub mod rng;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "alloc")]
+pub mod smt;
+pub mod sponge;
+mod static_constants;
+mod static_optimized_constants;
+#[cfg(feature = "alloc")]
+pub mod trace;
+pub mod transcript;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod width;
+
+pub use chain::PoseidonChain;
+#[cfg(feature = "alloc")]
+pub use cipher::PoseidonCipher;
+#[cfg(feature = "serde_json")]
+pub use circom::CircomMerkleProof;
+pub use commitment::PoseidonCommitment;
+pub use error::PoseidonError;
+pub use fixed::{Arity, SupportedArity};
+pub use hasher::PoseidonHasher;
+pub use incremental::IncrementalMerkleTree;
+#[cfg(feature = "alloc")]
+pub use indexed::{IndexedLeaf, IndexedMerkleTree, InsertionProof, NonMembershipProof};
+pub use input::{parse_field, FieldInput};
+pub use kdf::PoseidonKdf;
+pub use mac::PoseidonMac;
+#[cfg(feature = "alloc")]
+pub use merkle::{MerklePath, MerkleTree, NaryMerkleProof, NaryMerkleTree};
+#[cfg(feature = "alloc")]
+pub use mmr::{MerkleMountainRange, MmrProof};
+pub use output::PoseidonDigest;
+#[cfg(feature = "alloc")]
+pub use params::PoseidonParams;
+pub use poseidon2::Poseidon2;
+#[cfg(all(feature = "digest", feature = "width-3"))]
+pub use poseidon256::Poseidon256;
+#[cfg(all(feature = "std", feature = "width-3"))]
+pub use reader::hash_reader;
+#[cfg(feature = "std")]
+pub use reader::{hash_reader_with, Chunking};
+pub use rng::PoseidonRng;
+#[cfg(feature = "alloc")]
+pub use smt::{SmtProof, SparseMerkleTree};
+pub use sponge::PoseidonSponge;
+pub use transcript::PoseidonTranscript;
+pub use width::Width;
+
+#[cfg(feature = "solana")]
+use cost::SYSCALL_MAX_INPUTS;
+#[cfg(feature = "alloc")]
+use optimized::OptimizedConstants;
+use optimized::StaticOptimizedConstants;
+use static_constants::{C_CONSTANTS, M_CONSTANTS, N_ROUNDS_F, N_ROUNDS_P};
+use static_optimized_constants::{OPT_C_CONSTANTS, OPT_M_CONSTANTS, OPT_S_CONSTANTS};
+
+/// Largest supported state width
+pub(crate) const MAX_WIDTH: usize = Width::MAX.get();
+
+/// Fan-in of the tree `Poseidon::hash_unbounded` folds long inputs with, the largest
+/// circomlib `Poseidon(n)` template
+pub const UNBOUNDED_ARITY: usize = 16;
+
+/// Round constants and MDS matrices for a Poseidon instance over `F`.
+///
+/// Entry `Width::index` (`t - 2`) of `c`, `m` and `n_rounds_p` holds the parameters for
+/// width `t`, so a constant set supporting `n` inputs covers widths 2..=n+1. The `c`
+/// entry stores the constants of all rounds back to back (`t` per round); an empty entry
+/// marks width `t` as unsupported. `PoseidonConstants::width` reads the entries of one
+/// width.
+pub struct PoseidonConstants<F: 'static> {
+    pub c: &'static [&'static [F]],
+    pub m: &'static [&'static [&'static [F]]],
+    /// S-box exponent, -1 for the inverse s-box
+    pub alpha: i64,
+    pub n_rounds_f: usize,
+    pub n_rounds_p: &'static [usize],
+    /// Sparse partial-round form of the same constants, used by the permutation when set
+    pub optimized: Option<&'static StaticOptimizedConstants<F>>,
+}
+
+/// circomlib constants over BN254.
+///
+/// circomlib defines widths up to 17; widths 18..=25 extend it with Grain LFSR constants
+/// generated the same way (see `generate_constants.rs`) and are not circomlib compatible.
+/// Only widths whose `width-{t}` cargo feature is enabled (all of them with the default
+/// `all-widths` feature) carry constants; the other entries are empty.
+pub static BN254_CONSTANTS: PoseidonConstants<Fr> = PoseidonConstants {
+    c: C_CONSTANTS,
+    m: M_CONSTANTS,
+    alpha: 5,
+    n_rounds_f: N_ROUNDS_F,
+    n_rounds_p: N_ROUNDS_P,
+    optimized: Some(&BN254_OPTIMIZED_CONSTANTS),
+};
+
+static BN254_OPTIMIZED_CONSTANTS: StaticOptimizedConstants<Fr> = StaticOptimizedConstants {
+    c: OPT_C_CONSTANTS,
+    m_i: OPT_M_CONSTANTS,
+    s: OPT_S_CONSTANTS,
+};
+
+/// Apply the Poseidon permutation in place to a state of width `T`.
+///
+/// Runs the full circomlib round schedule (R_F = 8 full rounds, R_P partial rounds for
+/// width `T`) with the same round constants and MDS matrix used by `Poseidon::hash`, so
+/// `hash(inputs)` equals `state[0]` after permuting `[0, inputs...]`. Widths outside
+/// 2..=25 are rejected at compile time; panics if the `width-{T}` feature is disabled.
+pub fn permutation<const T: usize>(state: &mut [Fr; T]) {
+    assert!(
+        BN254_CONSTANTS.supports(Width::of::<T>()),
+        "Poseidon width {} is not enabled",
+        T
+    );
+    Poseidon::new().permute(state);
+}
+
+/// Poseidon hasher over `F`, defaulting to the circomlib BN254 instance
+pub struct Poseidon<F: 'static = Fr> {
+    constants: Constants<F>,
+}
+
+enum Constants<F: 'static> {
+    Static(&'static PoseidonConstants<F>),
+    #[cfg(feature = "alloc")]
+    Params(Arc<ParamsInstance<F>>),
+}
+
+#[cfg(feature = "alloc")]
+struct ParamsInstance<F> {
+    params: PoseidonParams<F>,
+    optimized: Option<OptimizedConstants<F>>,
+}
+
+impl<F> Clone for Poseidon<F> {
+    fn clone(&self) -> Self {
+        let constants = match &self.constants {
+            Constants::Static(c) => Constants::Static(c),
+            #[cfg(feature = "alloc")]
+            Constants::Params(p) => Constants::Params(Arc::clone(p)),
+        };
+        Poseidon { constants }
+    }
+}
+
+impl Default for Poseidon {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Poseidon {
-    pub fn new() -> Poseidon {
-        Poseidon
+    /// circomlib Poseidon over BN254.
+    ///
+    /// The constant tables are `MontFp!` literals, converted to Montgomery form by the
+    /// compiler, so construction and the first hash do no setup work, and the instance
+    /// can live in a `static`.
+    pub const fn new() -> Poseidon {
+        Poseidon {
+            constants: Constants::Static(&BN254_CONSTANTS),
+        }
+    }
+}
+
+/// `Poseidon::new().hash(inp)`, the circomlib BN254 hash of 1 to 24 elements
+pub fn hash(inp: &[Fr]) -> Result<Fr, PoseidonError> {
+    Poseidon::new().hash(inp)
+}
+
+/// `Poseidon::new().hash_unbounded(inp)`, the circomlib BN254 hash of any non-empty
+/// number of elements
+pub fn hash_unbounded(inp: &[Fr]) -> Result<Fr, PoseidonError> {
+    Poseidon::new().hash_unbounded(inp)
+}
+
+/// `Poseidon::new().hash_bytes(inputs, mode)` over 32-byte little-endian elements
+pub fn hash_bytes(inputs: &[&[u8; 32]], mode: DecodeMode) -> Result<PoseidonDigest, PoseidonError> {
+    Poseidon::new().hash_bytes(inputs, mode)
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    /// Poseidon instance using the given constant set (at most 24 inputs)
+    pub const fn with_constants(constants: &'static PoseidonConstants<F>) -> Poseidon<F> {
+        assert!(constants.n_rounds_p.len() <= Width::COUNT);
+        Poseidon {
+            constants: Constants::Static(constants),
+        }
+    }
+
+    /// Poseidon instance for the single width of `params`; the sparse partial-round
+    /// constants are derived once here
+    #[cfg(feature = "alloc")]
+    pub fn with_params(params: PoseidonParams<F>) -> Poseidon<F> {
+        let optimized =
+            optimized::optimize(params.n_rounds_f, params.n_rounds_p, &params.c, &params.m);
+        Poseidon {
+            constants: Constants::Params(Arc::new(ParamsInstance { params, optimized })),
+        }
     }
 
-    /// Hash function that uses Solana's native Poseidon syscall
-    pub fn hash_bytes(&self, inputs: &[&[u8; 32]]) -> Result<[u8; 32], String> {
+    /// The static constant set, if the instance was not built from `PoseidonParams`
+    pub fn constants(&self) -> Option<&'static PoseidonConstants<F>> {
+        match self.constants {
+            Constants::Static(c) => Some(c),
+            #[cfg(feature = "alloc")]
+            Constants::Params(_) => None,
+        }
+    }
+
+    /// Maximum number of inputs accepted by `hash`
+    pub fn max_inputs(&self) -> usize {
+        match &self.constants {
+            Constants::Static(c) => c.n_rounds_p.len(),
+            #[cfg(feature = "alloc")]
+            Constants::Params(p) => p.params.width() - 1,
+        }
+    }
+
+    /// Whether the constant set provides parameters for state width `t`
+    pub fn supports_width(&self, t: usize) -> bool {
+        match &self.constants {
+            Constants::Static(c) => c.supports_width(t),
+            #[cfg(feature = "alloc")]
+            Constants::Params(p) => t == p.params.width(),
+        }
+    }
+
+    /// Add the round constants of round `it` to the state
+    pub fn ark(&self, state: &mut [F], c: &[F], it: usize) {
+        for (s, c) in state.iter_mut().zip(&c[it..]) {
+            *s += c;
+        }
+    }
+
+    /// Apply x^alpha to the full state in full rounds and to state[0] in partial rounds;
+    /// with alpha = -1 zero maps to zero
+    pub fn sbox(&self, n_rounds_f: usize, n_rounds_p: usize, state: &mut [F], i: usize) {
+        if i < n_rounds_f / 2 || i >= n_rounds_f / 2 + n_rounds_p {
+            for s in state.iter_mut() {
+                self.sbox_pow(s);
+            }
+        } else {
+            self.sbox_pow(&mut state[0]);
+        }
+    }
+
+    fn sbox_pow(&self, x: &mut F) {
+        let alpha = match &self.constants {
+            Constants::Static(c) => c.alpha,
+            #[cfg(feature = "alloc")]
+            Constants::Params(p) => p.params.alpha,
+        };
+        match alpha {
+            5 => pow5(x),
+            -1 => *x = x.inverse().unwrap_or(F::zero()),
+            alpha => *x = x.pow([alpha as u64]),
+        }
+    }
+
+    /// Multiply the state by the MDS matrix without allocating
+    pub fn mix_inplace<R: AsRef<[F]>>(&self, state: &mut [F], m: &[R]) {
+        let t = state.len();
+        let mut new_state = [F::zero(); MAX_WIDTH];
+        for (i, row) in m.iter().enumerate().take(t) {
+            for (mij, s) in row.as_ref().iter().zip(state.iter()) {
+                new_state[i] += *mij * s;
+            }
+        }
+        state.copy_from_slice(&new_state[..t]);
+        // The scratch copy holds a full state, from which the permutation's input can be
+        // recovered
+        #[cfg(feature = "zeroize")]
+        new_state[..t]
+            .iter_mut()
+            .for_each(zeroize::Zeroize::zeroize);
+    }
+
+    /// Apply the permutation to `state`, whose length has to be `width`; fails with
+    /// `InvalidInputLength` if it is not or the instance does not support the width
+    pub fn permute_width(&self, width: Width, state: &mut [F]) -> Result<(), PoseidonError> {
+        if state.len() != width.get() || !self.supports_width(width.get()) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: state.len().saturating_sub(1),
+                max: self.max_inputs(),
+            });
+        }
+        self.permute(state);
+        Ok(())
+    }
+
+    /// Run the full round schedule over a state of a supported width
+    pub(crate) fn permute(&self, state: &mut [F]) {
+        match &self.constants {
+            Constants::Static(k) => {
+                let w = Width::new(state.len()).expect("supported width");
+                let i = w.index();
+                let (n_rounds_f, n_rounds_p) = (k.n_rounds_f, k.n_rounds_p[i]);
+                match k.optimized {
+                    Some(o) => self.rounds_optimized(
+                        state, n_rounds_f, n_rounds_p, k.c[i], k.m[i], o.c[i], o.m_i[i], o.s[i],
+                    ),
+                    None => self.rounds(state, n_rounds_f, n_rounds_p, k.c[i], k.m[i]),
+                }
+            }
+            #[cfg(feature = "alloc")]
+            Constants::Params(p) => {
+                let (k, o) = (&p.params, &p.optimized);
+                match o {
+                    Some(o) => self.rounds_optimized(
+                        state,
+                        k.n_rounds_f,
+                        k.n_rounds_p,
+                        &k.c,
+                        &k.m,
+                        &o.c,
+                        &o.m_i,
+                        &o.s,
+                    ),
+                    None => self.rounds(state, k.n_rounds_f, k.n_rounds_p, &k.c, &k.m),
+                }
+            }
+        }
+    }
+
+    /// Reference schedule: ark, sbox and a dense MDS multiplication in every round
+    fn rounds<R: AsRef<[F]>>(
+        &self,
+        state: &mut [F],
+        n_rounds_f: usize,
+        n_rounds_p: usize,
+        c: &[F],
+        m: &[R],
+    ) {
+        let t = state.len();
+        for i in 0..(n_rounds_f + n_rounds_p) {
+            self.ark(state, c, i * t);
+            self.sbox(n_rounds_f, n_rounds_p, state, i);
+            self.mix_inplace(state, m);
+        }
+    }
+
+    /// Same permutation as `rounds`, with the partial rounds in sparse form (see
+    /// `optimized`)
+    #[allow(clippy::too_many_arguments)]
+    fn rounds_optimized<R: AsRef<[F]>, Q: AsRef<[F]>>(
+        &self,
+        state: &mut [F],
+        n_rounds_f: usize,
+        n_rounds_p: usize,
+        c: &[F],
+        m: &[R],
+        opt_c: &[F],
+        m_i: &[Q],
+        s: &[F],
+    ) {
+        let t = state.len();
+        let half_f = n_rounds_f / 2;
+        for i in 0..half_f {
+            self.ark(state, c, i * t);
+            self.sbox(n_rounds_f, n_rounds_p, state, i);
+            self.mix_inplace(state, m);
+        }
+
+        self.ark(state, opt_c, 0);
+        self.mix_inplace(state, m_i);
+        let m00 = m[0].as_ref()[0];
+        for (r, sparse) in s.chunks_exact(2 * (t - 1)).enumerate() {
+            self.sbox_pow(&mut state[0]);
+            if r + 1 < n_rounds_p {
+                state[0] += opt_c[t + r];
+            }
+            let (w_hat, v) = sparse.split_at(t - 1);
+            let s0 = state[0];
+            let mut new_s0 = m00 * s0;
+            for (w, x) in w_hat.iter().zip(&state[1..]) {
+                new_s0 += *w * x;
+            }
+            for (x, v) in state[1..].iter_mut().zip(v) {
+                *x += s0 * v;
+            }
+            state[0] = new_s0;
+        }
+
+        for i in (half_f + n_rounds_p)..(n_rounds_f + n_rounds_p) {
+            self.ark(state, c, i * t);
+            self.sbox(n_rounds_f, n_rounds_p, state, i);
+            self.mix_inplace(state, m);
+        }
+    }
+
+    /// Pure-Rust Poseidon hash of 1 to `max_inputs()` field elements (circomlib compatible)
+    pub fn hash(&self, inp: &[F]) -> Result<F, PoseidonError> {
+        #[cfg(feature = "solana")]
+        if let Some(bn254) = self.syscall_instance(inp.len()) {
+            return bn254.hash_fields_syscall(inp);
+        }
+
+        self.hash_with_initial_state(F::zero(), inp)
+    }
+
+    /// `hash` with `state[0]` set to `initial_state` instead of zero (circom `PoseidonEx`
+    /// `initialState`)
+    pub fn hash_with_initial_state(&self, initial_state: F, inp: &[F]) -> Result<F, PoseidonError> {
+        let mut state = [F::zero(); MAX_WIDTH];
+        self.permute_inputs(initial_state, inp, &mut state)?;
+
+        Ok(state[0])
+    }
+
+    /// Domain-separated hash: the tag `domain` goes into the capacity element, like the
+    /// domain-separated Poseidon of zkSync and Scroll. Hashes under different domains are
+    /// independent and no input slot is spent on the tag; domain zero is `hash`.
+    pub fn hash_with_domain(&self, domain: F, inp: &[F]) -> Result<F, PoseidonError> {
+        self.hash_with_initial_state(domain, inp)
+    }
+
+    /// circom's `PoseidonEx` with `n_outs` outputs: the first `n_outs` elements of the
+    /// state after permuting `[0, inputs...]`; the first one equals `hash(inp)`
+    #[cfg(feature = "alloc")]
+    pub fn hash_ex(&self, inp: &[F], n_outs: usize) -> Result<Vec<F>, PoseidonError> {
+        self.hash_ex_with_initial_state(F::zero(), inp, n_outs)
+    }
+
+    /// `hash_ex` starting from `state[0] = initial_state`
+    #[cfg(feature = "alloc")]
+    pub fn hash_ex_with_initial_state(
+        &self,
+        initial_state: F,
+        inp: &[F],
+        n_outs: usize,
+    ) -> Result<Vec<F>, PoseidonError> {
+        let mut state = [F::zero(); MAX_WIDTH];
+        let t = self.permute_inputs(initial_state, inp, &mut state)?;
+        if n_outs == 0 || n_outs > t {
+            return Err(PoseidonError::InvalidOutputLength {
+                got: n_outs,
+                max: t,
+            });
+        }
+        Ok(state[..n_outs].to_vec())
+    }
+
+    /// Fixed-size `hash_ex` returning `N` outputs without allocating
+    pub fn hash_ex_array<const N: usize>(&self, inp: &[F]) -> Result<[F; N], PoseidonError> {
+        let mut state = [F::zero(); MAX_WIDTH];
+        let t = self.permute_inputs(F::zero(), inp, &mut state)?;
+        if N == 0 || N > t {
+            return Err(PoseidonError::InvalidOutputLength { got: N, max: t });
+        }
+        let mut out = [F::zero(); N];
+        out.copy_from_slice(&state[..N]);
+        Ok(out)
+    }
+
+    // Permutes [initial_state, inputs...] in `state`, returning the width
+    fn permute_inputs(
+        &self,
+        initial_state: F,
+        inp: &[F],
+        state: &mut [F; MAX_WIDTH],
+    ) -> Result<usize, PoseidonError> {
+        if !self.supports_width(inp.len() + 1) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: inp.len(),
+                max: self.max_inputs(),
+            });
+        }
+        let t = inp.len() + 1;
+        state[0] = initial_state;
+        state[1..t].copy_from_slice(inp);
+        self.permute(&mut state[..t]);
+        Ok(t)
+    }
+
+    /// Same as `hash`, reading the inputs from an iterator straight into the state
+    pub fn hash_iter(&self, inputs: impl IntoIterator<Item = F>) -> Result<F, PoseidonError> {
+        let mut state = [F::zero(); MAX_WIDTH];
+        let mut inputs = inputs.into_iter();
+        let mut n = 0;
+        for (s, x) in state[1..].iter_mut().zip(&mut inputs) {
+            *s = x;
+            n += 1;
+        }
+        let extra = inputs.count();
+        if extra > 0 || !self.supports_width(n + 1) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: n + extra,
+                max: self.max_inputs(),
+            });
+        }
+        self.permute(&mut state[..n + 1]);
+
+        Ok(state[0])
+    }
+
+    /// `hash` of integers, for amounts, indices and timestamps
+    pub fn hash_u64s(&self, inp: &[u64]) -> Result<F, PoseidonError> {
+        self.hash_converted(inp.iter().map(|&x| F::from(x)))
+    }
+
+    /// `hash` of 128-bit integers
+    pub fn hash_u128s(&self, inp: &[u128]) -> Result<F, PoseidonError> {
+        self.hash_converted(inp.iter().map(|&x| F::from(x)))
+    }
+
+    fn hash_converted(&self, inputs: impl ExactSizeIterator<Item = F>) -> Result<F, PoseidonError> {
+        let n = inputs.len();
+        let mut buf = [F::zero(); MAX_WIDTH - 1];
+        if n > buf.len() {
+            return Err(PoseidonError::InvalidInputLength {
+                got: n,
+                max: self.max_inputs(),
+            });
+        }
+        buf.iter_mut().zip(inputs).for_each(|(b, x)| *b = x);
+        self.hash(&buf[..n])
+    }
+
+    /// `hash` that wipes the permutation state before returning, for secret inputs.
+    ///
+    /// The state array and the full-state scratch copy of the MDS multiplication are
+    /// zeroized; single elements the compiler keeps in registers or spills are not.
+    #[cfg(feature = "zeroize")]
+    pub fn hash_zeroizing(&self, inp: &[F]) -> Result<F, PoseidonError> {
+        let mut state = [F::zero(); MAX_WIDTH];
+        let res = self
+            .permute_inputs(F::zero(), inp, &mut state)
+            .map(|_| state[0]);
+        zeroize::Zeroize::zeroize(&mut state);
+        res
+    }
+
+    /// `hash_bytes_into` that wipes the decoded inputs and the permutation state before
+    /// returning, like `hash_zeroizing`. Always hashes in software, never through the
+    /// Solana syscall.
+    #[cfg(feature = "zeroize")]
+    pub fn hash_bytes_zeroizing(
+        &self,
+        inputs: &[&[u8; 32]],
+        out: &mut [u8; 32],
+    ) -> Result<(), PoseidonError> {
+        let t = inputs.len() + 1;
+        if !self.supports_width(t) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: inputs.len(),
+                max: self.max_inputs(),
+            });
+        }
+        let mut state = [F::zero(); MAX_WIDTH];
+        let res = state[1..t]
+            .iter_mut()
+            .zip(inputs)
+            .try_for_each(|(s, bytes)| bytes_to_field_strict(bytes).map(|x| *s = x));
+        if res.is_ok() {
+            self.permute(&mut state[..t]);
+            *out = field_to_bytes(&state[0]);
+        }
+        zeroize::Zeroize::zeroize(&mut state);
+        res
+    }
+
+    /// Hash each of `inputs` with `hash`, spreading the work over rayon's thread pool
+    #[cfg(feature = "parallel")]
+    pub fn hash_batch(&self, inputs: &[&[F]]) -> Result<Vec<F>, PoseidonError> {
+        use rayon::prelude::*;
+
+        inputs.par_iter().map(|inp| self.hash(inp)).collect()
+    }
+
+    /// Hash each of `inputs` with `hash` on the current thread, for targets without
+    /// rayon. Inputs are grouped by length, and each group is hashed over one state
+    /// buffer with its constants looked up once; fails if any length is unsupported.
+    #[cfg(feature = "alloc")]
+    pub fn hash_many(&self, inputs: &[&[F]]) -> Result<Vec<F>, PoseidonError> {
+        let mut order: Vec<usize> = (0..inputs.len()).collect();
+        order.sort_unstable_by_key(|&i| inputs[i].len());
+        let mut out = alloc::vec![F::zero(); inputs.len()];
+        for group in order.chunk_by(|&a, &b| inputs[a].len() == inputs[b].len()) {
+            let n = inputs[group[0]].len();
+            if !self.supports_width(n + 1) {
+                return Err(PoseidonError::InvalidInputLength {
+                    got: n,
+                    max: self.max_inputs(),
+                });
+            }
+            #[cfg(feature = "solana")]
+            if let Some(bn254) = self.syscall_instance(n) {
+                for &i in group {
+                    out[i] = bn254.hash_fields_syscall(inputs[i])?;
+                }
+                continue;
+            }
+            macro_rules! hash_group {
+                ($($t:literal)*) => {
+                    match n + 1 {
+                        $($t => self.hash_group::<$t>(inputs, group, &mut out),)*
+                        _ => unreachable!("width is checked above"),
+                    }
+                };
+            }
+            hash_group!(2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25);
+        }
+        Ok(out)
+    }
+
+    /// Hash 32-byte little-endian field elements, decoding inputs at or above the modulus
+    /// as `mode` says.
+    ///
+    /// Built for Solana with the `solana` feature, the circomlib BN254 instance goes
+    /// through the native Poseidon syscall for 1 to 12 inputs, like `hash`; otherwise each
+    /// input is decoded and hashed in software, without allocating.
+    pub fn hash_bytes(
+        &self,
+        inputs: &[&[u8; 32]],
+        mode: DecodeMode,
+    ) -> Result<PoseidonDigest, PoseidonError> {
+        let mut out = [0u8; 32];
+        match mode {
+            DecodeMode::Strict => self.hash_bytes_into(inputs, &mut out)?,
+            DecodeMode::Reduce => {
+                if inputs.len() >= MAX_WIDTH {
+                    return Err(PoseidonError::InvalidInputLength {
+                        got: inputs.len(),
+                        max: self.max_inputs(),
+                    });
+                }
+                let mut inp = [F::zero(); MAX_WIDTH];
+                for (x, bytes) in inp.iter_mut().zip(inputs) {
+                    *x = bytes_to_field_reduce(bytes);
+                }
+                out = field_to_bytes(&self.hash(&inp[..inputs.len()])?);
+            }
+        }
+        Ok(PoseidonDigest::new(out))
+    }
+
+    /// `hash` writing the digest to `out`
+    pub fn hash_into(&self, inp: &[F], out: &mut F) -> Result<(), PoseidonError> {
+        *out = self.hash(inp)?;
+        Ok(())
+    }
+
+    /// `hash_bytes` in `Strict` mode writing the digest to `out`; `out` is left untouched
+    /// on error.
+    ///
+    /// Nothing is allocated. The inputs are decoded straight into the permutation state,
+    /// so the stack holds the `MAX_WIDTH`-element state and the equally sized
+    /// `mix_inplace` buffer: about 1.6 KB for BN254 plus small frames. The syscall path
+    /// only needs 12 slice references (192 bytes).
+    pub fn hash_bytes_into(
+        &self,
+        inputs: &[&[u8; 32]],
+        out: &mut [u8; 32],
+    ) -> Result<(), PoseidonError> {
+        self.hash_bytes_ordered(inputs, out, false)
+    }
+
+    /// `hash_bytes` over 32-byte big-endian field elements, the encoding of circomlibjs,
+    /// Solidity's `uint256` and light-poseidon, returning the big-endian digest
+    pub fn hash_bytes_be(&self, inputs: &[&[u8; 32]]) -> Result<[u8; 32], PoseidonError> {
+        let mut out = [0u8; 32];
+        self.hash_bytes_be_into(inputs, &mut out)?;
+        Ok(out)
+    }
+
+    /// `hash_bytes_be` writing the digest to `out`; `out` is left untouched on error
+    pub fn hash_bytes_be_into(
+        &self,
+        inputs: &[&[u8; 32]],
+        out: &mut [u8; 32],
+    ) -> Result<(), PoseidonError> {
+        self.hash_bytes_ordered(inputs, out, true)
+    }
+
+    /// Hash a byte string of any length like go-iden3-crypto's `HashBytes`:
+    /// `hash_bytes_msg_x` with frames of 16 elements
+    pub fn hash_bytes_msg(&self, msg: &[u8]) -> Result<F, PoseidonError> {
+        self.hash_bytes_msg_x(msg, 16)
+    }
+
+    /// go-iden3-crypto's `HashBytesX`. The message is split into 31-byte chunks, each read
+    /// as a big-endian integer and the last one right-padded with zeros. Chunks fill
+    /// frames of `frame_size` (2..=16) inputs that are hashed in turn, every frame after
+    /// the first starting with the previous hash; the last frame is padded with zeros.
+    /// An empty message hashes to zero, where go-iden3-crypto returns `nil`.
+    pub fn hash_bytes_msg_x(&self, msg: &[u8], frame_size: usize) -> Result<F, PoseidonError> {
+        const CHUNK: usize = 31;
+        if F::MODULUS_BIT_SIZE <= 8 * CHUNK as u32 {
+            return Err(PoseidonError::UnsupportedField);
+        }
+        if !(2..=16).contains(&frame_size) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: frame_size,
+                max: 16,
+            });
+        }
+        let mut frame = [F::zero(); 16];
+        let mut hash = F::zero();
+        let mut k = 0;
+        let mut dirty = false;
+        for chunk in msg.chunks(CHUNK) {
+            let mut buf = [0u8; CHUNK];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            frame[k] = F::from_be_bytes_mod_order(&buf);
+            dirty = true;
+            if k == frame_size - 1 {
+                hash = self.hash(&frame[..frame_size])?;
+                frame = [F::zero(); 16];
+                frame[0] = hash;
+                k = 1;
+                dirty = false;
+            } else {
+                k += 1;
+            }
+        }
+        if dirty {
+            hash = self.hash(&frame[..frame_size])?;
+        }
+        Ok(hash)
+    }
+
+    /// One hash of any non-empty number of elements, with a fixed layout a circuit can
+    /// rebuild from the input count alone.
+    ///
+    /// Up to `UNBOUNDED_ARITY` (16) inputs this is `hash`. Longer inputs are the leaves of
+    /// a 16-ary tree of height `h`, the smallest with `16^h >= n`: each level groups the
+    /// nodes below it in runs of 16 from the left and hashes every run with `hash`, the
+    /// last run keeping however many nodes are left, so a lone node is hashed on its own.
+    /// The result is the root hashed again with the length in the capacity element,
+    /// `hash_with_domain(n, [root])`, which keeps it apart from `hash` of the same
+    /// elements and from trees over other lengths. Needs widths 2 to 17; fails with
+    /// `InvalidInputLength` on an empty input.
+    pub fn hash_unbounded(&self, inp: &[F]) -> Result<F, PoseidonError> {
+        if inp.len() <= UNBOUNDED_ARITY {
+            return self.hash(inp);
+        }
+        let mut span = UNBOUNDED_ARITY;
+        while span * UNBOUNDED_ARITY < inp.len() {
+            span *= UNBOUNDED_ARITY;
+        }
+        let root = self.hash_subtree(inp, span)?;
+        self.hash_with_domain(F::from(inp.len() as u64), &[root])
+    }
+
+    // Node over the leaves `inp`, whose children cover `span` leaves each
+    fn hash_subtree(&self, inp: &[F], span: usize) -> Result<F, PoseidonError> {
+        let mut children = [F::zero(); UNBOUNDED_ARITY];
+        let mut n = 0;
+        for chunk in inp.chunks(span) {
+            children[n] = if span == 1 {
+                chunk[0]
+            } else {
+                self.hash_subtree(chunk, span / UNBOUNDED_ARITY)?
+            };
+            n += 1;
+        }
+        self.hash(&children[..n])
+    }
+
+    fn hash_bytes_ordered(
+        &self,
+        inputs: &[&[u8; 32]],
+        out: &mut [u8; 32],
+        big_endian: bool,
+    ) -> Result<(), PoseidonError> {
+        #[cfg(feature = "solana")]
+        if let Some(bn254) = self.syscall_instance(inputs.len()) {
+            *out = bn254.hash_bytes_syscall(inputs, big_endian)?;
+            return Ok(());
+        }
+
+        let t = inputs.len() + 1;
+        if !self.supports_width(t) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: inputs.len(),
+                max: self.max_inputs(),
+            });
+        }
+        let mut state = [F::zero(); MAX_WIDTH];
+        for (s, bytes) in state[1..t].iter_mut().zip(inputs) {
+            *s = if big_endian {
+                bytes_to_field_be(bytes)?
+            } else {
+                bytes_to_field_strict(bytes)?
+            };
+        }
+        self.permute(&mut state[..t]);
+        *out = if big_endian {
+            field_to_bytes_be(&state[0])
+        } else {
+            field_to_bytes(&state[0])
+        };
+
+        Ok(())
+    }
+}
+
+/// How 32-byte inputs at or above the modulus are decoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Reject them with `NonCanonicalBytes`, like `bytes_to_field_strict`
+    Strict,
+    /// Reduce the full 256-bit value modulo p, like `bytes_to_field_reduce`
+    Reduce,
+}
+
+/// Decode a little-endian field element, rejecting values not below the modulus
+#[deprecated(note = "use `bytes_to_field_strict` or `bytes_to_field_reduce`")]
+pub fn bytes_to_field<F: PrimeField>(bytes: &[u8; 32]) -> Result<F, PoseidonError> {
+    bytes_to_field_strict(bytes)
+}
+
+/// Decode a little-endian field element, reducing the 256-bit value modulo p
+pub fn bytes_to_field_reduce<F: PrimeField>(bytes: &[u8; 32]) -> F {
+    F::from_le_bytes_mod_order(bytes)
+}
+
+/// Decode a little-endian field element, rejecting values not below the modulus
+pub fn bytes_to_field_strict<F: PrimeField>(bytes: &[u8; 32]) -> Result<F, PoseidonError> {
+    let mut x = F::BigInt::default();
+    let limbs = x.as_mut();
+    for (i, chunk) in bytes.chunks_exact(8).enumerate() {
+        let limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        match limbs.get_mut(i) {
+            Some(l) => *l = limb,
+            None if limb == 0 => {}
+            None => return Err(PoseidonError::NonCanonicalBytes),
+        }
+    }
+    F::from_bigint(x).ok_or(PoseidonError::NonCanonicalBytes)
+}
+
+/// Decode a big-endian field element, rejecting values not below the modulus
+pub fn bytes_to_field_be<F: PrimeField>(bytes: &[u8; 32]) -> Result<F, PoseidonError> {
+    let mut le = *bytes;
+    le.reverse();
+    bytes_to_field_strict(&le)
+}
+
+/// Pack arbitrary bytes into field elements: little-endian chunks of
+/// `(MODULUS_BIT_SIZE - 1) / 8` bytes, which are always below the modulus
+pub(crate) fn pack_bytes<F: PrimeField>(bytes: &[u8]) -> impl Iterator<Item = F> + '_ {
+    let chunk_len = ((F::MODULUS_BIT_SIZE - 1) / 8) as usize;
+    bytes.chunks(chunk_len).map(|chunk| {
+        let mut buf = [0u8; 32];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        bytes_to_field_strict(&buf).expect("chunk is below the modulus")
+    })
+}
+
+/// Encode a field element as 32 little-endian bytes
+pub fn field_to_bytes<F: PrimeField>(x: &F) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (chunk, limb) in out.chunks_exact_mut(8).zip(x.into_bigint().as_ref()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    out
+}
+
+/// Encode a field element as 32 big-endian bytes
+pub fn field_to_bytes_be<F: PrimeField>(x: &F) -> [u8; 32] {
+    let mut out = field_to_bytes(x);
+    out.reverse();
+    out
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    /// Lowercase big-endian hex of an element without `0x`, as circomlibjs and block
+    /// explorers print it; `core::str::from_utf8` turns it into a `&str`
+    pub fn field_to_hex(x: &F) -> [u8; 64] {
+        encode_hex(&field_to_bytes_be(x))
+    }
+
+    /// Parse 1 to 64 big-endian hex digits, with or without `0x`. Fails with
+    /// `InvalidEncoding` on other input and `NonCanonicalBytes` if the value is not below
+    /// the modulus.
+    pub fn hex_to_field(hex: &[u8]) -> Result<F, PoseidonError> {
+        bytes_to_field_be(&decode_hex(hex)?)
+    }
+}
+
+/// Lowercase hex of bytes in order
+pub(crate) fn encode_hex(bytes: &[u8; 32]) -> [u8; 64] {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = [0u8; 64];
+    for (pair, b) in out.chunks_exact_mut(2).zip(bytes) {
+        pair[0] = DIGITS[(b >> 4) as usize];
+        pair[1] = DIGITS[(b & 0xf) as usize];
+    }
+    out
+}
+
+/// Big-endian 256-bit integer from 1 to 64 hex digits with an optional `0x`
+pub(crate) fn decode_hex(hex: &[u8]) -> Result<[u8; 32], PoseidonError> {
+    let digits = hex.strip_prefix(b"0x").unwrap_or(hex);
+    if digits.is_empty() || digits.len() > 64 {
+        return Err(PoseidonError::InvalidEncoding);
+    }
+    let mut out = [0u8; 32];
+    for (i, d) in digits.iter().rev().enumerate() {
+        let nibble = (*d as char)
+            .to_digit(16)
+            .ok_or(PoseidonError::InvalidEncoding)? as u8;
+        out[31 - i / 2] |= nibble << (4 * (i % 2));
+    }
+    Ok(out)
+}
+
+/// The canonical field element of a Solana public key: its 32 bytes as a big-endian
+/// integer, reduced modulo the BN254 scalar field order.
+///
+/// Keys are uniform 256-bit strings, so most of them are at or above the modulus and are
+/// reduced. The map is not injective: the key `k + p` encodes to the same element as `k`.
+#[cfg(feature = "solana")]
+pub fn pubkey_to_field(key: &Pubkey) -> Fr {
+    Fr::from_be_bytes_mod_order(key.as_ref())
+}
+
+#[cfg(feature = "solana")]
+impl<F: PrimeField> Poseidon<F> {
+    /// The circomlib BN254 instance, if `self` is it and the program runs on Solana, where
+    /// the syscall takes `n_inputs` inputs at a fraction of the software path's compute
+    /// units. Off-chain, `sol_poseidon` is emulated in software and never used.
+    fn syscall_instance(&self, n_inputs: usize) -> Option<&Poseidon> {
+        if !cfg!(target_os = "solana") || n_inputs == 0 || n_inputs > SYSCALL_MAX_INPUTS {
+            return None;
+        }
+        let bn254 = (self as &dyn Any).downcast_ref::<Poseidon>()?;
+        match bn254.constants {
+            Constants::Static(c) if ptr::eq(c, &BN254_CONSTANTS) => Some(bn254),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "solana")]
+impl Poseidon {
+    /// Hash public keys encoded with `pubkey_to_field`, returning the big-endian digest;
+    /// on Solana this goes through the syscall
+    pub fn hash_pubkeys(&self, keys: &[&Pubkey]) -> Result<[u8; 32], PoseidonError> {
+        let mut bytes = [[0u8; 32]; MAX_WIDTH - 1];
+        if keys.len() > bytes.len() {
+            return Err(PoseidonError::InvalidInputLength {
+                got: keys.len(),
+                max: self.max_inputs(),
+            });
+        }
+        for (b, key) in bytes.iter_mut().zip(keys) {
+            *b = field_to_bytes_be(&pubkey_to_field(key));
+        }
+        let refs: [&[u8; 32]; MAX_WIDTH - 1] = core::array::from_fn(|i| &bytes[i]);
+        self.hash_bytes_be(&refs[..keys.len()])
+    }
+
+    /// `hash` over the syscall, for inputs of any field type that is `Fr`
+    fn hash_fields_syscall<F: PrimeField>(&self, inp: &[F]) -> Result<F, PoseidonError> {
+        let mut bytes = [[0u8; 32]; SYSCALL_MAX_INPUTS];
+        for (b, x) in bytes.iter_mut().zip(inp) {
+            *b = field_to_bytes(x);
+        }
+        let refs: [&[u8; 32]; SYSCALL_MAX_INPUTS] = core::array::from_fn(|i| &bytes[i]);
+        bytes_to_field_strict(&self.hash_bytes_syscall(&refs[..inp.len()], false)?)
+    }
+
+    /// Hash function that uses Solana's native Poseidon syscall, with inputs and output in
+    /// the given byte order
+    fn hash_bytes_syscall(
+        &self,
+        inputs: &[&[u8; 32]],
+        big_endian: bool,
+    ) -> Result<[u8; 32], PoseidonError> {
         if inputs.is_empty() {
-            return Err("Empty input".to_string());
+            return Err(PoseidonError::InvalidInputLength {
+                got: 0,
+                max: SYSCALL_MAX_INPUTS,
+            });
+        }
+
+        // Reject inputs at or above the modulus like the software path, so that only
+        // runtime failures are reported as `SyscallFailed`
+        for bytes in inputs {
+            if big_endian {
+                bytes_to_field_be::<Fr>(bytes)?;
+            } else {
+                bytes_to_field_strict::<Fr>(bytes)?;
+            }
         }
-        
+
         // Convert &[u8; 32] to &[u8] for the syscall
-        let byte_slices: Vec<&[u8]> = inputs.iter().map(|&arr| &arr[..]).collect();
-        
+        let mut byte_slices: [&[u8]; SYSCALL_MAX_INPUTS] = [&[]; SYSCALL_MAX_INPUTS];
+        for (slice, &arr) in byte_slices.iter_mut().zip(inputs) {
+            *slice = &arr[..];
+        }
+
         // Use Solana's native Poseidon syscall
-        let result = hashv(Parameters::Bn254X5, Endianness::LittleEndian, &byte_slices)
-            .map_err(|_| "Poseidon syscall failed".to_string())?;
-            
+        let byte_slices = &byte_slices[..inputs.len()];
+        let endianness = if big_endian {
+            Endianness::BigEndian
+        } else {
+            Endianness::LittleEndian
+        };
+        let result = hashv(Parameters::Bn254X5, endianness, byte_slices)
+            .map_err(|_| PoseidonError::SyscallFailed)?;
+
         Ok(result.to_bytes())
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn pow5<F: Field>(x: &mut F) {
+    let aux = *x;
+    x.square_in_place();
+    x.square_in_place();
+    *x *= aux;
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    #[cfg(feature = "width-3")]
+    fn be_word(x: u64) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&x.to_be_bytes());
+        word
+    }
+
+    // circomlibjs `poseidon([1, 2])`
+    #[cfg(feature = "width-3")]
+    #[test]
+    fn hash_bytes_be_matches_circomlibjs() {
+        let digest = Poseidon::new()
+            .hash_bytes_be(&[&be_word(1), &be_word(2)])
+            .unwrap();
+        assert_eq!(
+            digest,
+            decode_hex(b"0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a")
+                .unwrap()
+        );
+    }
+
+    #[cfg(all(feature = "alloc", feature = "all-widths"))]
+    #[test]
+    fn hash_bytes_be_matches_light_poseidon() {
+        use ::light_poseidon::{Poseidon as LightPoseidon, PoseidonBytesHasher};
+
+        let p_minus_1 = field_to_bytes_be(&-Fr::from(1u64));
+        for n in 3..=12 {
+            let words: Vec<[u8; 32]> = (0..n as u64)
+                .map(|i| match i {
+                    0 => p_minus_1,
+                    _ => be_word(i * 0x1_0000_0001),
+                })
+                .collect();
+            let inputs: Vec<&[u8; 32]> = words.iter().collect();
+            let slices: Vec<&[u8]> = words.iter().map(|w| &w[..]).collect();
+            let expected = LightPoseidon::<Fr>::new_circom(n)
+                .unwrap()
+                .hash_bytes_be(&slices)
+                .unwrap();
+            assert_eq!(
+                Poseidon::new().hash_bytes_be(&inputs).unwrap(),
+                expected,
+                "t = {}",
+                n + 1
+            );
+        }
+    }
+
+    #[test]
+    fn be_and_le_encodings_round_trip() {
+        for x in [
+            Fr::from(0u64),
+            Fr::from(1u64),
+            Fr::from(0x0102_0304u64),
+            -Fr::from(1u64),
+        ] {
+            let be = field_to_bytes_be(&x);
+            let mut le = field_to_bytes(&x);
+            assert_eq!(bytes_to_field_be::<Fr>(&be).unwrap(), x);
+            assert_eq!(bytes_to_field_strict::<Fr>(&le).unwrap(), x);
+            le.reverse();
+            assert_eq!(le, be);
+        }
+        let p = decode_hex(b"0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001")
+            .unwrap();
+        assert_eq!(
+            bytes_to_field_be::<Fr>(&p),
+            Err(PoseidonError::NonCanonicalBytes)
+        );
+    }
+
+    #[cfg(any(feature = "bls12-381", feature = "goldilocks", feature = "pasta"))]
+    /// Permute `[0, 1, .., t - 1]` and compare the state with the big-endian hex `expected`
+    pub(crate) fn assert_permutation<F: PrimeField>(poseidon: &Poseidon<F>, expected: &[&str]) {
+        let t = expected.len();
+        let mut state = [F::zero(); MAX_WIDTH];
+        for (i, x) in state[..t].iter_mut().enumerate() {
+            *x = F::from(i as u64);
+        }
+        poseidon
+            .permute_width(Width::try_from(t).unwrap(), &mut state[..t])
+            .unwrap();
+        for (x, hex) in state.iter().zip(expected) {
+            assert_eq!(*x, Poseidon::<F>::hex_to_field(hex.as_bytes()).unwrap());
+        }
+    }
+}
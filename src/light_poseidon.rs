@@ -0,0 +1,128 @@
+//! The API of the `light-poseidon` crate over this crate's permutation.
+//!
+//! Replacing `light_poseidon::` with `poseidon_ark::light_poseidon::` in a `use` is
+//! enough to migrate: `Poseidon::<Fr>::new_circom(nr_inputs)` and the `PoseidonHasher`
+//! and `PoseidonBytesHasher` traits behave like light-poseidon's and give byte-identical
+//! outputs. Errors are reported as this crate's `PoseidonError`.
+
+use ark_bn254::Fr;
+use ark_ff::{PrimeField, Zero};
+
+use crate::{
+    bytes_to_field_be, bytes_to_field_strict, field_to_bytes, field_to_bytes_be, PoseidonError,
+};
+
+/// Length of a digest in bytes
+pub const HASH_LEN: usize = 32;
+/// Largest circom width `new_circom` accepts, as in light-poseidon
+pub const MAX_X5_LEN: usize = 13;
+
+/// Hashes a fixed number of field elements
+pub trait PoseidonHasher<F: PrimeField> {
+    /// Fails unless `inputs` holds exactly the number of inputs the hasher was built for
+    fn hash(&mut self, inputs: &[F]) -> Result<F, PoseidonError>;
+}
+
+/// Hashes a fixed number of byte-encoded field elements
+pub trait PoseidonBytesHasher {
+    /// Hash big-endian inputs of 1 to 32 bytes into a big-endian digest
+    fn hash_bytes_be(&mut self, inputs: &[&[u8]]) -> Result<[u8; HASH_LEN], PoseidonError>;
+    /// Hash little-endian inputs of 1 to 32 bytes into a little-endian digest
+    fn hash_bytes_le(&mut self, inputs: &[&[u8]]) -> Result<[u8; HASH_LEN], PoseidonError>;
+}
+
+/// light-poseidon's `Poseidon`: a circom instance for a fixed number of inputs, with an
+/// optional domain tag in the capacity element
+pub struct Poseidon<F: 'static = Fr> {
+    poseidon: crate::Poseidon<F>,
+    nr_inputs: usize,
+    domain_tag: F,
+}
+
+impl Poseidon<Fr> {
+    /// circomlib instance over BN254 hashing `nr_inputs` elements, 1 to 12
+    pub fn new_circom(nr_inputs: usize) -> Result<Poseidon<Fr>, PoseidonError> {
+        Self::with_domain_tag_circom(nr_inputs, Fr::zero())
+    }
+
+    /// `new_circom` with `domain_tag` in the first state element instead of zero
+    pub fn with_domain_tag_circom(
+        nr_inputs: usize,
+        domain_tag: Fr,
+    ) -> Result<Poseidon<Fr>, PoseidonError> {
+        let poseidon = crate::Poseidon::new();
+        if nr_inputs == 0 || nr_inputs >= MAX_X5_LEN || !poseidon.supports_width(nr_inputs + 1) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: nr_inputs,
+                max: MAX_X5_LEN - 1,
+            });
+        }
+        Ok(Poseidon {
+            poseidon,
+            nr_inputs,
+            domain_tag,
+        })
+    }
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    fn hash_bytes(
+        &mut self,
+        inputs: &[&[u8]],
+        big_endian: bool,
+    ) -> Result<[u8; HASH_LEN], PoseidonError> {
+        self.check_len(inputs.len())?;
+        let mut elems = [F::zero(); MAX_X5_LEN - 1];
+        for (x, input) in elems.iter_mut().zip(inputs) {
+            if input.is_empty() || input.len() > HASH_LEN {
+                return Err(PoseidonError::InvalidEncoding);
+            }
+            let mut bytes = [0u8; HASH_LEN];
+            *x = if big_endian {
+                bytes[HASH_LEN - input.len()..].copy_from_slice(input);
+                bytes_to_field_be(&bytes)?
+            } else {
+                bytes[..input.len()].copy_from_slice(input);
+                bytes_to_field_strict(&bytes)?
+            };
+        }
+        let hash = self.hash(&elems[..inputs.len()])?;
+        Ok(if big_endian {
+            field_to_bytes_be(&hash)
+        } else {
+            field_to_bytes(&hash)
+        })
+    }
+
+    fn check_len(&self, got: usize) -> Result<(), PoseidonError> {
+        if got != self.nr_inputs {
+            return Err(PoseidonError::InvalidInputLength {
+                got,
+                max: self.nr_inputs,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> PoseidonHasher<F> for Poseidon<F> {
+    fn hash(&mut self, inputs: &[F]) -> Result<F, PoseidonError> {
+        self.check_len(inputs.len())?;
+        if self.domain_tag.is_zero() {
+            // Lets the circom instance use the Solana syscall
+            self.poseidon.hash(inputs)
+        } else {
+            self.poseidon.hash_with_domain(self.domain_tag, inputs)
+        }
+    }
+}
+
+impl<F: PrimeField> PoseidonBytesHasher for Poseidon<F> {
+    fn hash_bytes_be(&mut self, inputs: &[&[u8]]) -> Result<[u8; HASH_LEN], PoseidonError> {
+        self.hash_bytes(inputs, true)
+    }
+
+    fn hash_bytes_le(&mut self, inputs: &[&[u8]]) -> Result<[u8; HASH_LEN], PoseidonError> {
+        self.hash_bytes(inputs, false)
+    }
+}
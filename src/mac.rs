@@ -0,0 +1,63 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use subtle::ConstantTimeEq;
+
+use crate::sponge::PoseidonSponge;
+use crate::{field_to_bytes, Poseidon, PoseidonError};
+
+/// Capacity tag of MAC sponges; plain hashes and sponges start from zero
+const MAC_DOMAIN: u64 = 0x6d6163;
+
+/// Keyed Poseidon MAC.
+///
+/// The tag is squeezed from a rate-2, capacity-1 sponge whose capacity element starts at
+/// a MAC-specific domain tag, after absorbing the key followed by the message, so tags
+/// never coincide with `hash` or `hash_sponge` outputs.
+pub struct PoseidonMac<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+}
+
+#[cfg(feature = "width-3")]
+impl Default for PoseidonMac {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonMac {
+    #[cfg(feature = "width-3")]
+    pub fn new() -> PoseidonMac {
+        PoseidonMac::with_poseidon(Poseidon::new()).expect("BN254 supports width 3")
+    }
+}
+
+impl<F: PrimeField> PoseidonMac<F> {
+    /// MAC over the permutation of the given instance, which has to support width 3
+    pub fn with_poseidon(poseidon: Poseidon<F>) -> Result<PoseidonMac<F>, PoseidonError> {
+        if !poseidon.supports_width(3) {
+            return Err(PoseidonError::InvalidSpongeParameters {
+                rate: 2,
+                capacity: 1,
+            });
+        }
+        Ok(PoseidonMac { poseidon })
+    }
+
+    /// Tag of `msg` under `key`
+    pub fn mac(&self, key: &F, msg: &[F]) -> F {
+        let mut sponge =
+            PoseidonSponge::with_domain(self.poseidon.clone(), 2, 1, F::from(MAC_DOMAIN))
+                .expect("width 3 is checked on construction");
+        sponge.absorb_one(*key);
+        for x in msg {
+            sponge.absorb_one(*x);
+        }
+        sponge.squeeze()
+    }
+
+    /// Check `tag` against the tag of `msg` under `key`, in constant time
+    pub fn verify(&self, key: &F, msg: &[F], tag: &F) -> bool {
+        let expected = field_to_bytes(&self.mac(key, msg));
+        expected.ct_eq(&field_to_bytes(tag)).into()
+    }
+}
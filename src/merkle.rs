@@ -0,0 +1,421 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{bytes_to_field_strict, field_to_bytes, Poseidon, PoseidonError};
+
+/// Binary Merkle tree with `Poseidon([left, right])` as the node hash, as in circomlib's
+/// Merkle tree templates.
+///
+/// The leaves are padded with zeros to the next power of two; an empty tree holds a
+/// single zero leaf. Leaf `i` is the left child of its parent when bit 0 of `i` is
+/// zero, and so on for each level.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree<F = Fr> {
+    // Leaves first, root last
+    layers: Vec<Vec<F>>,
+    len: usize,
+}
+
+impl MerkleTree {
+    #[cfg(feature = "width-3")]
+    pub fn new(leaves: &[Fr]) -> MerkleTree {
+        MerkleTree::with_poseidon(Poseidon::new(), leaves).expect("BN254 supports width 3")
+    }
+}
+
+impl<F: PrimeField> MerkleTree<F> {
+    /// Tree over the given Poseidon instance, which has to support two inputs
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        leaves: &[F],
+    ) -> Result<MerkleTree<F>, PoseidonError> {
+        if !poseidon.supports_width(3) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: 2,
+                max: poseidon.max_inputs(),
+            });
+        }
+        let mut leaf_layer = leaves.to_vec();
+        leaf_layer.resize(leaves.len().max(1).next_power_of_two(), F::zero());
+        let mut layers = vec![leaf_layer];
+        while layers[layers.len() - 1].len() > 1 {
+            let next = hash_layer(&poseidon, &layers[layers.len() - 1], 2);
+            layers.push(next);
+        }
+        Ok(MerkleTree {
+            layers,
+            len: leaves.len(),
+        })
+    }
+
+    pub fn root(&self) -> F {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Number of levels above the leaves
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// Number of leaves the tree was built from, without padding
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Leaves including the zero padding
+    pub fn leaves(&self) -> &[F] {
+        &self.layers[0]
+    }
+
+    /// Inclusion proof of leaf `index`, or `None` if the index is out of range
+    pub fn prove(&self, index: usize) -> Option<MerklePath<F>> {
+        if index >= self.len {
+            return None;
+        }
+        let (siblings, path_indices) = self.layers[..self.depth()]
+            .iter()
+            .enumerate()
+            .map(|(level, layer)| (layer[(index >> level) ^ 1], (index >> level) & 1 == 1))
+            .unzip();
+        Some(MerklePath {
+            siblings,
+            path_indices,
+        })
+    }
+}
+
+/// Inclusion proof of a leaf in a binary Poseidon Merkle tree, in the layout of
+/// circomlib-style `MerkleTreeInclusionProof` circuits
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct MerklePath<F = Fr> {
+    /// Siblings from the leaf level up to the root
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elems"))]
+    pub siblings: Vec<F>,
+    /// For each level, whether the node on the path is the right child
+    pub path_indices: Vec<bool>,
+}
+
+impl MerklePath {
+    /// Check that `leaf` is on this path in the tree with the given root
+    pub fn verify(&self, root: Fr, leaf: Fr) -> bool {
+        self.verify_with(&Poseidon::new(), root, leaf)
+    }
+}
+
+impl<F: PrimeField> MerklePath<F> {
+    /// Check that `leaf` is on this path in the tree with the given root, hashing with
+    /// `poseidon`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>, root: F, leaf: F) -> bool {
+        self.compute_root(poseidon, leaf) == Some(root)
+    }
+
+    /// Root of the tree holding `leaf` on this path, or `None` if the path is malformed
+    /// or `poseidon` does not support two inputs
+    pub fn compute_root(&self, poseidon: &Poseidon<F>, leaf: F) -> Option<F> {
+        if self.siblings.len() != self.path_indices.len() {
+            return None;
+        }
+        let mut node = leaf;
+        for (sibling, right) in self.siblings.iter().zip(&self.path_indices) {
+            let pair = if *right {
+                [*sibling, node]
+            } else {
+                [node, *sibling]
+            };
+            node = poseidon.hash(&pair).ok()?;
+        }
+        Some(node)
+    }
+
+    /// Index of the leaf, with the first path index as bit 0
+    pub fn index(&self) -> u64 {
+        self.path_indices
+            .iter()
+            .rev()
+            .fold(0, |index, right| (index << 1) | *right as u64)
+    }
+
+    /// Encode as the depth (u32, little-endian), the siblings as 32-byte little-endian
+    /// field elements, and the path indices as a little-endian bitmap
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let depth = self.siblings.len();
+        let mut out = Vec::with_capacity(4 + 32 * depth + depth.div_ceil(8));
+        out.extend_from_slice(&(depth as u32).to_le_bytes());
+        for sibling in &self.siblings {
+            out.extend_from_slice(&field_to_bytes(sibling));
+        }
+        let mut bits = vec![0u8; depth.div_ceil(8)];
+        for (i, right) in self.path_indices.iter().enumerate() {
+            bits[i / 8] |= (*right as u8) << (i % 8);
+        }
+        out.extend(bits);
+        out
+    }
+
+    /// Decode the encoding of `to_bytes`, rejecting trailing bytes, unused bitmap bits
+    /// and non-canonical field elements
+    pub fn from_bytes(bytes: &[u8]) -> Result<MerklePath<F>, PoseidonError> {
+        let (depth, rest) = bytes
+            .split_first_chunk::<4>()
+            .ok_or(PoseidonError::InvalidEncoding)?;
+        let depth = u32::from_le_bytes(*depth) as usize;
+        if depth > 64 || rest.len() != 32 * depth + depth.div_ceil(8) {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+        let (siblings, bits) = rest.split_at(32 * depth);
+        let siblings = siblings
+            .chunks_exact(32)
+            .map(|chunk| bytes_to_field_strict(chunk.try_into().expect("chunks of 32 bytes")))
+            .collect::<Result<Vec<F>, _>>()?;
+        let path_indices: Vec<bool> = (0..depth)
+            .map(|i| bits[i / 8] >> (i % 8) & 1 == 1)
+            .collect();
+        if !depth.is_multiple_of(8) && bits[depth / 8] >> (depth % 8) != 0 {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+        Ok(MerklePath {
+            siblings,
+            path_indices,
+        })
+    }
+}
+
+/// Check every `(leaf, path)` pair against `root`, hashing each internal node shared by
+/// several paths only once
+pub fn verify_batch(root: Fr, proofs: &[(Fr, MerklePath)]) -> bool {
+    verify_batch_with(&Poseidon::new(), root, proofs)
+}
+
+/// `verify_batch` hashing with `poseidon`.
+///
+/// Nodes already authenticated by an earlier path, including its siblings, are keyed by
+/// level and position; a later path stops climbing as soon as it reaches one of them, so
+/// its siblings above that node are not checked. All paths must have the same depth.
+pub fn verify_batch_with<F: PrimeField>(
+    poseidon: &Poseidon<F>,
+    root: F,
+    proofs: &[(F, MerklePath<F>)],
+) -> bool {
+    let depth = match proofs.first() {
+        Some((_, path)) => path.siblings.len(),
+        None => return true,
+    };
+    let mut known = BTreeMap::new();
+    known.insert((depth, 0), root);
+    for (leaf, path) in proofs {
+        if path.siblings.len() != depth || path.path_indices.len() != depth || depth >= 64 {
+            return false;
+        }
+        let index = path.index();
+        let mut node = *leaf;
+        for level in 0..=depth {
+            let pos = index >> level;
+            if let Some(expected) = known.insert((level, pos), node) {
+                if expected != node {
+                    return false;
+                }
+                break;
+            }
+            let sibling = path.siblings[level];
+            if let Some(expected) = known.insert((level, pos ^ 1), sibling) {
+                if expected != sibling {
+                    return false;
+                }
+            }
+            let pair = if path.path_indices[level] {
+                [sibling, node]
+            } else {
+                [node, sibling]
+            };
+            node = match poseidon.hash(&pair) {
+                Ok(hash) => hash,
+                Err(_) => return false,
+            };
+        }
+    }
+    true
+}
+
+/// Merkle tree whose nodes hash `arity` children at once with `Poseidon(children)`.
+///
+/// Wider nodes make the tree shallower: a tree of arity 16 over `2^20` leaves has 5
+/// levels instead of 20. The leaves are padded with zeros to the next power of the arity
+/// and the children of a node are ordered by their index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NaryMerkleTree<F = Fr> {
+    arity: usize,
+    // Leaves first, root last
+    layers: Vec<Vec<F>>,
+    len: usize,
+}
+
+/// Inclusion proof of a `NaryMerkleTree` leaf
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct NaryMerkleProof<F = Fr> {
+    /// Index of the leaf
+    pub index: usize,
+    /// For each level from the leaves up, the `arity - 1` other children of the node on
+    /// the path, in order
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::rows"))]
+    pub siblings: Vec<Vec<F>>,
+}
+
+impl NaryMerkleTree {
+    pub fn new(arity: usize, leaves: &[Fr]) -> Result<NaryMerkleTree, PoseidonError> {
+        NaryMerkleTree::with_poseidon(Poseidon::new(), arity, leaves)
+    }
+}
+
+impl<F: PrimeField> NaryMerkleTree<F> {
+    /// Tree of the given arity over the given Poseidon instance, which has to support
+    /// `arity` inputs
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        arity: usize,
+        leaves: &[F],
+    ) -> Result<NaryMerkleTree<F>, PoseidonError> {
+        if arity < 2 || !poseidon.supports_width(arity + 1) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: arity,
+                max: poseidon.max_inputs(),
+            });
+        }
+        let mut n = 1;
+        while n < leaves.len() {
+            n *= arity;
+        }
+        let mut leaf_layer = leaves.to_vec();
+        leaf_layer.resize(n, F::zero());
+        let mut layers = vec![leaf_layer];
+        while layers[layers.len() - 1].len() > 1 {
+            let next = hash_layer(&poseidon, &layers[layers.len() - 1], arity);
+            layers.push(next);
+        }
+        Ok(NaryMerkleTree {
+            arity,
+            layers,
+            len: leaves.len(),
+        })
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn root(&self) -> F {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Number of levels above the leaves
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// Number of leaves the tree was built from, without padding
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Leaves including the zero padding
+    pub fn leaves(&self) -> &[F] {
+        &self.layers[0]
+    }
+
+    /// Inclusion proof of leaf `index`, or `None` if the index is out of range
+    pub fn prove(&self, index: usize) -> Option<NaryMerkleProof<F>> {
+        if index >= self.len {
+            return None;
+        }
+        let mut pos = index;
+        let mut siblings = Vec::with_capacity(self.depth());
+        for layer in &self.layers[..self.depth()] {
+            let first = pos - pos % self.arity;
+            let mut level = layer[first..first + self.arity].to_vec();
+            level.remove(pos % self.arity);
+            siblings.push(level);
+            pos /= self.arity;
+        }
+        Some(NaryMerkleProof { index, siblings })
+    }
+}
+
+impl NaryMerkleProof {
+    /// Check that `leaf` is at `index` in the tree with the given root
+    pub fn verify(&self, root: Fr, leaf: Fr) -> bool {
+        self.verify_with(&Poseidon::new(), root, leaf)
+    }
+}
+
+impl<F: PrimeField> NaryMerkleProof<F> {
+    /// Check that `leaf` is at `index` in the tree with the given root, hashing with
+    /// `poseidon`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>, root: F, leaf: F) -> bool {
+        let arity = match self.siblings.first() {
+            Some(level) => level.len() + 1,
+            None => return self.index == 0 && leaf == root,
+        };
+        let mut pos = self.index;
+        let mut node = leaf;
+        let mut children = Vec::with_capacity(arity);
+        for level in &self.siblings {
+            if level.len() + 1 != arity {
+                return false;
+            }
+            children.clear();
+            children.extend_from_slice(level);
+            children.insert(pos % arity, node);
+            node = match poseidon.hash(&children) {
+                Ok(hash) => hash,
+                Err(_) => return false,
+            };
+            pos /= arity;
+        }
+        pos == 0 && node == root
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn hash_layer<F: PrimeField>(poseidon: &Poseidon<F>, layer: &[F], arity: usize) -> Vec<F> {
+    layer
+        .chunks(arity)
+        .map(|children| {
+            poseidon
+                .hash(children)
+                .expect("the arity is checked on construction")
+        })
+        .collect()
+}
+
+// The nodes of a level are independent, so each level is hashed across rayon's pool
+#[cfg(feature = "parallel")]
+fn hash_layer<F: PrimeField>(poseidon: &Poseidon<F>, layer: &[F], arity: usize) -> Vec<F> {
+    use rayon::prelude::*;
+
+    layer
+        .par_chunks(arity)
+        .map(|children| {
+            poseidon
+                .hash(children)
+                .expect("the arity is checked on construction")
+        })
+        .collect()
+}
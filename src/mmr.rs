@@ -0,0 +1,204 @@
+use alloc::{vec, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{Poseidon, PoseidonError};
+
+/// Merkle Mountain Range: an append-only accumulator whose leaves form perfect binary
+/// Poseidon trees ("mountains") of decreasing height, one per set bit of the leaf count.
+///
+/// Nodes are `Poseidon([left, right])`. The root bags the peaks from right to left,
+/// `bag = Poseidon([peak, bag])`, and commits to the size: `Poseidon([leaf_count, bag])`,
+/// or zero for an empty range. Appending hashes at most `log2(n) + 1` nodes plus the
+/// peaks, and old proofs stay checkable against the root of the size they were made for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleMountainRange<F = Fr> {
+    // layers[h] holds every node of height h in the complete subtrees, left to right
+    layers: Vec<Vec<F>>,
+    root: F,
+}
+
+#[cfg(feature = "width-3")]
+impl Default for MerkleMountainRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MerkleMountainRange {
+    #[cfg(feature = "width-3")]
+    pub fn new() -> MerkleMountainRange {
+        MerkleMountainRange::with_poseidon(&Poseidon::new()).expect("BN254 supports width 3")
+    }
+
+    /// Append `leaf`, returning its index
+    pub fn append(&mut self, leaf: Fr) -> u64 {
+        self.append_with(&Poseidon::new(), leaf)
+    }
+}
+
+impl<F: PrimeField> MerkleMountainRange<F> {
+    /// Empty range hashed with the given Poseidon instance, which has to support two
+    /// inputs
+    pub fn with_poseidon(poseidon: &Poseidon<F>) -> Result<MerkleMountainRange<F>, PoseidonError> {
+        if !poseidon.supports_width(3) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: 2,
+                max: poseidon.max_inputs(),
+            });
+        }
+        Ok(MerkleMountainRange {
+            layers: vec![Vec::new()],
+            root: F::zero(),
+        })
+    }
+
+    /// Append `leaf` hashing with `poseidon`, which has to be the instance the range was
+    /// created with; returns the index of the leaf
+    pub fn append_with(&mut self, poseidon: &Poseidon<F>, leaf: F) -> u64 {
+        let index = self.len();
+        self.layers[0].push(leaf);
+        let mut h = 0;
+        while self.layers[h].len().is_multiple_of(2) {
+            let layer = &self.layers[h];
+            let parent = hash_pair(poseidon, layer[layer.len() - 2], layer[layer.len() - 1]);
+            if h + 1 == self.layers.len() {
+                self.layers.push(Vec::new());
+            }
+            self.layers[h + 1].push(parent);
+            h += 1;
+        }
+        self.root = bag(poseidon, self.len(), &self.peaks()).expect("width 3 is checked");
+        index
+    }
+
+    pub fn root(&self) -> F {
+        self.root
+    }
+
+    /// Number of appended leaves
+    pub fn len(&self) -> u64 {
+        self.layers[0].len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers[0].is_empty()
+    }
+
+    /// Roots of the mountains, highest first
+    pub fn peaks(&self) -> Vec<F> {
+        let n = self.len();
+        (0..self.layers.len())
+            .rev()
+            .filter(|h| n >> h & 1 == 1)
+            .map(|h| self.layers[h][self.layers[h].len() - 1])
+            .collect()
+    }
+
+    /// Inclusion proof of leaf `index` against the current root, or `None` if the index
+    /// is out of range
+    pub fn prove(&self, index: u64) -> Option<MmrProof<F>> {
+        let leaf_count = self.len();
+        let (_, height) = mountain(leaf_count, index)?;
+        let siblings = (0..height)
+            .map(|level| self.layers[level][((index >> level) ^ 1) as usize])
+            .collect();
+        Some(MmrProof {
+            leaf_index: index,
+            leaf_count,
+            siblings,
+            peaks: self.peaks(),
+        })
+    }
+}
+
+/// Inclusion proof of a leaf in a `MerkleMountainRange` of `leaf_count` leaves
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct MmrProof<F = Fr> {
+    pub leaf_index: u64,
+    pub leaf_count: u64,
+    /// Siblings from the leaf up to its mountain's peak
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elems"))]
+    pub siblings: Vec<F>,
+    /// Every peak of the range, highest first
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elems"))]
+    pub peaks: Vec<F>,
+}
+
+impl MmrProof {
+    /// Check that `leaf` is at `leaf_index` in the range with the given root
+    pub fn verify(&self, root: Fr, leaf: Fr) -> bool {
+        self.verify_with(&Poseidon::new(), root, leaf)
+    }
+}
+
+impl<F: PrimeField> MmrProof<F> {
+    /// `verify` hashing with `poseidon`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>, root: F, leaf: F) -> bool {
+        self.compute_root(poseidon, leaf) == Some(root)
+    }
+
+    /// Root of the range holding `leaf` as in this proof, or `None` if the proof is
+    /// malformed, does not reach its peak, or `poseidon` does not support two inputs
+    pub fn compute_root(&self, poseidon: &Poseidon<F>, leaf: F) -> Option<F> {
+        let (peak, height) = mountain(self.leaf_count, self.leaf_index)?;
+        if self.siblings.len() != height
+            || self.peaks.len() != self.leaf_count.count_ones() as usize
+        {
+            return None;
+        }
+        let mut node = leaf;
+        for (level, sibling) in self.siblings.iter().enumerate() {
+            node = if self.leaf_index >> level & 1 == 1 {
+                poseidon.hash2(*sibling, node).ok()?
+            } else {
+                poseidon.hash2(node, *sibling).ok()?
+            };
+        }
+        if node != self.peaks[peak] {
+            return None;
+        }
+        bag(poseidon, self.leaf_count, &self.peaks)
+    }
+}
+
+/// The position among the peaks and the height of the mountain holding leaf `index`
+fn mountain(leaf_count: u64, index: u64) -> Option<(usize, usize)> {
+    if index >= leaf_count {
+        return None;
+    }
+    let mut start = 0;
+    let mut peak = 0;
+    for h in (0..64).rev() {
+        if leaf_count >> h & 1 == 1 {
+            if index < start + (1 << h) {
+                return Some((peak, h));
+            }
+            start += 1 << h;
+            peak += 1;
+        }
+    }
+    unreachable!("index is below leaf_count")
+}
+
+fn bag<F: PrimeField>(poseidon: &Poseidon<F>, leaf_count: u64, peaks: &[F]) -> Option<F> {
+    let Some((&last, rest)) = peaks.split_last() else {
+        return Some(F::zero());
+    };
+    let mut bag = last;
+    for peak in rest.iter().rev() {
+        bag = poseidon.hash2(*peak, bag).ok()?;
+    }
+    poseidon.hash2(F::from(leaf_count), bag).ok()
+}
+
+fn hash_pair<F: PrimeField>(poseidon: &Poseidon<F>, left: F, right: F) -> F {
+    poseidon
+        .hash2(left, right)
+        .expect("width 3 is checked on construction")
+}
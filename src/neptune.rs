@@ -0,0 +1,92 @@
+//! Parameter profile of neptune, the Poseidon implementation used by Filecoin.
+//!
+//! neptune instantiates one width `t = arity + 1` at a time with x^5, R_F = 8 and the
+//! partial rounds of the Poseidon paper's round-number script (128-bit security,
+//! 255-bit field, with its security margin); `Strength::Strengthened` raises R_P by 25%,
+//! rounded up. Round constants come from the Grain LFSR seeded with s-box code 1, and
+//! the MDS matrix is the fixed Cauchy matrix `1 / (i + j + t)` rather than one drawn
+//! from the LFSR. Hashes put a domain tag in `state[0]` and the preimage after it, and
+//! output `state[1]`.
+
+use alloc::vec::Vec;
+use ark_ff::PrimeField;
+
+use crate::grain::GrainLfsr;
+use crate::{Poseidon, PoseidonError, PoseidonParams, MAX_WIDTH};
+
+/// Full rounds of every neptune instance
+pub const FULL_ROUNDS: usize = 8;
+
+// Standard partial rounds for arities 1..=24
+const PARTIAL_ROUNDS: [usize; MAX_WIDTH - 1] = [
+    55, 55, 56, 56, 56, 56, 57, 57, 57, 57, 57, 57, 57, 57, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+];
+
+/// neptune's `Strength`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strength {
+    #[default]
+    Standard,
+    /// 25% more partial rounds, the profile of Filecoin's commitments
+    Strengthened,
+}
+
+/// `(R_F, R_P)` of the instance hashing `arity` inputs, for arities 1..=24
+pub fn round_numbers(arity: usize, strength: Strength) -> Option<(usize, usize)> {
+    let r_p = *PARTIAL_ROUNDS.get(arity.checked_sub(1)?)?;
+    let r_p = match strength {
+        Strength::Standard => r_p,
+        Strength::Strengthened => (5 * r_p).div_ceil(4),
+    };
+    Some((FULL_ROUNDS, r_p))
+}
+
+/// neptune's parameters for `arity` inputs over `F`
+pub fn params<F: PrimeField>(
+    arity: usize,
+    strength: Strength,
+) -> Result<PoseidonParams<F>, PoseidonError> {
+    let (r_f, r_p) = round_numbers(arity, strength).ok_or(PoseidonError::InvalidInputLength {
+        got: arity,
+        max: MAX_WIDTH - 1,
+    })?;
+    let t = arity + 1;
+    let mut lfsr = GrainLfsr::new(F::MODULUS_BIT_SIZE as usize, t, r_f, r_p, 1);
+    let c = (0..(r_f + r_p) * t)
+        .map(|_| lfsr.next_field_element())
+        .collect();
+    let m = (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| F::from((i + j + t) as u64).inverse())
+                .collect::<Option<Vec<F>>>()
+        })
+        .collect::<Option<Vec<Vec<F>>>>()
+        .ok_or(PoseidonError::UnsupportedField)?;
+    PoseidonParams::new(r_f, r_p, 5, c, m)
+}
+
+/// neptune's domain tag of `HashType::MerkleTree` for `arity` inputs, `2^arity - 1`
+pub fn merkle_tree_tag<F: PrimeField>(arity: usize) -> F {
+    F::from(2u64).pow([arity as u64]) - F::one()
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    /// Single-width instance with neptune's parameters for `arity` inputs
+    pub fn new_neptune(arity: usize, strength: Strength) -> Result<Poseidon<F>, PoseidonError> {
+        params(arity, strength).map(Poseidon::with_params)
+    }
+
+    /// neptune's `Poseidon::hash` of a `HashType::MerkleTree` preimage, which has to fill
+    /// the instance's arity
+    pub fn hash_neptune(&self, preimage: &[F]) -> Result<F, PoseidonError> {
+        self.hash_neptune_with_tag(merkle_tree_tag(preimage.len()), preimage)
+    }
+
+    /// neptune's hash with the given domain tag in `state[0]`
+    pub fn hash_neptune_with_tag(&self, tag: F, preimage: &[F]) -> Result<F, PoseidonError> {
+        let mut state = [F::zero(); MAX_WIDTH];
+        self.permute_inputs(tag, preimage, &mut state)?;
+        Ok(state[1])
+    }
+}
@@ -0,0 +1,163 @@
+//! Sparse-matrix evaluation of the partial rounds ("optimized Poseidon").
+//!
+//! Follows the equivalent-representation trick of the Poseidon reference implementation:
+//! the round constants of the partial rounds are moved through the MDS matrix so that
+//! only one scalar constant remains per partial round, and the MDS matrix of each partial
+//! round is factored into one dense matrix `m_i`, applied once before the partial rounds,
+//! and one sparse matrix per round. A sparse round costs `2(t - 1) + 1` multiplications
+//! instead of `t^2`.
+//!
+//! This module only depends on `ark_ff` so that `generate_constants.rs` can include it to
+//! emit the static BN254 tables.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use ark_ff::Field;
+
+/// Optimized constants for a single width `t`, produced by `optimize`
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptimizedConstants<F> {
+    /// `t` constants added before `m_i`, followed by one scalar for every partial round
+    /// but the last
+    pub c: Vec<F>,
+    /// Dense matrix applied once before the partial rounds
+    pub m_i: Vec<Vec<F>>,
+    /// For each partial round in order, `w_hat` (t - 1 entries) followed by `v` (t - 1
+    /// entries) of its sparse matrix
+    pub s: Vec<F>,
+}
+
+/// Static optimized constants indexed by `t - 2`, matching a `PoseidonConstants` layout
+pub struct StaticOptimizedConstants<F: 'static> {
+    pub c: &'static [&'static [F]],
+    pub m_i: &'static [&'static [&'static [F]]],
+    pub s: &'static [&'static [F]],
+}
+
+/// Derive the optimized constants from unoptimized round constants `c` (`t` per round)
+/// and MDS matrix `m`.
+///
+/// Returns `None` when there are no partial rounds or one of the intermediate matrices is
+/// singular, in which case the unoptimized schedule has to be used.
+#[cfg(feature = "alloc")]
+pub fn optimize<F: Field, R: AsRef<[F]>>(
+    n_rounds_f: usize,
+    n_rounds_p: usize,
+    c: &[F],
+    m: &[R],
+) -> Option<OptimizedConstants<F>> {
+    let t = m.len();
+    if n_rounds_p == 0 || t < 2 {
+        return None;
+    }
+    let mds: Vec<Vec<F>> = m.iter().map(|row| row.as_ref().to_vec()).collect();
+    let r_f = n_rounds_f / 2;
+    let round = |r: usize| c[r * t..(r + 1) * t].to_vec();
+
+    // Sparse factorization, computed from the last partial round backwards
+    let mds_t = transpose(&mds);
+    let mut m_mul = mds_t.clone();
+    let mut m_i = vec![vec![F::zero(); t]; t];
+    let mut sparse = Vec::with_capacity(n_rounds_p);
+    for _ in 0..n_rounds_p {
+        let v = m_mul[0][1..].to_vec();
+        let w: Vec<F> = m_mul[1..].iter().map(|row| row[0]).collect();
+        let m_hat: Vec<Vec<F>> = m_mul[1..].iter().map(|row| row[1..].to_vec()).collect();
+        let w_hat = mat_vec_mul(&inverse(&m_hat)?, &w);
+        sparse.push((w_hat, v));
+
+        // Keep the lower-right block, with identity in the first row and column
+        m_i = m_mul.clone();
+        for row in m_i.iter_mut().skip(1) {
+            row[0] = F::zero();
+        }
+        m_i[0].fill(F::zero());
+        m_i[0][0] = F::one();
+        m_mul = mat_mul(&mds_t, &m_i);
+    }
+    let m_i = transpose(&m_i);
+
+    // Push the partial round constants back through the inverse MDS matrix
+    let mds_inv = inverse(&mds)?;
+    let mut scalars = vec![F::zero(); n_rounds_p - 1];
+    let mut tmp = round(r_f + n_rounds_p - 1);
+    for i in (0..n_rounds_p - 1).rev() {
+        let inv_cip = mat_vec_mul(&mds_inv, &tmp);
+        scalars[i] = inv_cip[0];
+        tmp = round(r_f + i);
+        for (x, y) in tmp.iter_mut().zip(&inv_cip).skip(1) {
+            *x += y;
+        }
+    }
+
+    let mut opt_c = tmp;
+    opt_c.extend(scalars);
+    let s = sparse
+        .into_iter()
+        .rev()
+        .flat_map(|(w_hat, v)| w_hat.into_iter().chain(v))
+        .collect();
+    Some(OptimizedConstants { c: opt_c, m_i, s })
+}
+
+#[cfg(feature = "alloc")]
+fn transpose<F: Field>(m: &[Vec<F>]) -> Vec<Vec<F>> {
+    (0..m.len())
+        .map(|j| m.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn mat_mul<F: Field>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| {
+            (0..b.len())
+                .map(|j| row.iter().zip(b).map(|(x, b_row)| *x * b_row[j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn mat_vec_mul<F: Field>(m: &[Vec<F>], v: &[F]) -> Vec<F> {
+    m.iter()
+        .map(|row| row.iter().zip(v).map(|(x, y)| *x * y).sum())
+        .collect()
+}
+
+// Gauss-Jordan elimination
+#[cfg(feature = "alloc")]
+fn inverse<F: Field>(m: &[Vec<F>]) -> Option<Vec<Vec<F>>> {
+    let n = m.len();
+    let mut a: Vec<Vec<F>> = m.to_vec();
+    let mut inv: Vec<Vec<F>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| if i == j { F::one() } else { F::zero() })
+                .collect()
+        })
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !a[row][col].is_zero())?;
+        a.swap(col, pivot);
+        inv.swap(col, pivot);
+        let p_inv = a[col][col].inverse()?;
+        for j in 0..n {
+            a[col][j] *= p_inv;
+            inv[col][j] *= p_inv;
+        }
+        for row in 0..n {
+            if row != col && !a[row][col].is_zero() {
+                let f = a[row][col];
+                for j in 0..n {
+                    let (a_cj, inv_cj) = (a[col][j], inv[col][j]);
+                    a[row][j] -= f * a_cj;
+                    inv[row][j] -= f * inv_cj;
+                }
+            }
+        }
+    }
+    Some(inv)
+}
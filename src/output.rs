@@ -0,0 +1,142 @@
+use ark_bn254::Fr;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::{
+    bytes_to_field_strict, decode_hex, encode_hex, field_to_bytes, parse_field, PoseidonError,
+};
+
+/// 32-byte little-endian Poseidon digest, as returned by `Poseidon::hash_bytes`.
+///
+/// `==` compares in constant time, so MAC tags, nullifiers and commitments can be
+/// checked without leaking how many leading bytes matched. `Ord` compares the encoded
+/// numbers and is not constant time; it is meant for map keys and sorted lists.
+/// `Display` prints the `0x`-prefixed `to_hex`, which `FromStr` parses back along with
+/// decimal.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PoseidonDigest([u8; 32]);
+
+impl PoseidonDigest {
+    pub const fn new(bytes: [u8; 32]) -> PoseidonDigest {
+        PoseidonDigest(bytes)
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    pub const fn to_bytes(self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Lowercase hex of the digest as a big-endian number, matching
+    /// `Poseidon::field_to_hex` of the element it encodes
+    pub fn to_hex(&self) -> [u8; 64] {
+        let mut be = self.0;
+        be.reverse();
+        encode_hex(&be)
+    }
+
+    /// Inverse of `to_hex`, accepting 1 to 64 digits with or without `0x`; fails with
+    /// `InvalidEncoding` on other input
+    pub fn from_hex(hex: &[u8]) -> Result<PoseidonDigest, PoseidonError> {
+        let mut bytes = decode_hex(hex)?;
+        bytes.reverse();
+        Ok(PoseidonDigest(bytes))
+    }
+}
+
+impl ConstantTimeEq for PoseidonDigest {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for PoseidonDigest {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for PoseidonDigest {}
+
+impl Hash for PoseidonDigest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+/// Numeric order of the little-endian encodings
+impl Ord for PoseidonDigest {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for PoseidonDigest {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for PoseidonDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = self.to_hex();
+        write!(f, "0x{}", core::str::from_utf8(&hex).expect("hex is ASCII"))
+    }
+}
+
+/// `0x`-prefixed hex like `from_hex`, or a decimal element of the BN254 scalar field like
+/// `parse_field`
+impl FromStr for PoseidonDigest {
+    type Err = PoseidonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            PoseidonDigest::from_hex(s.as_bytes())
+        } else {
+            parse_field::<Fr>(s).map(PoseidonDigest::from)
+        }
+    }
+}
+
+impl AsRef<[u8]> for PoseidonDigest {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 32]> for PoseidonDigest {
+    fn from(bytes: [u8; 32]) -> Self {
+        PoseidonDigest(bytes)
+    }
+}
+
+impl From<PoseidonDigest> for [u8; 32] {
+    fn from(digest: PoseidonDigest) -> Self {
+        digest.0
+    }
+}
+
+impl From<Fr> for PoseidonDigest {
+    fn from(x: Fr) -> Self {
+        PoseidonDigest(field_to_bytes(&x))
+    }
+}
+
+/// Fails with `NonCanonicalBytes` if the bytes are not below the modulus
+impl TryFrom<PoseidonDigest> for Fr {
+    type Error = PoseidonError;
+
+    fn try_from(digest: PoseidonDigest) -> Result<Self, Self::Error> {
+        bytes_to_field_strict(&digest.0)
+    }
+}
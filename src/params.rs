@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+use ark_ff::PrimeField;
+
+use crate::{PoseidonError, MAX_WIDTH};
+
+/// Runtime parameters for a single-width Poseidon instance.
+///
+/// Unlike `PoseidonConstants`, which bundles static constants for many widths, this holds
+/// owned constants for one width `t = m.len()`, e.g. an audited parameter set or one
+/// produced by `grain::generate_constants`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonParams<F> {
+    pub n_rounds_f: usize,
+    pub n_rounds_p: usize,
+    /// S-box exponent: 3 or more and coprime to `p - 1`, so x^alpha is a permutation,
+    /// or -1 for the inverse s-box x^-1 (zero maps to zero)
+    pub alpha: i64,
+    /// Round constants, `t` per round back to back
+    pub c: Vec<F>,
+    /// `t x t` MDS matrix
+    pub m: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> PoseidonParams<F> {
+    pub fn new(
+        n_rounds_f: usize,
+        n_rounds_p: usize,
+        alpha: i64,
+        c: Vec<F>,
+        m: Vec<Vec<F>>,
+    ) -> Result<PoseidonParams<F>, PoseidonError> {
+        let t = m.len();
+        if !(2..=MAX_WIDTH).contains(&t) || m.iter().any(|row| row.len() != t) {
+            return Err(PoseidonError::InvalidMdsMatrix);
+        }
+        if !n_rounds_f.is_multiple_of(2) || c.len() != (n_rounds_f + n_rounds_p) * t {
+            return Err(PoseidonError::InvalidRoundConstants);
+        }
+        if alpha != -1 && (alpha < 3 || !is_permutation::<F>(alpha as u64)) {
+            return Err(PoseidonError::InvalidSboxExponent);
+        }
+        Ok(PoseidonParams {
+            n_rounds_f,
+            n_rounds_p,
+            alpha,
+            c,
+            m,
+        })
+    }
+
+    /// State width of the instance
+    pub fn width(&self) -> usize {
+        self.m.len()
+    }
+}
+
+/// Whether x^alpha permutes `F`, i.e. `gcd(alpha, p - 1) = 1`
+fn is_permutation<F: PrimeField>(alpha: u64) -> bool {
+    let modulus = F::MODULUS;
+    // p is odd, so p - 1 only differs from p in the lowest limb
+    let rem = modulus
+        .as_ref()
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0u128, |rem, (i, limb)| {
+            let limb = if i + 1 == modulus.as_ref().len() {
+                limb - 1
+            } else {
+                *limb
+            };
+            ((rem << 64) | limb as u128) % alpha as u128
+        });
+    gcd(alpha, rem as u64) == 1
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
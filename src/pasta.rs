@@ -0,0 +1,799 @@
+//! Poseidon constants over the Pasta fields.
+//!
+//! Round constants and MDS matrices are the Grain LFSR instances of the Poseidon
+//! reference implementation (x^5, R_F = 8, R_P = 56): widths 3 and 4 over the Pallas
+//! base field (the Vesta scalar field) and width 3 over the Vesta base field (the Pallas
+//! scalar field). Width 2 is not provided, so single-input hashes are rejected.
+
+use ark_ff::MontFp;
+use ark_pallas::{Fq, Fr};
+
+use crate::{Poseidon, PoseidonConstants};
+
+/// Constants over the Pallas base field
+pub static PALLAS_CONSTANTS: PoseidonConstants<Fq> = PoseidonConstants {
+    c: PALLAS_C,
+    m: PALLAS_M,
+    alpha: 5,
+    n_rounds_f: 8,
+    n_rounds_p: &[0, 56, 56],
+    optimized: None,
+};
+
+/// Constants over the Vesta base field
+pub static VESTA_CONSTANTS: PoseidonConstants<Fr> = PoseidonConstants {
+    c: VESTA_C,
+    m: VESTA_M,
+    alpha: 5,
+    n_rounds_f: 8,
+    n_rounds_p: &[0, 56],
+    optimized: None,
+};
+
+impl Poseidon<Fq> {
+    /// Poseidon over the Pallas base field, hashing 2 or 3 inputs
+    pub const fn new_pallas() -> Poseidon<Fq> {
+        Poseidon::with_constants(&PALLAS_CONSTANTS)
+    }
+}
+
+impl Poseidon<Fr> {
+    /// Poseidon over the Vesta base field, hashing 2 inputs
+    pub const fn new_vesta() -> Poseidon<Fr> {
+        Poseidon::with_constants(&VESTA_CONSTANTS)
+    }
+}
+
+static PALLAS_C: &[&[Fq]] = &[
+    &[],
+    &[
+        MontFp!("15069682083288160432732370099798351618679311578385352494909426280271494064689"),
+        MontFp!("19552372332799130802276417626232312252003112635717995616214163996953876465864"),
+        MontFp!("19043902806963638374160190911613821337150830066549453686591397590151261639780"),
+        MontFp!("15514927955085138849903142742338037532910619924819969724732960117103183528083"),
+        MontFp!("15495014697804903298255235331386641424574726009953953908941032386906465281856"),
+        MontFp!("22783616522732622893019082906592797185801504915639760629184772959223142258739"),
+        MontFp!("13013282258164837279493662912995836732931467481763657157388243170268288494777"),
+        MontFp!("6981250340229026941600359079713644943364499010076989145230271905447893411428"),
+        MontFp!("24035237617505623260266869665310459241730996321154035943461846954723024413195"),
+        MontFp!("19490150944318489161354799945354283869958593100591069159064256502786081113445"),
+        MontFp!("10001310054178566741581108732318364481096445685845154795650911304361521661288"),
+        MontFp!("13265290993276339200466518757285986387873219067370510717658484079003987909088"),
+        MontFp!("5168875594760045207805167119482723892422371357036786272812920185355882590357"),
+        MontFp!("16404781914610122477169558382353268685340329314879749933213689777663574642030"),
+        MontFp!("13926627674696294849949462322975705763490000301788840352575629025889041709871"),
+        MontFp!("5540005465573610713369283553280413619347229650432107922453007392732395383302"),
+        MontFp!("25331413312157336203788915641742781025904079251646304923273846484713954689805"),
+        MontFp!("22209006774619169629238704899737836689832339477498820549083655530919085937469"),
+        MontFp!("5311185660468923011620882201281555732086369217347178075669744505704316314145"),
+        MontFp!("15478623232978858035827156578649305899475322662360676831830504986059993752553"),
+        MontFp!("12519402894226670702047228301088292812324011377069509125270413768864485388152"),
+        MontFp!("25265407608799125973911895075576151376017821913856802599988018285045773189436"),
+        MontFp!("27425326055714195207210257024227984260401076936541461193784131216457004989193"),
+        MontFp!("3768678765872159570334616338154024307389259318183719031698087507626949916634"),
+        MontFp!("25086755002143452066622281417655366233567137314445218198002604928363131851181"),
+        MontFp!("23779491437658546967906847642122014312188103887363792308946119528980860787181"),
+        MontFp!("24955891074778304314273694435921532988165668138889091495806365274009288972837"),
+        MontFp!("27608023756696462562086135196379277578164230293508795841538784900757910244975"),
+        MontFp!("1080226634920093792565119304474407980618655593930293185675690557789787269714"),
+        MontFp!("11469284678695953893354610068791896411465714706458842211112702612541728086569"),
+        MontFp!("8084618519052242994285315279354211170874167518709449374556345882449384664914"),
+        MontFp!("3197111092620571258325644615893031262113157326998479923840014013137131891865"),
+        MontFp!("8936353836030705427988862862233037869944090544819240137805149557956090660037"),
+        MontFp!("3159228639587844516793026797109602325520417644888043082840400709519943335087"),
+        MontFp!("8223951140921425480780788933706256452584667061314395494999262876180139867481"),
+        MontFp!("26278918500147759775494040128510496726909950674693464120779447256751644164722"),
+        MontFp!("26779879159404780097315149077287766113601664881164369916028591314567870355151"),
+        MontFp!("20456223343957287727514213669396629715995897855649159394525218606933035704192"),
+        MontFp!("12747039712720051884421283218579068027169362545566746338894823615977089768645"),
+        MontFp!("27898851815710187079705561875563256790423231382156200112332228390723271299275"),
+        MontFp!("1082651051840204340034509102399834433911785522401318955077701718631699684491"),
+        MontFp!("7079478478825447313725566949453824123009129220327890795130819256071009925257"),
+        MontFp!("16775457493308856116209955838772682657406283117383038425813679898244265869081"),
+        MontFp!("7470547477163269972171214292244143106200345859222444719977197893052417329024"),
+        MontFp!("1548460794806212448462544801932001590091559214841471349303252342401635420988"),
+        MontFp!("9710379243331339391425756096099609297363072174144732918502957312733827361665"),
+        MontFp!("25557916014812134616741794835185340865176681029029911448118486027379598419824"),
+        MontFp!("27203905164251724386864486493184261124876028946032441782291642547535072250765"),
+        MontFp!("24625437726134665849087299926909516861882286967636758123373386905204291527519"),
+        MontFp!("18399131756860263245632355856085898109846105597980167545439769047691399369221"),
+        MontFp!("2006984980145785374239883910267497430622626915726794516080549269061371741375"),
+        MontFp!("12396928969388423044049752681574951136377530623963875360936480702464073837248"),
+        MontFp!("12114905967386652311865918547204654236955577491397322081448823934460193386797"),
+        MontFp!("10401998325120181088916772218934245595382205296446102357054486991342799209542"),
+        MontFp!("26911117626519727234397280470579483704522021687326570539888597777543155036833"),
+        MontFp!("16178563677338850636323817606133246816968202242684869407274198108437675500963"),
+        MontFp!("24609327750431672087261202601111011395169770206984072233495868616129877212531"),
+        MontFp!("19482735308953249432669137408021717720428231082355491611546353416655589592340"),
+        MontFp!("4764894878114589733354385240482172124912883938531667965986136194711928132233"),
+        MontFp!("13114707554944862672438565577661976538565216138843156583255044274084192242063"),
+        MontFp!("26514654721325469812795406739655803678839887227616850563164721996934129593885"),
+        MontFp!("27126849378630612860366678348207722981358217324654958134542524347309943374582"),
+        MontFp!("236991681791069900684736931834346209872667167685372604088058265168904639678"),
+        MontFp!("14751080878640780948793941540266346729539404711251035167406410355870311851517"),
+        MontFp!("17063576703247539896769726695823097250024396057514079699081766963840922451736"),
+        MontFp!("2678670726345510540233267914626485703001052639233984097013988075445853551597"),
+        MontFp!("28227478247801079192371492183561575762821144884795494773821038295704687443211"),
+        MontFp!("5210533892428165644429246427363395072036163009697389936492578843733239349257"),
+        MontFp!("18565328527355902314638803835821683223077355049529845924128501780833336857204"),
+        MontFp!("2141802313494140370073018441574861992652757526683544636831277365623658291589"),
+        MontFp!("15903307826353145606870059007907818011919330577771722791409049863634014171629"),
+        MontFp!("21789964138026487246749132534358503414358720591077571155045035726152900260283"),
+        MontFp!("7442079075042857503374740032054228297945408872846551468629659912935727218776"),
+        MontFp!("11857459086272814010999262004984281116267744158822076580327736381537645075869"),
+        MontFp!("2091190871418370281590070735405568973418605739655753629014523500610411588600"),
+        MontFp!("26937997670332816050283264807299604599440310862750972678751522541624260828071"),
+        MontFp!("7972639145875100235259305739238229435664723046014927544952331947835699686334"),
+        MontFp!("22990354086345679157773926123388123897037509529865384672393345100211020789940"),
+        MontFp!("27835418420514150922324621870473968951447297351380663051383641541477283087718"),
+        MontFp!("26037037662526543183165785736562446815576768327202815158151661638541917290038"),
+        MontFp!("22733808701222687584037244726901966408364756561054715824812307029085152197642"),
+        MontFp!("6909665327947373477876503326690117023529844275178113772693805280086804275789"),
+        MontFp!("7142936019716449044931405839983402075733572660094931801224445203683818523487"),
+        MontFp!("6195670886424446063977106583870779707000929864809996500832965273157219182343"),
+        MontFp!("23626751267233364310995403567470582688694752693951259636362991013411409154045"),
+        MontFp!("24117560745911367534934047343436604087458307327200065653417350567748509644008"),
+        MontFp!("11256113935068829705348336396109049816650575048305516437972021145493818303054"),
+        MontFp!("12038411585482408245866588535507440716321383306582213959649631352380505296649"),
+        MontFp!("6526502100039000285301377000454544755879307550325897610117285417992653989883"),
+        MontFp!("8205951858827962858297044937178522337758499050416522405544152346456182876452"),
+        MontFp!("258398675175833276592949264479784361720882569973571099775733400234847598810"),
+        MontFp!("22223153731430445043444016839574137508856134848907757325890234838652117711255"),
+        MontFp!("22951305417350044093692687017278203980139082646462566498062849603914254938713"),
+        MontFp!("26227258298154231476832786489383884166035950963373421526125560917884324210124"),
+        MontFp!("25291782232716612141048957027934887898040440195011622096126796765924895737287"),
+        MontFp!("8505440081055658194053172657484459326491099490258463076621342719248831630197"),
+        MontFp!("11365018193794909309545286941196906855169274477738507283227421787403768748969"),
+        MontFp!("25736678787176620090645934284034870430980578056043435302357339717372990968826"),
+        MontFp!("2093316466514052019706232110655619447777479424303545028727744865960868558731"),
+        MontFp!("2680077661465513628082040274885478576977430197291388638064080263520494949856"),
+        MontFp!("5729302472764015845222707014257473080859933080961697625938995555882573633064"),
+        MontFp!("22272502822659301784370016135642595749648995815203953291431983719105487529766"),
+        MontFp!("19741397985720228172534098658729160979413981846806940466958557796590189770662"),
+        MontFp!("4778855865285211663172499533545045925722873599431753726389782546252541496490"),
+        MontFp!("4169459141156341175215230696374765606570647592859646446835515013786945456302"),
+        MontFp!("28521126816855872476883625640419544513729454445948267490846928448029046818145"),
+        MontFp!("9937275659915884209465318727755953580740132368738485847765689432997053848728"),
+        MontFp!("28937686875138289956188244889191850574004773598279618129296474335044152339184"),
+        MontFp!("11936378053002679728544971557108961055262394145293696880702109600002543710922"),
+        MontFp!("9304690391983614872047450188868484086392196244117934306347957287162354277881"),
+        MontFp!("14912889187608850624998509012566219001003137346393918987174119070334478148726"),
+        MontFp!("10902700458872657355784751515874982539763794767384646478005720625487850984103"),
+        MontFp!("12855300847783316358900144508437611561727809518091406297908608950098225219206"),
+        MontFp!("12808905174791867200279004146841646719638664553785558199159750485287629394709"),
+        MontFp!("12721859968629888269359610922006787499205086924392139184099457087455588878536"),
+        MontFp!("12149718278790640625999519947850358944529357815447148500537556634924379118783"),
+        MontFp!("10467370517928203582042229398077284639523752511039488171348394941357680108530"),
+        MontFp!("24655006251422685937728251841804448521960992769716312885334528834067641655543"),
+        MontFp!("18239622862789216008934445523262406025863074314710097942681312866619449581817"),
+        MontFp!("11874377445400854013054723999779496567271258825608398515243321307826899069961"),
+        MontFp!("4864318393192213171240543126558044134145285759574559698047342429668232332724"),
+        MontFp!("25581916704016366754416610582415654066579771261186983025724796917641936007677"),
+        MontFp!("27592097404650504147675913359698603491988170657921171728295211603320943182513"),
+        MontFp!("8132270650536091121856741673243842423515057415821694090391564651087862024923"),
+        MontFp!("24077613630605687980575068864990684432687934839285097579300393328780506189618"),
+        MontFp!("2504917251000859290029597265410428927672638767278326159148609301207336050135"),
+        MontFp!("3507717361151347186372804119943947217698185013949406748460940978493797077547"),
+        MontFp!("10238478552406470014862782012869182317520630503049294244340743528542317257229"),
+        MontFp!("23452937932971293828724115398936602431558898471997852441729752824149860069940"),
+        MontFp!("5507174975377567449908815948780307011973002050137956136417253437184643281618"),
+        MontFp!("26152696613558111513010988524265655065462908309470930344484687481220893285969"),
+        MontFp!("4927644999310074369011503915883277897515565700973005455630539895756185854571"),
+        MontFp!("26786495534173573884793314662481527367728245780784202879468757321445313190277"),
+        MontFp!("25352112591295528442598094170098599906211820405487804600825992787445328151648"),
+        MontFp!("9541876906713498797378423820637716464941788950322477528498096731033634986169"),
+        MontFp!("13347247001936238982830304745497426747310001544654619311462581265111950013319"),
+        MontFp!("6821368916967661230938349886852948172263852043978261612161874120112047492849"),
+        MontFp!("15283865223775277938893948800494028039218001261337770498371224516905124257013"),
+        MontFp!("27749485148124544306301161468235879614841484812651078331844135495400074266281"),
+        MontFp!("6196647483610958539761559037463425065291601127652682919796373268738102084988"),
+        MontFp!("20149961612753930727582312440976742151191204420142426724772662781898743424645"),
+        MontFp!("23703492919611159223856415838004223407240264841129924398573810900152977325413"),
+        MontFp!("18954977684484282900482692634437051906789629587468888897072760491258232030021"),
+        MontFp!("23637404216495656449676884613666125716776863769919834862820342893488100975261"),
+        MontFp!("25052291902893170786389609555028232023408993081815971996587255273919239111426"),
+        MontFp!("21918447195920351647336742499546629335791460923502477281272651087966321757476"),
+        MontFp!("23626417547856604455624403914897560192102128625447916336781401018958471576074"),
+        MontFp!("27688182248297785076935773219820877738426440535915819801595379051487697785792"),
+        MontFp!("13220470919427537642552050388100040000195457107638915815290677008184618075209"),
+        MontFp!("19194608302443102551527527966531494788470411803783058547783177356037418408446"),
+        MontFp!("1404147693257553669177951406702477863728339350368759020247125551167062218761"),
+        MontFp!("15448823693591390106136801553962240766990078673835077742117238854009358602062"),
+        MontFp!("6582375792694694167058675493137279606284154603511129883336409801108208869832"),
+        MontFp!("225737682581293581179842866433566979380295502854318984377369282011116631702"),
+        MontFp!("22039073002911562685813640381878901717543527717766638236646574477397401246385"),
+        MontFp!("16545685945783357125471811213792742719607911728884208493674617459269547776316"),
+        MontFp!("27910479511228880009360061842845238183732896084397213226001044088660669156341"),
+        MontFp!("10749831239902890639364394373388829090293328534687970567270952443097458479621"),
+        MontFp!("25801382612260750807176567306185100806245800957539080470982401613733847017389"),
+        MontFp!("21478681944505151232769112854403239444771196992862352028539522578371697770792"),
+        MontFp!("28787075409272728909463776127520390674639904299633896875197734352633823855783"),
+        MontFp!("835370699545620877456284280172935272028455352393732631056733870831421878570"),
+        MontFp!("8251935792645352066003472758657542796124411323268491925524587614243033732523"),
+        MontFp!("27902415527132863705974774952879292466590802685762862893583738200340757381081"),
+        MontFp!("24359340513663374411242640414346337131063700645761497929998219379501103527667"),
+        MontFp!("9093882205083685941153498175525403996893843797372167462155475056114450164823"),
+        MontFp!("6916891904887948883201733816823900696451124681457876821033260483567204857277"),
+        MontFp!("14667147518061791821528796158893812955530302194419423260855853227654634260193"),
+        MontFp!("15299327721255367393777577852898735767506934211174133458732221146042818360078"),
+        MontFp!("20176333718435620296741725024615884136310539579367621850139940464074226251693"),
+        MontFp!("13932915224939920185237974062060506324293379999528941707682509433919767847316"),
+        MontFp!("15010155868499727052646797554996331419512441279722532416217954787125710432775"),
+        MontFp!("25156829243294730301898460906907876197255678416855099501924966061248446928057"),
+        MontFp!("21353200017979506555483391209250072460166352446895897441334419101883859559040"),
+        MontFp!("15487161047607689456399148988841445820330113670225845384538495598896207396548"),
+        MontFp!("7984092475671550229551455331070011525649193769331573127915741006891633639583"),
+        MontFp!("22546525275055847966964854979935064065262455006200549389266006423238691069289"),
+        MontFp!("18086819150071553330554959042846355753357300736888859214673445003567430523918"),
+        MontFp!("18191574381277135401347782576091474687430418599697352734710106721733327791488"),
+        MontFp!("15905437465297970563705232698711551947467023501848437208196761987207924924541"),
+        MontFp!("25848374577539774581196270494705610428524458984312008540126985541645676597417"),
+        MontFp!("6210360595811783286317260121592387304358086206140844147404703857444283605071"),
+        MontFp!("28682804891159185636152195042784732954944496907875645016660815243199115985699"),
+        MontFp!("7599049506625668775607926402522378412503847853486268630131782363812100337646"),
+        MontFp!("25227241923136823255122683229700043762557658620405700228415820897624655202103"),
+        MontFp!("3051672138930866023848792153189168019303710196606665152314209620496136005161"),
+        MontFp!("17407562530836467959545327669620432605056064355763822195664090193918567319970"),
+        MontFp!("23962473404443897694420624988790637870540052939255465237625200853662527566107"),
+        MontFp!("19817941717900800753911852047879195294507586827877873753324298963864958483695"),
+        MontFp!("9255063042638646434672244663926162255611038780081491745199426073041017278387"),
+        MontFp!("18302449404011056177161371180903042680360561412201103431093343019859989502723"),
+        MontFp!("11055283330303806791981104016138402404578863912800889397884804770621189509412"),
+    ],
+    &[
+        MontFp!("1370520444679725988228262470913666553238263453957210974636393810919959979221"),
+        MontFp!("10904314678364808044230213451965080218085737975089994055575542411979256546511"),
+        MontFp!("6349586935615331236579567710640725058404989192089454553534808747777432000567"),
+        MontFp!("8764746332168020320265685146882040707735876837249392529416580448674426737945"),
+        MontFp!("2753995214152641315998608120979458811338081286005609322599429597294278074021"),
+        MontFp!("22087651368285987499179492227258546558776915575568665944406660021708851126718"),
+        MontFp!("18586560579856051660551746178410532388300930801726220293583473786526316231735"),
+        MontFp!("3219384989687415172500344625905168403964311839382894404814053845356587061020"),
+        MontFp!("3969431972379572587155571199555836290596475738381796216269991262070911986908"),
+        MontFp!("6681072794479434321634527872382098993235714362122247914875165392981552688642"),
+        MontFp!("25571738279430898680761101903383154814378626634718212132594299928831200592332"),
+        MontFp!("14951195081217542808583191091966143527501400190276244798784141582090616893850"),
+        MontFp!("6554934978505538932134734303024884835890828784408924826581058267739640748992"),
+        MontFp!("16043658459305955312233658378320470641756824192822111021192660136133301741763"),
+        MontFp!("5469206939287818887494087595414050903946074496593401839929228620927815547742"),
+        MontFp!("15052501386706020599882126563229866758716220313400866315931952699307126689150"),
+        MontFp!("16397736235714212366409756459423566009306661718453633881576590614957113474670"),
+        MontFp!("27055303296734363948563820302369378462318110993895435257425399277854393714338"),
+        MontFp!("12789364635649064696054408181362139458203577736949914512501363359175349596013"),
+        MontFp!("4974768925091180315530469688349560145876078006442609399346127968552493030436"),
+        MontFp!("23642847112256863921824626464828320950170544767830019950162246263604418341908"),
+        MontFp!("25816059562075803364663984366037288340684968946549488396412915528358975068163"),
+        MontFp!("5984340577257150194219476524669677068056569168032460881692866992422749959373"),
+        MontFp!("25269611455577922776035009856213089041159237964217618064537581611674840821955"),
+        MontFp!("28507728677301369656652822834706695537913133363551624954314896242376247958035"),
+        MontFp!("7945274132079834101530449801531076631104936788052814984595948662604152543536"),
+        MontFp!("19628365891313809497596750902837978943828962608377125703304663344695028454095"),
+        MontFp!("27836694223266583155989163400201173160023062352760945000059173810699310306628"),
+        MontFp!("5916309528670099066446132553477828769328438692333697616915603152279974329689"),
+        MontFp!("22556104744504020278873746551928270574902071947335003432178112995253892995905"),
+        MontFp!("5746388943697798704075019607614894992471695727174266423511963777554176222211"),
+        MontFp!("14534795918922206806235409403988677673666382776602070515264645828334875625917"),
+        MontFp!("9335383362627185276799979877799097561991301923269937895509612196472173788880"),
+        MontFp!("5106964826136471130963814904302232589954964367434983724462627578310059842407"),
+        MontFp!("28182884432704221998797924801002511697488813605515902622706078499354764564963"),
+        MontFp!("27427512859882452474148600504816354349859158946152372063355189981236487231200"),
+        MontFp!("2497908365207463349748918999005882837399248282842762497518641804295652211712"),
+        MontFp!("23080236203753823521367189700574752738195033273016118767887079224219882828298"),
+        MontFp!("22008177557825548714013013079652933694832981836719244106640938976782875928300"),
+        MontFp!("13063576081780315381603330418381445226180182907433399394679308045787987352590"),
+        MontFp!("20240940716226487729689425482717905802816967531024801674787006330327633274919"),
+        MontFp!("9544331330997447435124467121668874235420308435318676450995468750823261664276"),
+        MontFp!("14308956146491308075148954005555924544592891364321982749944572835892226135984"),
+        MontFp!("27498626689403765004067833752455973236997852046089320043023311849075169077963"),
+        MontFp!("17020001715850477393898071880852365340088959550350299994309244131271772089138"),
+        MontFp!("15844781112614200761028238979932846764022593203256895296638159670729660772298"),
+        MontFp!("26017333560401811632837262936567926013863703994918649491776706837631199331502"),
+        MontFp!("6281080124263669343237170083642108115421057645822521354172989032265370145128"),
+        MontFp!("1655454839116271620575749293234574274222993456067245397034476501783047702987"),
+        MontFp!("11302640827401046868813685197817902243008854423815484425332056543009205521370"),
+        MontFp!("19578228780438011420624902648651489689332348017075452075789389495641515107526"),
+        MontFp!("1666726261581868906801456292503816513141347546305539848542007723530050849112"),
+        MontFp!("5599112757051382945777209761927842690976326262291653896197147009061146745988"),
+        MontFp!("2409493590054208958374949729641477680883839845998908515374399694494329108693"),
+        MontFp!("9369028429127203794192971970752143335885775173146694301391342234577831311729"),
+        MontFp!("24397467974886117679160205937888825720945482611255960634279679963680280751609"),
+        MontFp!("9746726349753403789756808032086478458320047927693804876223210454427848152455"),
+        MontFp!("21482635785009966188378819639648300586364231845630021175074355245099600549611"),
+        MontFp!("4750785218472448199946495651043918405071094597133085775232454516071918670313"),
+        MontFp!("8506951532504720413535844871634697448522153784942356613601176599885339116279"),
+        MontFp!("22505633264778970761396576255816357511261068050218108921438097604965144387230"),
+        MontFp!("11831171017125238400494001160752938431533426772357125798866247136372052031633"),
+        MontFp!("287378964627352873177644545264407589209245483537768925610934787360661675307"),
+        MontFp!("21395557280972200352935495524360575478284948876240057549672630565754898687475"),
+        MontFp!("11151574305677326496508847602654325750931882622488859533751156703335791442500"),
+        MontFp!("23176446032156911556681163735964460088367412384346998644831788535866095783907"),
+        MontFp!("25013819107861200871362763274086348118604854366898794646030011194469845796415"),
+        MontFp!("19567114239944088120182151576580746770749741023802236387349349983405056346215"),
+        MontFp!("2106082962224677382939499300262722414994142226749429458574228463349817137446"),
+        MontFp!("21488726403520602433412177403198395506814161155884024548765698473807436437187"),
+        MontFp!("19874389305460885643370670233898930265797432322257101548601144109087150938731"),
+        MontFp!("1626063516190230014066269384047367454995825185479824852734384567195006885600"),
+        MontFp!("10810840117237347488369756214920011498151790731611107208910892886311569789743"),
+        MontFp!("9150715968393290898498549262976154567808374032681082660068962444761451210324"),
+        MontFp!("8982834703527797861839412363861866770624764672791560560865132583698330639987"),
+        MontFp!("7578156132851051599628743403125925693771832908105808495208256981767217224493"),
+        MontFp!("27681526531733120476606571351188233778513940164082965303645738300453778753666"),
+        MontFp!("24463417913263327143654549068777587238139067649902482248214374567520261816015"),
+        MontFp!("24940531539280306452277732266232662172583427834156412827433855876429941750255"),
+        MontFp!("3721935602468500474275409904463084130451434647858033328364698929463348406386"),
+        MontFp!("14760524023936338544565983571830598004344868230350575237710117759391655711145"),
+        MontFp!("9928126117863816642733083939891051939935167916653319936149565996661356913806"),
+        MontFp!("9203309867499608775681898132077626809082089199383545356262185389777976476979"),
+        MontFp!("27058175272637855536119794581494362105972717979599083950780192040500453884860"),
+        MontFp!("13304777388606337567833978771868357294897224276944581464211075155155755981458"),
+        MontFp!("11144736070906755782611625123063233295929461829500908937240428238622250764080"),
+        MontFp!("24870627946249178882177389102058934545422736670070762385514286962937053222660"),
+        MontFp!("23564114052540301783116943415774942117663321140998659811977381541292515625754"),
+        MontFp!("6136316109717448476911022204287786386209120824073595158967426769250595091953"),
+        MontFp!("3091881387868466597386838623831237886049823757718649996447200705078580376115"),
+        MontFp!("4454686012075561039011926006982290713740289762945264098484664898038754329884"),
+        MontFp!("15318541945433775417553530676675532319120180197999278449682125950544603498650"),
+        MontFp!("17146687321154087692378466595707165449687123495891298147092142141505845312206"),
+        MontFp!("8191580993298922860147608305309094640120815917887430996044294592718919389613"),
+        MontFp!("9329061113134462804599113283178896436283404767427401419304710287753840742425"),
+        MontFp!("7481463502429213881555806863809265523413988113260721713701539910589917212734"),
+        MontFp!("23904473855555189461519282739363920544777287568000883999739923105707820516373"),
+        MontFp!("13013602894042166855542931848610885982581702516124567198498749487460240771110"),
+        MontFp!("8351002998573577697495162211418435742255211700646176831757329133934121781800"),
+        MontFp!("17480710079554125542441006901285024138060122999903968018230261587289407026837"),
+        MontFp!("17075950064777286702673643721856460221311122428907993982312895896590011766349"),
+        MontFp!("19698319689449804965374271249898073014616836315392340065702550277626218800048"),
+        MontFp!("7772411579765951090672122176465008797185279611302662319745737295929804239087"),
+        MontFp!("11889360987871649601551882899928875042758776607466285118280758002345997830258"),
+        MontFp!("3705415384153857368096223909370609747605144943328000903237748146312742447021"),
+        MontFp!("23526004781773596239317400546871333426503416151531259845147457745331749488557"),
+        MontFp!("872976432811155781085699703688271826762824288634904331353135555466071090075"),
+        MontFp!("18193909225449607731799697042688797027362722499446651268234233463222984822633"),
+        MontFp!("25347928293935187070195211873894766464931012354412211300648381435654666407204"),
+        MontFp!("19755146966666705788910044185365588087096768032209613831936051669945448406694"),
+        MontFp!("5859978328786901282193761033880070312121687127979056560520976771776012204593"),
+        MontFp!("10748246348071352004097176712330632857291363529103927381613829497545445222625"),
+        MontFp!("25428865798033511134904899547874206248135745722712552731076184474713048816325"),
+        MontFp!("6345058700678451451474721364612644686291088373197518117247461922114451362832"),
+        MontFp!("4983946948258244665330639819946746710881367075183909387124249985242631313187"),
+        MontFp!("15683410550117040699830973934425984195796628164705176838337887072093005102304"),
+        MontFp!("26636374903860983787041966592859435803698975896438430107387714615782002161044"),
+        MontFp!("28233149857965597202548710587300858295565407021256435545087048718404029524094"),
+        MontFp!("1109333099910515686066827787339902267447685002446284961778913417692592974833"),
+        MontFp!("9253037108333354268176893380261355448380284264319339629810114358582581436310"),
+        MontFp!("8832195681577639191418280580861396815223235853776583022923869509651957972963"),
+        MontFp!("13417889808975748181538839685324907571136815284913626419487608797684204627591"),
+        MontFp!("8258410361054407490750515862908656279121399225427353480613536653773199887103"),
+        MontFp!("9507351959090265807864525456853284133940932292804999257963948075500872710607"),
+        MontFp!("7718879057666406454245502916422647781482542525726905830189942612531048085025"),
+        MontFp!("22340398882451260022615758120944911465920029942029665778654071297602879638857"),
+        MontFp!("16469797442999099723607174109015439380006077484826771467979480922177784283864"),
+        MontFp!("22857940224538172357102129975275338997933005152289183852747623491940528792114"),
+        MontFp!("28142987505329131998964365677387966775881465840652291779820915108092423574179"),
+        MontFp!("10960395800643098918952216752058689900258345443973501556311011458138797773075"),
+        MontFp!("25029465373010213115037516220461829171851623829126754127456957555936216767861"),
+        MontFp!("23641171984152300212750271269641428908028196195209313587740831076572785003081"),
+        MontFp!("2344924412149728727359298993358291083972972161029118219844447056909802649589"),
+        MontFp!("27732246361414808510800105623244220999575948692729933039358122231978060696311"),
+        MontFp!("16095093230599114084437216924729069586404843861744829375971615465966955225309"),
+        MontFp!("3295336389769061462166494005913072151119130898184831584973073657686928230528"),
+        MontFp!("195796946742617127687672210696189716578713722188348948884505364959912445558"),
+        MontFp!("9374247208038257594741465202689484503296451728954324710551988713532193732644"),
+        MontFp!("10727773120486838979089580862042142030433744334234879247497582539431870018024"),
+        MontFp!("8350613923891547934559799726383813628352222864626316704240749679088863466077"),
+        MontFp!("9693902666656303608929913487510127256111716875482188778379262228306244757998"),
+        MontFp!("9345277687408427711681424759385633099249988663052404484813576985013666314567"),
+        MontFp!("23584157439605349666865128560222502259879306939450450490348805071695367322019"),
+        MontFp!("7714913014073522539597004492368959398970741926943700214687695082971872201312"),
+        MontFp!("18809426280749616425280716491035589720305312414990527785909546592963158964901"),
+        MontFp!("21434589496312620237248425051135790079786030041568259058625330920419964009338"),
+        MontFp!("4110092154188680597255898662211488790253302387770024693109739981543060705977"),
+        MontFp!("17812614221813611873505355143702971110454556404760183975640689638497877713"),
+        MontFp!("10770381837050558913849551632018116909204377294339150623407793497997880022589"),
+        MontFp!("17948591912327775478764714953388009242629974017518162245393457489636839428338"),
+        MontFp!("3217711550190844364754938996682178457925813754374337362122259938503701223514"),
+        MontFp!("17451034796031199065423201816962343060679136695245178062038613568095103541289"),
+        MontFp!("10191181251603844246548191313643393903858957062299334476512715147322199150709"),
+        MontFp!("19908549411457324950018053787613807577647937881036226924984564655676161533944"),
+        MontFp!("23982442404642540877935180667772707427047811736260845110630305866858053989496"),
+        MontFp!("8801172789462661502531253780677460369462564389801914778759702073476275150594"),
+        MontFp!("16230680055445324689858676497347378166143176489164118311557124553683754803626"),
+        MontFp!("4458956708241845925140619860655725914597025899331632144147060195988887036062"),
+        MontFp!("25911354559981611311185544396163525806037157446141539756332726938727607909139"),
+        MontFp!("15688640814445675668406782006158042011784913057679718175406840072701724023629"),
+        MontFp!("5452881705934855190232797243332762961545589489158951770952229150466741392111"),
+        MontFp!("18611676134399862836120929488180441731324074532739999401384022875798788127508"),
+        MontFp!("14658665534470044795454349461986351327855595234265527604295359773136197787856"),
+        MontFp!("7050972918456549400257182023023990218120698009222181349079923131041795153641"),
+        MontFp!("7172795021167233442823211289932239404490133032853326748641182769571408368055"),
+        MontFp!("7376889833874867251080499933789864568591237916964822475661083509769325820142"),
+        MontFp!("27067978830028569228367151505880363682278900385679132139708398038466477137470"),
+        MontFp!("19531531654329359565322160626992506718591858318212151338481665284553292124305"),
+        MontFp!("26143622548724177536671049685007406172387582860183909255765069439081445904466"),
+        MontFp!("21437022223148789303111193654597566477656942779136273236400431806920229283595"),
+        MontFp!("7365391933343315287204570625757710336960515889090665465507088784174316895877"),
+        MontFp!("2466053088957493550032402348798419170871486995112866758393094788294048094378"),
+        MontFp!("27528274570290227089559920303584786324682919911000194355132731695474785289334"),
+        MontFp!("3331813154658863217854969165485891112596379650550842838262576349874320713074"),
+        MontFp!("6887054898653374233808614659864991266540439541452150097456460116359738003085"),
+        MontFp!("5243803926111945472649756458881701732682213308698990011399986535059111845550"),
+        MontFp!("20077244038147649382259107660091382610896628859758747291744872440812257209780"),
+        MontFp!("20944345887790128096757681985977020432116913231978211698379415818110924790662"),
+        MontFp!("1860798458821865483721313772835214179354431440498644253493700794369886056233"),
+        MontFp!("2873876666437877322857012619786938538670440241240252072715921833116096257125"),
+        MontFp!("5224894131882212853484177190549677516768087075981096105213216656445530975487"),
+        MontFp!("27331907293017127681918470184979255518094399882511341931647076912247969914328"),
+        MontFp!("7453622700519156697877385054139856051298202221712739381397866789782296555918"),
+        MontFp!("3334904930117825772266310125351243677622263174257590470959444805918398521886"),
+        MontFp!("2176841885399654198422253098936997188454798745167309210228089176116585541611"),
+        MontFp!("27711733558535974800317663518411164448068858940546551927531696077371767178466"),
+        MontFp!("20630384677311590562998447537946710002146998269464359603282723588994815252094"),
+        MontFp!("25705460250674725495369098662787319609560081039802895255199834430175224456227"),
+        MontFp!("14612972949047831106913190272197765955599163706530030339637309111784741256233"),
+        MontFp!("8302600825021596955310587481966042945112936158001343774039421687851536781698"),
+        MontFp!("12172897984385635527576076381812194246306879724884419665483639444851237404907"),
+        MontFp!("15873670076337320394995927677617404269625816345385889979763053706670846181350"),
+        MontFp!("17764519967359566134952998299635669823523452363346837564205788214286399311539"),
+        MontFp!("15938868183689607329466742586478239389177452846494838517697300077278451917527"),
+        MontFp!("16765624505847398417136846384749131030837864758468043768685476528746534765010"),
+        MontFp!("7097905467964468643767065015384075772633698703503948747693140960633934490115"),
+        MontFp!("8265496919137322322012369654608924476643723345875951282593371102982285971855"),
+        MontFp!("6010852739314700332655943336070411396644488348151001212601536670719950922486"),
+        MontFp!("10758595435565645899342798036665680055083495391721544701072834473362568510446"),
+        MontFp!("10577882704641768664964635686365799652657936320355294663919430112830720627675"),
+        MontFp!("21934529859840106748337304976442946125926810200630741472720541252047318079194"),
+        MontFp!("27350027590559421527441079770585406630257839409763222532558546725073076731425"),
+        MontFp!("25345138222071186932231561258161234979715695798996983271097152875615580367668"),
+        MontFp!("22494727130581361944486181178135691695195861976643453370913691161224828015513"),
+        MontFp!("3147069667934976523569860515462936419324781507739051559696312735748888592975"),
+        MontFp!("11656826163864747746207205987178943386431955587750504063700206964999927532453"),
+        MontFp!("17534911959067657313201800263319147058760374218206240099262464387176937340775"),
+        MontFp!("20237809090858488235228403769571928676845308696817359562038128157572736320599"),
+        MontFp!("11235915876244788070364431004763785982358183290372629579302469190359131602999"),
+        MontFp!("23928567216390428685299339188962920349582350939450418873249889702505605934192"),
+        MontFp!("13047991893476579409391759099560092446249490917734834712783730686983357311840"),
+        MontFp!("22588925321018643286498726956003391353478388713319297700996740099041228469985"),
+        MontFp!("20174974775145102055837214081287283554068946149624958625129006599329696419614"),
+        MontFp!("15273699145624189646620586923323973129247877469642166422378454532395610502856"),
+        MontFp!("21980312385968796583140904076618693464273691030784200061480980717702714648067"),
+        MontFp!("25120884424784790917341355719504430427772044368835269628676570293158609543768"),
+        MontFp!("1444163876930407951147963909816029797336098289032981891002467124947672737044"),
+        MontFp!("18616182071301710711890286958796109428864423214737658833916735979012438355972"),
+        MontFp!("12702156420141262093067762206509191611852134647420119487277231871601789099732"),
+        MontFp!("27228016326732584183104063416908261074654276965585166054630253129292845642785"),
+        MontFp!("7853991265100609139503435199169366544117770247763538677186496665213421515129"),
+        MontFp!("6154980172086010346110223709076428585080395265680528720277354247797224314784"),
+        MontFp!("23826931362482482269367339162327653168902086040209302038025098193559870470334"),
+        MontFp!("27248975455705507704681355110566509530696724444795092894735211559171466141710"),
+        MontFp!("7347061316934462057909656021130055748505048650459859597865849426861800110020"),
+        MontFp!("26504676642430606752285744832511163120185842253330490325001721945149312072402"),
+        MontFp!("27496768181979591281478184575306555901062834262599687681898802622938829908042"),
+        MontFp!("4993167649499520035114815761096258035167353463573477402187902783757550546564"),
+        MontFp!("27563576636203293419685183216809782791326761898097628459624656993768711015134"),
+        MontFp!("22013927775728418398159567844410057343233290776136430184131336465007385648586"),
+        MontFp!("1579906466819039505951019675019104098969043280424089032152952325447502399320"),
+        MontFp!("4600119559229215097458575173563573788527562598532634178366657008962677885178"),
+        MontFp!("22855339636908978457269772649039698712780056756145632368935742430680165019741"),
+        MontFp!("4434988962263984301447023948421779624516791800976550039462657811967953523277"),
+        MontFp!("6876351280846796601416895758552549944221764066352175059065069450835027078622"),
+        MontFp!("27042428409161826598104537422046264587914459327661363984584724614298274487388"),
+        MontFp!("7902815047518256893936242744519134796289449540812996338264835879315755468716"),
+        MontFp!("5018851632824928065929441847966499307556429747973294892388712252829517378441"),
+        MontFp!("6850386711198212855403858129896361036260050596816612093986111265608113968276"),
+        MontFp!("21497136470345139474091938047035317034833232626112427180215765646069744796429"),
+        MontFp!("5075449137349371906478840715700170303958465386705663855947385189850428661161"),
+        MontFp!("4594516530195662288120573604805490015776321362520933226592407275084139971368"),
+        MontFp!("23292558232240489154163826717589185425071175954349374510204809238929076844568"),
+        MontFp!("10502727852940627620775448849368725712112818039312827827161215453300275521878"),
+        MontFp!("20602581180847700040755778975698113914571044491776604873588313061620740822894"),
+        MontFp!("2118870912694020672019586224113550304700865630370917528115741518420191461340"),
+        MontFp!("6053182732656285565822218221332615542452996987233903263151699619576096222097"),
+        MontFp!("18874193836627331973063161638883535016105493711672976835198350140944744688484"),
+        MontFp!("27825060710430124156554459568225474660632607476313299543291151333092718568064"),
+        MontFp!("3602136449141427490120492703751945260462516958059846883858637857296246710406"),
+        MontFp!("9909738349808456939523875262871682983348859242093756546130681525410048906438"),
+        MontFp!("5644012392682787285948962408437658776928559701673164604712078314708892398242"),
+        MontFp!("10450326091563440305836422955555074068690516483069775335149343744613311403032"),
+        MontFp!("12308070483181191736269102332652913618687048650760273736119257942353914603015"),
+        MontFp!("2997931642360318372784829533620342459008557298967638149427939997103322421151"),
+        MontFp!("6050971264997329703525350035109624750155294607885030355629085221455877393740"),
+    ],
+];
+
+static PALLAS_M: &[&[&[Fq]]] = &[
+    &[],
+    &[
+        &[
+            MontFp!("5306861834269217585117752024864762608007490164951938254926213181083020520737"),
+            MontFp!("7899320246926688069468352065455427407263677055902966959452944115842433674576"),
+            MontFp!("28141003429769155864170153667573222274056492486517913611382388621259654535098"),
+        ],
+        &[
+            MontFp!("2972771392367448633703092735427097986592864480665937864973239373568304503053"),
+            MontFp!("2249874333267389825909527502238284618547947686156383571610434220349770970075"),
+            MontFp!("2591028189305692370136472869911249939947512330318782453635060302108449682556"),
+        ],
+        &[
+            MontFp!("22561377475690468386436360689345452345054900704250707533666543557350498119310"),
+            MontFp!("845443288388751026406425520653347029690071626562889463238274713084588396483"),
+            MontFp!("19445867470536849662624871500969232350352362574852254703023649150718871493734"),
+        ],
+    ],
+    &[
+        &[
+            MontFp!("24123883072822259174101645188231440198797566707631290952876701956177530670227"),
+            MontFp!("2326775407381110166949357423720710665603237302635291126854250219952408629031"),
+            MontFp!("4602455183211439587029871108672380387681467741958677657246926127836253551818"),
+            MontFp!("25310346757664642461156691485408482707897947597583234821348705382279936612387"),
+        ],
+        &[
+            MontFp!("3075572191578560807644405047732438963230039283043056409905699404121509261859"),
+            MontFp!("986953230514215724668710989716139426758651683329998052586190736254243855607"),
+            MontFp!("26031916732735555995009164832170248860194951330512778263046496426038137240742"),
+            MontFp!("1382297095938703251491750062240590006135268379411432373891387989695090041796"),
+        ],
+        &[
+            MontFp!("25192307601050122750799511590466886712636955687438046834984016101561993787090"),
+            MontFp!("4120371607002073119572760049908484402706067265990555487547559846890440559264"),
+            MontFp!("19050388443898847261041150900760886244986805288574609622273068435133137181533"),
+            MontFp!("26314076859007586251027319376517434066779809209797844353847608117326483262134"),
+        ],
+        &[
+            MontFp!("20300304959609183463668080072561530093344724792226527873365467008829430738390"),
+            MontFp!("16625808782740905545392901433846862283303937918084255583354933572240374007236"),
+            MontFp!("22876515469655292080189876587166759013879525601144345393134978276026634387347"),
+            MontFp!("2394836559891867419205822474193797961419839137206388500464846992444334878184"),
+        ],
+    ],
+];
+
+static VESTA_C: &[&[Fr]] = &[
+    &[],
+    &[
+        MontFp!("15069682083288160432732370099798351618679311578385352494909426280271494064689"),
+        MontFp!("19552372332799130802276417626232312252003112635717995616214163996953876465864"),
+        MontFp!("19043902806963638374160190911613821337150830066549453686591397590151261639780"),
+        MontFp!("15514927955085138849903142742338037532910619924819969724732960117103183528083"),
+        MontFp!("15495014697804903298255235331386641424574726009953953908941032386906465281856"),
+        MontFp!("22783616522732622893019082906592797185801504915639760629184772959223142258739"),
+        MontFp!("13013282258164837279493662912995836732931467481763657157388243170268288494777"),
+        MontFp!("6981250340229026941600359079713644943364499010076989145230271905447893411428"),
+        MontFp!("24035237617505623260266869665310459241730996321154035943461846954723024413195"),
+        MontFp!("19490150944318489161354799945354283869958593100591069159064256502786081113445"),
+        MontFp!("10001310054178566741581108732318364481096445685845154795650911304361521661288"),
+        MontFp!("13265290993276339200466518757285986387873219067370510717658484079003987909088"),
+        MontFp!("5168875594760045207805167119482723892422371357036786272812920185355882590357"),
+        MontFp!("16404781914610122477169558382353268685340329314879749933213689777663574642030"),
+        MontFp!("13926627674696294849949462322975705763490000301788840352575629025889041709871"),
+        MontFp!("5540005465573610713369283553280413619347229650432107922453007392732395383302"),
+        MontFp!("25331413312157336203788915641742781025904079251646304923273846484713954689805"),
+        MontFp!("22209006774619169629238704899737836689832339477498820549083655530919085937469"),
+        MontFp!("5311185660468923011620882201281555732086369217347178075669744505704316314145"),
+        MontFp!("15478623232978858035827156578649305899475322662360676831830504986059993752553"),
+        MontFp!("12519402894226670702047228301088292812324011377069509125270413768864485388152"),
+        MontFp!("25265407608799125973911895075576151376017821913856802599988018285045773189436"),
+        MontFp!("27425326055714195207210257024227984260401076936541461193784131216457004989193"),
+        MontFp!("3768678765872159570334616338154024307389259318183719031698087507626949916634"),
+        MontFp!("25086755002143452066622281417655366233567137314445218198002604928363131851181"),
+        MontFp!("23779491437658546967906847642122014312188103887363792308946119528980860787181"),
+        MontFp!("24955891074778304314273694435921532988165668138889091495806365274009288972837"),
+        MontFp!("27608023756696462562086135196379277578164230293508795841538784900757910244975"),
+        MontFp!("1080226634920093792565119304474407980618655593930293185675690557789787269714"),
+        MontFp!("11469284678695953893354610068791896411465714706458842211112702612541728086569"),
+        MontFp!("8084618519052242994285315279354211170874167518709449374556345882449384664914"),
+        MontFp!("3197111092620571258325644615893031262113157326998479923840014013137131891865"),
+        MontFp!("8936353836030705427988862862233037869944090544819240137805149557956090660037"),
+        MontFp!("3159228639587844516793026797109602325520417644888043082840400709519943335087"),
+        MontFp!("8223951140921425480780788933706256452584667061314395494999262876180139867481"),
+        MontFp!("26278918500147759775494040128510496726909950674693464120779447256751644164722"),
+        MontFp!("26779879159404780097315149077287766113601664881164369916028591314567870355151"),
+        MontFp!("20456223343957287727514213669396629715995897855649159394525218606933035704192"),
+        MontFp!("12747039712720051884421283218579068027169362545566746338894823615977089768645"),
+        MontFp!("27898851815710187079705561875563256790423231382156200112332228390723271299275"),
+        MontFp!("1082651051840204340034509102399834433911785522401318955077701718631699684491"),
+        MontFp!("7079478478825447313725566949453824123009129220327890795130819256071009925257"),
+        MontFp!("16775457493308856116209955838772682657406283117383038425813679898244265869081"),
+        MontFp!("7470547477163269972171214292244143106200345859222444719977197893052417329024"),
+        MontFp!("1548460794806212448462544801932001590091559214841471349303252342401635420988"),
+        MontFp!("9710379243331339391425756096099609297363072174144732918502957312733827361665"),
+        MontFp!("25557916014812134616741794835185340865176681029029911448118486027379598419824"),
+        MontFp!("27203905164251724386864486493184261124876028946032441782291642547535072250765"),
+        MontFp!("24625437726134665849087299926909516861882286967636758123373386905204291527519"),
+        MontFp!("18399131756860263245632355856085898109846105597980167545439769047691399369221"),
+        MontFp!("2006984980145785374239883910267497430622626915726794516080549269061371741375"),
+        MontFp!("12396928969388423044049752681574951136377530623963875360936480702464073837248"),
+        MontFp!("12114905967386652311865918547204654236955577491397322081448823934460193386797"),
+        MontFp!("10401998325120181088916772218934245595382205296446102357054486991342799209542"),
+        MontFp!("26911117626519727234397280470579483704522021687326570539888597777543155036833"),
+        MontFp!("16178563677338850636323817606133246816968202242684869407274198108437675500963"),
+        MontFp!("24609327750431672087261202601111011395169770206984072233495868616129877212531"),
+        MontFp!("19482735308953249432669137408021717720428231082355491611546353416655589592340"),
+        MontFp!("4764894878114589733354385240482172124912883938531667965986136194711928132233"),
+        MontFp!("13114707554944862672438565577661976538565216138843156583255044274084192242063"),
+        MontFp!("26514654721325469812795406739655803678839887227616850563164721996934129593885"),
+        MontFp!("27126849378630612860366678348207722981358217324654958134542524347309943374582"),
+        MontFp!("236991681791069900684736931834346209872667167685372604088058265168904639678"),
+        MontFp!("14751080878640780948793941540266346729539404711251035167406410355870311851517"),
+        MontFp!("17063576703247539896769726695823097250024396057514079699081766963840922451736"),
+        MontFp!("2678670726345510540233267914626485703001052639233984097013988075445853551597"),
+        MontFp!("28227478247801079192371492183561575762821144884795494773821038295704687443211"),
+        MontFp!("5210533892428165644429246427363395072036163009697389936492578843733239349257"),
+        MontFp!("18565328527355902314638803835821683223077355049529845924128501780833336857204"),
+        MontFp!("2141802313494140370073018441574861992652757526683544636831277365623658291589"),
+        MontFp!("15903307826353145606870059007907818011919330577771722791409049863634014171629"),
+        MontFp!("21789964138026487246749132534358503414358720591077571155045035726152900260283"),
+        MontFp!("7442079075042857503374740032054228297945408872846551468629659912935727218776"),
+        MontFp!("11857459086272814010999262004984281116267744158822076580327736381537645075869"),
+        MontFp!("2091190871418370281590070735405568973418605739655753629014523500610411588600"),
+        MontFp!("26937997670332816050283264807299604599440310862750972678751522541624260828071"),
+        MontFp!("7972639145875100235259305739238229435664723046014927544952331947835699686334"),
+        MontFp!("22990354086345679157773926123388123897037509529865384672393345100211020789940"),
+        MontFp!("27835418420514150922324621870473968951447297351380663051383641541477283087718"),
+        MontFp!("26037037662526543183165785736562446815576768327202815158151661638541917290038"),
+        MontFp!("22733808701222687584037244726901966408364756561054715824812307029085152197642"),
+        MontFp!("6909665327947373477876503326690117023529844275178113772693805280086804275789"),
+        MontFp!("7142936019716449044931405839983402075733572660094931801224445203683818523487"),
+        MontFp!("6195670886424446063977106583870779707000929864809996500832965273157219182343"),
+        MontFp!("23626751267233364310995403567470582688694752693951259636362991013411409154045"),
+        MontFp!("24117560745911367534934047343436604087458307327200065653417350567748509644008"),
+        MontFp!("11256113935068829705348336396109049816650575048305516437972021145493818303054"),
+        MontFp!("12038411585482408245866588535507440716321383306582213959649631352380505296649"),
+        MontFp!("6526502100039000285301377000454544755879307550325897610117285417992653989883"),
+        MontFp!("8205951858827962858297044937178522337758499050416522405544152346456182876452"),
+        MontFp!("258398675175833276592949264479784361720882569973571099775733400234847598810"),
+        MontFp!("22223153731430445043444016839574137508856134848907757325890234838652117711255"),
+        MontFp!("22951305417350044093692687017278203980139082646462566498062849603914254938713"),
+        MontFp!("26227258298154231476832786489383884166035950963373421526125560917884324210124"),
+        MontFp!("25291782232716612141048957027934887898040440195011622096126796765924895737287"),
+        MontFp!("8505440081055658194053172657484459326491099490258463076621342719248831630197"),
+        MontFp!("11365018193794909309545286941196906855169274477738507283227421787403768748969"),
+        MontFp!("25736678787176620090645934284034870430980578056043435302357339717372990968826"),
+        MontFp!("2093316466514052019706232110655619447777479424303545028727744865960868558731"),
+        MontFp!("2680077661465513628082040274885478576977430197291388638064080263520494949856"),
+        MontFp!("5729302472764015845222707014257473080859933080961697625938995555882573633064"),
+        MontFp!("22272502822659301784370016135642595749648995815203953291431983719105487529766"),
+        MontFp!("19741397985720228172534098658729160979413981846806940466958557796590189770662"),
+        MontFp!("4778855865285211663172499533545045925722873599431753726389782546252541496490"),
+        MontFp!("4169459141156341175215230696374765606570647592859646446835515013786945456302"),
+        MontFp!("28521126816855872476883625640419544513729454445948267490846928448029046818145"),
+        MontFp!("9937275659915884209465318727755953580740132368738485847765689432997053848728"),
+        MontFp!("28937686875138289956188244889191850574004773598279618129296474335044152339184"),
+        MontFp!("11936378053002679728544971557108961055262394145293696880702109600002543710922"),
+        MontFp!("9304690391983614872047450188868484086392196244117934306347957287162354277881"),
+        MontFp!("14912889187608850624998509012566219001003137346393918987174119070334478148726"),
+        MontFp!("10902700458872657355784751515874982539763794767384646478005720625487850984103"),
+        MontFp!("12855300847783316358900144508437611561727809518091406297908608950098225219206"),
+        MontFp!("12808905174791867200279004146841646719638664553785558199159750485287629394709"),
+        MontFp!("12721859968629888269359610922006787499205086924392139184099457087455588878536"),
+        MontFp!("12149718278790640625999519947850358944529357815447148500537556634924379118783"),
+        MontFp!("10467370517928203582042229398077284639523752511039488171348394941357680108530"),
+        MontFp!("24655006251422685937728251841804448521960992769716312885334528834067641655543"),
+        MontFp!("18239622862789216008934445523262406025863074314710097942681312866619449581817"),
+        MontFp!("11874377445400854013054723999779496567271258825608398515243321307826899069961"),
+        MontFp!("4864318393192213171240543126558044134145285759574559698047342429668232332724"),
+        MontFp!("25581916704016366754416610582415654066579771261186983025724796917641936007677"),
+        MontFp!("27592097404650504147675913359698603491988170657921171728295211603320943182513"),
+        MontFp!("8132270650536091121856741673243842423515057415821694090391564651087862024923"),
+        MontFp!("24077613630605687980575068864990684432687934839285097579300393328780506189618"),
+        MontFp!("2504917251000859290029597265410428927672638767278326159148609301207336050135"),
+        MontFp!("3507717361151347186372804119943947217698185013949406748460940978493797077547"),
+        MontFp!("10238478552406470014862782012869182317520630503049294244340743528542317257229"),
+        MontFp!("23452937932971293828724115398936602431558898471997852441729752824149860069940"),
+        MontFp!("5507174975377567449908815948780307011973002050137956136417253437184643281618"),
+        MontFp!("26152696613558111513010988524265655065462908309470930344484687481220893285969"),
+        MontFp!("4927644999310074369011503915883277897515565700973005455630539895756185854571"),
+        MontFp!("26786495534173573884793314662481527367728245780784202879468757321445313190277"),
+        MontFp!("25352112591295528442598094170098599906211820405487804600825992787445328151648"),
+        MontFp!("9541876906713498797378423820637716464941788950322477528498096731033634986169"),
+        MontFp!("13347247001936238982830304745497426747310001544654619311462581265111950013319"),
+        MontFp!("6821368916967661230938349886852948172263852043978261612161874120112047492849"),
+        MontFp!("15283865223775277938893948800494028039218001261337770498371224516905124257013"),
+        MontFp!("27749485148124544306301161468235879614841484812651078331844135495400074266281"),
+        MontFp!("6196647483610958539761559037463425065291601127652682919796373268738102084988"),
+        MontFp!("20149961612753930727582312440976742151191204420142426724772662781898743424645"),
+        MontFp!("23703492919611159223856415838004223407240264841129924398573810900152977325413"),
+        MontFp!("18954977684484282900482692634437051906789629587468888897072760491258232030021"),
+        MontFp!("23637404216495656449676884613666125716776863769919834862820342893488100975261"),
+        MontFp!("25052291902893170786389609555028232023408993081815971996587255273919239111426"),
+        MontFp!("21918447195920351647336742499546629335791460923502477281272651087966321757476"),
+        MontFp!("23626417547856604455624403914897560192102128625447916336781401018958471576074"),
+        MontFp!("27688182248297785076935773219820877738426440535915819801595379051487697785792"),
+        MontFp!("13220470919427537642552050388100040000195457107638915815290677008184618075209"),
+        MontFp!("19194608302443102551527527966531494788470411803783058547783177356037418408446"),
+        MontFp!("1404147693257553669177951406702477863728339350368759020247125551167062218761"),
+        MontFp!("15448823693591390106136801553962240766990078673835077742117238854009358602062"),
+        MontFp!("6582375792694694167058675493137279606284154603511129883336409801108208869832"),
+        MontFp!("225737682581293581179842866433566979380295502854318984377369282011116631702"),
+        MontFp!("22039073002911562685813640381878901717543527717766638236646574477397401246385"),
+        MontFp!("16545685945783357125471811213792742719607911728884208493674617459269547776316"),
+        MontFp!("27910479511228880009360061842845238183732896084397213226001044088660669156341"),
+        MontFp!("10749831239902890639364394373388829090293328534687970567270952443097458479621"),
+        MontFp!("25801382612260750807176567306185100806245800957539080470982401613733847017389"),
+        MontFp!("21478681944505151232769112854403239444771196992862352028539522578371697770792"),
+        MontFp!("28787075409272728909463776127520390674639904299633896875197734352633823855783"),
+        MontFp!("835370699545620877456284280172935272028455352393732631056733870831421878570"),
+        MontFp!("8251935792645352066003472758657542796124411323268491925524587614243033732523"),
+        MontFp!("27902415527132863705974774952879292466590802685762862893583738200340757381081"),
+        MontFp!("24359340513663374411242640414346337131063700645761497929998219379501103527667"),
+        MontFp!("9093882205083685941153498175525403996893843797372167462155475056114450164823"),
+        MontFp!("6916891904887948883201733816823900696451124681457876821033260483567204857277"),
+        MontFp!("14667147518061791821528796158893812955530302194419423260855853227654634260193"),
+        MontFp!("15299327721255367393777577852898735767506934211174133458732221146042818360078"),
+        MontFp!("20176333718435620296741725024615884136310539579367621850139940464074226251693"),
+        MontFp!("13932915224939920185237974062060506324293379999528941707682509433919767847316"),
+        MontFp!("15010155868499727052646797554996331419512441279722532416217954787125710432775"),
+        MontFp!("25156829243294730301898460906907876197255678416855099501924966061248446928057"),
+        MontFp!("21353200017979506555483391209250072460166352446895897441334419101883859559040"),
+        MontFp!("15487161047607689456399148988841445820330113670225845384538495598896207396548"),
+        MontFp!("7984092475671550229551455331070011525649193769331573127915741006891633639583"),
+        MontFp!("22546525275055847966964854979935064065262455006200549389266006423238691069289"),
+        MontFp!("18086819150071553330554959042846355753357300736888859214673445003567430523918"),
+        MontFp!("18191574381277135401347782576091474687430418599697352734710106721733327791488"),
+        MontFp!("15905437465297970563705232698711551947467023501848437208196761987207924924541"),
+        MontFp!("25848374577539774581196270494705610428524458984312008540126985541645676597417"),
+        MontFp!("6210360595811783286317260121592387304358086206140844147404703857444283605071"),
+        MontFp!("28682804891159185636152195042784732954944496907875645016660815243199115985699"),
+        MontFp!("7599049506625668775607926402522378412503847853486268630131782363812100337646"),
+        MontFp!("25227241923136823255122683229700043762557658620405700228415820897624655202103"),
+        MontFp!("3051672138930866023848792153189168019303710196606665152314209620496136005161"),
+        MontFp!("17407562530836467959545327669620432605056064355763822195664090193918567319970"),
+        MontFp!("23962473404443897694420624988790637870540052939255465237625200853662527566107"),
+        MontFp!("19817941717900800753911852047879195294507586827877873753324298963864958483695"),
+        MontFp!("9255063042638646434672244663926162255611038780081491745199426073041017278387"),
+        MontFp!("18302449404011056177161371180903042680360561412201103431093343019859989502723"),
+        MontFp!("11055283330303806791981104016138402404578863912800889397884804770621189509412"),
+    ],
+];
+
+static VESTA_M: &[&[&[Fr]]] = &[
+    &[],
+    &[
+        &[
+            MontFp!("21723747085710545014676292613446536198084199903144474881929408292094730750416"),
+            MontFp!("24058880310080872211036050339866153849483841987896729143140537335408401541077"),
+            MontFp!("11814040080588499199864894182861028087789884583636442957093047496621415237816"),
+        ],
+        &[
+            MontFp!("10669183152692740181676426795018780188409192936293068662820794543585776108665"),
+            MontFp!("11414768715624389313377608598436376814524425290553060179503860347865897922729"),
+            MontFp!("18555595818285445891538839601206559825719511072467040903943003285345025713988"),
+        ],
+        &[
+            MontFp!("19552838523680278772353430139784169650887094872102806652840796825687909391136"),
+            MontFp!("22422680335662078934852487448810381717006425790124480936736162481621068551567"),
+            MontFp!("5968071119364613504999866753287514557272754516781567741873124349241491666092"),
+        ],
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_permutation;
+
+    // Poseidon reference implementation (zkhash `POSEIDON_PALLAS_3_PARAMS`)
+    #[test]
+    fn pallas_kats() {
+        assert_permutation(
+            &Poseidon::new_pallas(),
+            &[
+                "0x08fd69dd1602112194d1fefd8c2b20242e371879feba6683a4bdeebd6e8f121c",
+                "0x2a17023cc2483bf305661df2580c3b29444f8b954de7f2166091592ba7728591",
+                "0x1495649c6632dd6202315e468aa08b1392b750dfe0d2b3bbc902e230355e9615",
+            ],
+        );
+    }
+
+    // Poseidon reference implementation (zkhash `POSEIDON_VESTA_PARAMS`)
+    #[test]
+    fn vesta_kats() {
+        assert_permutation(
+            &Poseidon::new_vesta(),
+            &[
+                "0x32e8b71fc2963b1c2371a5a9e191671079b3e059d9683027b146bd5d34cea133",
+                "0x005e6cd1461b0470c03f045e8fba078846bbdbb0992c37fc6f4764ebdb92a1d6",
+                "0x162f4406f334d8600c569b3172e75abf00f6c201871d4fff9834cedd0c8aa5d3",
+            ],
+        );
+    }
+}
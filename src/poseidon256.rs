@@ -0,0 +1,102 @@
+//! RustCrypto `digest` traits for Poseidon over BN254.
+
+use ::digest::consts::U32;
+use ::digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+use ark_bn254::Fr;
+
+use crate::sponge::PoseidonSponge;
+use crate::{field_to_bytes, Poseidon};
+
+/// Capacity tag of `Poseidon256` sponges
+const DIGEST_DOMAIN: u64 = 0x646967;
+
+/// Byte-oriented Poseidon hash implementing `digest::Digest`.
+///
+/// Input is split into 32-byte blocks, each absorbed into a rate-2, capacity-1 sponge as
+/// two little-endian 128-bit limbs, so every block costs one permutation and any byte
+/// string maps to field elements injectively. The last block is zero-padded and followed
+/// by the message length in bytes. The output is the squeezed element as 32
+/// little-endian bytes.
+#[derive(Clone)]
+pub struct Poseidon256 {
+    sponge: PoseidonSponge,
+    block: [u8; 32],
+    pos: usize,
+    len: u64,
+}
+
+impl Default for Poseidon256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Poseidon256 {
+    pub fn new() -> Poseidon256 {
+        Poseidon256 {
+            sponge: PoseidonSponge::with_domain(Poseidon::new(), 2, 1, Fr::from(DIGEST_DOMAIN))
+                .expect("BN254 supports width 3"),
+            block: [0; 32],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    fn absorb_block(&mut self) {
+        let (lo, hi) = self.block.split_at(16);
+        self.sponge
+            .absorb_one(Fr::from(u128::from_le_bytes(lo.try_into().unwrap())));
+        self.sponge
+            .absorb_one(Fr::from(u128::from_le_bytes(hi.try_into().unwrap())));
+        self.block = [0; 32];
+        self.pos = 0;
+    }
+
+    fn finish(&mut self) -> [u8; 32] {
+        if self.pos > 0 {
+            self.absorb_block();
+        }
+        self.sponge.absorb_one(Fr::from(self.len));
+        field_to_bytes(&self.sponge.squeeze())
+    }
+}
+
+impl HashMarker for Poseidon256 {}
+
+impl OutputSizeUser for Poseidon256 {
+    type OutputSize = U32;
+}
+
+impl Update for Poseidon256 {
+    fn update(&mut self, mut data: &[u8]) {
+        self.len += data.len() as u64;
+        while !data.is_empty() {
+            let n = data.len().min(32 - self.pos);
+            self.block[self.pos..self.pos + n].copy_from_slice(&data[..n]);
+            self.pos += n;
+            data = &data[n..];
+            if self.pos == 32 {
+                self.absorb_block();
+            }
+        }
+    }
+}
+
+impl FixedOutput for Poseidon256 {
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.finish());
+    }
+}
+
+impl Reset for Poseidon256 {
+    fn reset(&mut self) {
+        *self = Poseidon256::new();
+    }
+}
+
+impl FixedOutputReset for Poseidon256 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.finish());
+        self.reset();
+    }
+}
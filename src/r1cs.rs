@@ -0,0 +1,106 @@
+//! R1CS gadget for the Poseidon hash over `FpVar`.
+//!
+//! The gadget reads the same `PoseidonConstants` tables as the native hasher, so a circuit
+//! built with `PoseidonGadget::new` computes exactly `Poseidon::new().hash`. In R1CS only
+//! the s-boxes cost constraints, so the gadget runs the reference round schedule: one
+//! x^5 (3 constraints) per state element in full rounds and one per partial round. The
+//! inverse s-box (alpha = -1) takes 3 constraints as well.
+
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use ark_r1cs_std::alloc::AllocVar;
+use ark_r1cs_std::fields::{fp::FpVar, FieldVar};
+use ark_r1cs_std::R1CSVar;
+use ark_relations::r1cs::SynthesisError;
+use ark_std::vec::Vec;
+
+use crate::{PoseidonConstants, Width, BN254_CONSTANTS};
+
+/// In-circuit Poseidon hash over a static constant set
+#[derive(Clone, Copy)]
+pub struct PoseidonGadget<F: PrimeField = Fr> {
+    constants: &'static PoseidonConstants<F>,
+}
+
+impl Default for PoseidonGadget {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PoseidonGadget {
+    /// Gadget over the circomlib BN254 constants
+    pub fn new() -> PoseidonGadget {
+        PoseidonGadget {
+            constants: &BN254_CONSTANTS,
+        }
+    }
+}
+
+impl<F: PrimeField> PoseidonGadget<F> {
+    pub fn with_constants(constants: &'static PoseidonConstants<F>) -> PoseidonGadget<F> {
+        PoseidonGadget { constants }
+    }
+
+    /// Hash `inputs` like `Poseidon::hash`: permute `[0, inputs...]` and return the first
+    /// element.
+    ///
+    /// Panics if the constant set does not support `inputs.len()` inputs, since a circuit
+    /// has a fixed shape.
+    pub fn hash(&self, inputs: &[FpVar<F>]) -> Result<FpVar<F>, SynthesisError> {
+        let mut state = Vec::with_capacity(inputs.len() + 1);
+        state.push(FpVar::zero());
+        state.extend_from_slice(inputs);
+        self.permute(&mut state)?;
+        Ok(state.swap_remove(0))
+    }
+
+    /// Apply the permutation to a state of width `state.len()`; panics if the width is
+    /// not supported
+    pub fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        let t = state.len();
+        let k = Width::new(t)
+            .and_then(|w| self.constants.width(w))
+            .unwrap_or_else(|| panic!("Poseidon width {} is not supported", t));
+        let (c, m) = (k.c, k.m);
+        let (n_rounds_f, n_rounds_p) = (k.n_rounds_f, k.n_rounds_p);
+        for i in 0..n_rounds_f + n_rounds_p {
+            for (s, c) in state.iter_mut().zip(&c[i * t..]) {
+                *s += *c;
+            }
+            if i < n_rounds_f / 2 || i >= n_rounds_f / 2 + n_rounds_p {
+                for s in state.iter_mut() {
+                    *s = sbox(s, k.alpha)?;
+                }
+            } else {
+                state[0] = sbox(&state[0], k.alpha)?;
+            }
+            let mixed: Vec<FpVar<F>> = m
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(FpVar::zero(), |acc, (mij, s)| acc + s * *mij)
+                })
+                .collect();
+            state.clone_from_slice(&mixed);
+        }
+        Ok(())
+    }
+}
+
+/// x^alpha, or for alpha = -1 the inverse with zero mapping to zero: `y` is the only
+/// witness with `x y x = x` and `y x y = y`
+fn sbox<F: PrimeField>(x: &FpVar<F>, alpha: i64) -> Result<FpVar<F>, SynthesisError> {
+    if alpha != -1 {
+        return x.pow_by_constant([alpha as u64]);
+    }
+    if let FpVar::Constant(c) = x {
+        return Ok(FpVar::Constant(c.inverse().unwrap_or(F::zero())));
+    }
+    let y = FpVar::new_witness(x.cs(), || Ok(x.value()?.inverse().unwrap_or(F::zero())))?;
+    let xy = x * &y;
+    xy.mul_equals(x, x)?;
+    xy.mul_equals(&y, &y)?;
+    Ok(y)
+}
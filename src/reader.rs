@@ -0,0 +1,100 @@
+//! Hashing of byte streams too large to hold in memory.
+//!
+//! The stream is cut into fixed-size chunks, each read as an integer below the modulus,
+//! and the chunks are absorbed into a `PoseidonHasher` as they are read, followed by the
+//! stream length in bytes so that zero padding of the last chunk cannot collide with
+//! real zero bytes. Only one buffer of a few thousand chunks is kept.
+
+use alloc::vec;
+#[cfg(feature = "width-3")]
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use std::io::{self, Read};
+
+use crate::{PoseidonError, PoseidonHasher};
+
+/// Chunks read from the stream at a time
+const CHUNKS_PER_READ: usize = 2048;
+
+/// How `hash_reader` splits a stream into field elements: chunks of the given number of
+/// bytes, from 1 up to the whole bytes below the modulus (31 for BN254), read as little-
+/// or big-endian integers, the last one padded with zeros at its end
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chunking {
+    LittleEndian(usize),
+    BigEndian(usize),
+}
+
+/// 31-byte little-endian chunks
+impl Default for Chunking {
+    fn default() -> Self {
+        Chunking::LittleEndian(31)
+    }
+}
+
+impl Chunking {
+    fn size(&self) -> usize {
+        match self {
+            Chunking::LittleEndian(size) | Chunking::BigEndian(size) => *size,
+        }
+    }
+
+    fn decode<F: PrimeField>(&self, chunk: &[u8]) -> F {
+        match self {
+            Chunking::LittleEndian(_) => F::from_le_bytes_mod_order(chunk),
+            Chunking::BigEndian(_) => F::from_be_bytes_mod_order(chunk),
+        }
+    }
+}
+
+/// Hash everything `reader` yields with `PoseidonHasher::new()` and the default
+/// `Chunking`
+#[cfg(feature = "width-3")]
+pub fn hash_reader<R: Read + ?Sized>(reader: &mut R) -> io::Result<Fr> {
+    hash_reader_with(PoseidonHasher::new(), reader, Chunking::default())
+}
+
+/// Absorb the chunks of `reader` and its length into `hasher` and finalize it, which
+/// equals hashing `chunks ++ [length]` with the hasher's `update`. Fails with the
+/// reader's errors, retrying `Interrupted` reads, or with `InvalidInput` if the chunk
+/// size does not fit the field.
+pub fn hash_reader_with<F: PrimeField, R: Read + ?Sized>(
+    mut hasher: PoseidonHasher<F>,
+    reader: &mut R,
+    chunking: Chunking,
+) -> io::Result<F> {
+    let size = chunking.size();
+    let max = (F::MODULUS_BIT_SIZE as usize - 1) / 8;
+    if size == 0 || size > max {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            PoseidonError::InvalidInputLength { got: size, max },
+        ));
+    }
+
+    let mut buf = vec![0u8; size * CHUNKS_PER_READ];
+    let mut filled = 0;
+    let mut len = 0u64;
+    loop {
+        let n = match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        len += n as u64;
+        filled += n;
+        let whole = filled - filled % size;
+        for chunk in buf[..whole].chunks_exact(size) {
+            hasher.update(&[chunking.decode(chunk)]);
+        }
+        buf.copy_within(whole..filled, 0);
+        filled -= whole;
+    }
+    if filled > 0 {
+        buf[filled..size].fill(0);
+        hasher.update(&[chunking.decode(&buf[..size])]);
+    }
+    hasher.update(&[F::from(len)]);
+    Ok(hasher.finalize())
+}
@@ -0,0 +1,111 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::sponge::PoseidonSponge;
+use crate::{field_to_bytes, Poseidon, PoseidonError};
+
+/// Capacity tag of RNG sponges
+const RNG_DOMAIN: u64 = 0x726e67;
+
+/// Deterministic RNG squeezing a seeded Poseidon sponge.
+///
+/// The seed is absorbed (after its length) into a rate-2, capacity-1 sponge tagged with
+/// an RNG domain, and every output comes from further squeezes. `next_field` returns the
+/// squeezed elements themselves, matching an in-circuit sponge with the same seeding;
+/// byte outputs only use the low `(MODULUS_BIT_SIZE - 128) / 8` bytes of each element,
+/// which keeps their bias below 2^-128. With the `rand` feature this implements
+/// `rand_core::RngCore` and `CryptoRng`.
+pub struct PoseidonRng<F: 'static = Fr> {
+    sponge: PoseidonSponge<F>,
+    buf: [u8; 32],
+    pos: usize,
+}
+
+impl PoseidonRng {
+    #[cfg(feature = "width-3")]
+    pub fn new(seed: &[Fr]) -> PoseidonRng {
+        PoseidonRng::with_poseidon(Poseidon::new(), seed).expect("BN254 supports width 3")
+    }
+}
+
+impl<F: PrimeField> PoseidonRng<F> {
+    /// RNG over the permutation of the given instance, which has to support width 3 over
+    /// a field of at least 192 bits
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        seed: &[F],
+    ) -> Result<PoseidonRng<F>, PoseidonError> {
+        if F::MODULUS_BIT_SIZE < 192 {
+            return Err(PoseidonError::UnsupportedField);
+        }
+        let mut sponge = PoseidonSponge::with_domain(poseidon, 2, 1, F::from(RNG_DOMAIN))?;
+        sponge.absorb_one(F::from(seed.len() as u64));
+        for x in seed {
+            sponge.absorb_one(*x);
+        }
+        Ok(PoseidonRng {
+            sponge,
+            buf: [0u8; 32],
+            pos: Self::bytes_per_element(),
+        })
+    }
+
+    /// Next squeezed field element
+    pub fn next_field(&mut self) -> F {
+        self.sponge.squeeze()
+    }
+
+    /// Fill `dest` with pseudorandom bytes
+    pub fn fill(&mut self, dest: &mut [u8]) {
+        let n = Self::bytes_per_element();
+        for b in dest {
+            if self.pos == n {
+                self.buf = field_to_bytes(&self.sponge.squeeze());
+                self.pos = 0;
+            }
+            *b = self.buf[self.pos];
+            self.pos += 1;
+        }
+    }
+
+    fn bytes_per_element() -> usize {
+        ((F::MODULUS_BIT_SIZE - 128) / 8) as usize
+    }
+}
+
+/// Wipes the sponge state and the buffered output bytes
+#[cfg(feature = "zeroize")]
+impl<F: PrimeField> zeroize::Zeroize for PoseidonRng<F> {
+    fn zeroize(&mut self) {
+        self.sponge.zeroize();
+        self.buf.zeroize();
+        self.pos = Self::bytes_per_element();
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<F: PrimeField> rand_core::RngCore for PoseidonRng<F> {
+    fn next_u32(&mut self) -> u32 {
+        let mut b = [0u8; 4];
+        self.fill(&mut b);
+        u32::from_le_bytes(b)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut b = [0u8; 8];
+        self.fill(&mut b);
+        u64::from_le_bytes(b)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<F: PrimeField> rand_core::CryptoRng for PoseidonRng<F> {}
@@ -0,0 +1,137 @@
+//! serde helpers encoding field elements as their canonical 32-byte little-endian form.
+//!
+//! Used through `#[serde(with = ...)]` on the public value types; decoding rejects
+//! non-canonical encodings like `bytes_to_field_strict`.
+
+use alloc::vec::Vec;
+use ark_ff::PrimeField;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{bytes_to_field_strict, field_to_bytes, PoseidonParams};
+
+fn decode<F: PrimeField, E: serde::de::Error>(bytes: &[u8; 32]) -> Result<F, E> {
+    bytes_to_field_strict(bytes).map_err(E::custom)
+}
+
+/// `F` as a 32-byte array
+pub(crate) mod elem {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(x: &F, s: S) -> Result<S::Ok, S::Error> {
+        field_to_bytes(x).serialize(s)
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(d: D) -> Result<F, D::Error> {
+        decode(&<[u8; 32]>::deserialize(d)?)
+    }
+}
+
+/// `Vec<F>` as a sequence of 32-byte arrays
+pub(crate) mod elems {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(v: &[F], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(v.iter().map(field_to_bytes))
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(d: D) -> Result<Vec<F>, D::Error> {
+        Vec::<[u8; 32]>::deserialize(d)?
+            .iter()
+            .map(decode)
+            .collect()
+    }
+}
+
+/// `Vec<Vec<F>>` as nested sequences of 32-byte arrays
+pub(crate) mod rows {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(v: &[Vec<F>], s: S) -> Result<S::Ok, S::Error> {
+        s.collect_seq(
+            v.iter()
+                .map(|row| row.iter().map(field_to_bytes).collect::<Vec<_>>()),
+        )
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Vec<Vec<F>>, D::Error> {
+        Vec::<Vec<[u8; 32]>>::deserialize(d)?
+            .iter()
+            .map(|row| row.iter().map(decode).collect())
+            .collect()
+    }
+}
+
+/// `Option<F>`
+pub(crate) mod opt_elem {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(v: &Option<F>, s: S) -> Result<S::Ok, S::Error> {
+        v.as_ref().map(field_to_bytes).serialize(s)
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<F>, D::Error> {
+        Option::<[u8; 32]>::deserialize(d)?
+            .as_ref()
+            .map(decode)
+            .transpose()
+    }
+}
+
+/// `Option<(F, F)>`
+pub(crate) mod opt_pair {
+    use super::*;
+
+    pub fn serialize<F: PrimeField, S: Serializer>(
+        v: &Option<(F, F)>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        v.map(|(a, b)| (field_to_bytes(&a), field_to_bytes(&b)))
+            .serialize(s)
+    }
+
+    pub fn deserialize<'de, F: PrimeField, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<Option<(F, F)>, D::Error> {
+        Option::<([u8; 32], [u8; 32])>::deserialize(d)?
+            .map(|(a, b)| Ok((decode(&a)?, decode(&b)?)))
+            .transpose()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "PoseidonParams", bound = "F: PrimeField")]
+struct ParamsRepr<F> {
+    n_rounds_f: usize,
+    n_rounds_p: usize,
+    alpha: i64,
+    #[serde(with = "elems")]
+    c: Vec<F>,
+    #[serde(with = "rows")]
+    m: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> Serialize for PoseidonParams<F> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        ParamsRepr {
+            n_rounds_f: self.n_rounds_f,
+            n_rounds_p: self.n_rounds_p,
+            alpha: self.alpha,
+            c: self.c.clone(),
+            m: self.m.clone(),
+        }
+        .serialize(s)
+    }
+}
+
+/// Deserialized parameters go through `PoseidonParams::new`, so their shape is validated
+impl<'de, F: PrimeField> Deserialize<'de> for PoseidonParams<F> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let p = ParamsRepr::deserialize(d)?;
+        PoseidonParams::new(p.n_rounds_f, p.n_rounds_p, p.alpha, p.c, p.m).map_err(D::Error::custom)
+    }
+}
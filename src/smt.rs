@@ -0,0 +1,386 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::{bytes_to_field_strict, field_to_bytes, Poseidon, PoseidonError};
+
+// Encoded node: hash, tag and two field elements
+const NODE_BYTES: usize = 97;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Node<F> {
+    Leaf { key: F, value: F },
+    Middle { left: F, right: F },
+}
+
+/// Sparse Merkle tree following iden3's SMT conventions, as implemented by circomlibjs'
+/// `smt.js` and verified by circomlib's `SMTVerifier`.
+///
+/// Leaves hash as `Poseidon([key, value, 1])`, internal nodes as `Poseidon([left,
+/// right])` and empty subtrees as zero. The path of a key is given by its bits, least
+/// significant first, with a one bit going right. A subtree holding a single leaf is
+/// replaced by that leaf, so leaves sit at the shallowest level where their path is
+/// unique.
+#[derive(Clone)]
+pub struct SparseMerkleTree<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+    root: F,
+    nodes: BTreeMap<F, Node<F>>,
+}
+
+/// Inclusion or exclusion proof of a key in a `SparseMerkleTree`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "F: PrimeField")
+)]
+pub struct SmtProof<F = Fr> {
+    /// Siblings from the root down to the level where the search ended
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::elems"))]
+    pub siblings: Vec<F>,
+    /// Value of the key if it is in the tree
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::opt_elem"))]
+    pub value: Option<F>,
+    /// For an exclusion proof, the key and value of the leaf found on the key's path, or
+    /// `None` if the path ended in an empty subtree
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::opt_pair"))]
+    pub other: Option<(F, F)>,
+}
+
+#[cfg(all(feature = "width-3", feature = "width-4"))]
+impl Default for SparseMerkleTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseMerkleTree {
+    #[cfg(all(feature = "width-3", feature = "width-4"))]
+    pub fn new() -> SparseMerkleTree {
+        SparseMerkleTree::with_poseidon(Poseidon::new()).expect("BN254 supports widths 3 and 4")
+    }
+
+    /// Tree saved by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<SparseMerkleTree, PoseidonError> {
+        SparseMerkleTree::from_bytes_with(Poseidon::new(), bytes)
+    }
+}
+
+impl<F: PrimeField> SparseMerkleTree<F> {
+    /// Empty tree over the given Poseidon instance, which has to support two and three
+    /// inputs
+    pub fn with_poseidon(poseidon: Poseidon<F>) -> Result<SparseMerkleTree<F>, PoseidonError> {
+        for t in [3, 4] {
+            if !poseidon.supports_width(t) {
+                return Err(PoseidonError::InvalidInputLength {
+                    got: t - 1,
+                    max: poseidon.max_inputs(),
+                });
+            }
+        }
+        Ok(SparseMerkleTree {
+            poseidon,
+            root: F::zero(),
+            nodes: BTreeMap::new(),
+        })
+    }
+
+    /// Version of the `to_bytes` layout, its first byte
+    pub const STATE_VERSION: u8 = 1;
+
+    /// Encode the tree for `from_bytes`: `STATE_VERSION`, the root, the number of nodes
+    /// (u64, little-endian) and for each node in hash order its hash, a tag (0 for a leaf,
+    /// 1 for an internal node) and its key and value or its children, all field elements
+    /// as 32-byte little-endian arrays
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(41 + NODE_BYTES * self.nodes.len());
+        out.push(Self::STATE_VERSION);
+        out.extend_from_slice(&field_to_bytes(&self.root));
+        out.extend_from_slice(&(self.nodes.len() as u64).to_le_bytes());
+        for (hash, node) in &self.nodes {
+            let (tag, a, b) = match node {
+                Node::Leaf { key, value } => (0, key, value),
+                Node::Middle { left, right } => (1, left, right),
+            };
+            out.extend_from_slice(&field_to_bytes(hash));
+            out.push(tag);
+            out.extend_from_slice(&field_to_bytes(a));
+            out.extend_from_slice(&field_to_bytes(b));
+        }
+        out
+    }
+
+    /// Restore a tree saved by `to_bytes` over `poseidon`, which has to be the instance
+    /// the tree was built with.
+    ///
+    /// The stored node hashes are trusted, not recomputed, so only state written by
+    /// `to_bytes` should be restored; the layout itself is checked, including the version
+    /// byte and that the root is a stored node.
+    pub fn from_bytes_with(
+        poseidon: Poseidon<F>,
+        bytes: &[u8],
+    ) -> Result<SparseMerkleTree<F>, PoseidonError> {
+        let mut tree = SparseMerkleTree::with_poseidon(poseidon)?;
+        let (version, rest) = bytes.split_first().ok_or(PoseidonError::InvalidEncoding)?;
+        if *version != Self::STATE_VERSION {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+        let (root, rest) = rest
+            .split_first_chunk::<32>()
+            .ok_or(PoseidonError::InvalidEncoding)?;
+        let (count, nodes) = rest
+            .split_first_chunk::<8>()
+            .ok_or(PoseidonError::InvalidEncoding)?;
+        let count = u64::from_le_bytes(*count);
+        if nodes.len() as u64 != count.saturating_mul(NODE_BYTES as u64) {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+        let field = |bytes: &[u8]| bytes_to_field_strict(bytes.try_into().expect("32 bytes"));
+        for node in nodes.chunks_exact(NODE_BYTES) {
+            let (a, b) = (field(&node[33..65])?, field(&node[65..])?);
+            let decoded = match node[32] {
+                0 => Node::Leaf { key: a, value: b },
+                1 => Node::Middle { left: a, right: b },
+                _ => return Err(PoseidonError::InvalidEncoding),
+            };
+            tree.nodes.insert(field(&node[..32])?, decoded);
+        }
+        tree.root = bytes_to_field_strict(root)?;
+        if tree.nodes.len() as u64 != count
+            || !(tree.root.is_zero() || tree.nodes.contains_key(&tree.root))
+        {
+            return Err(PoseidonError::InvalidEncoding);
+        }
+        Ok(tree)
+    }
+
+    pub fn root(&self) -> F {
+        self.root
+    }
+
+    pub fn get(&self, key: &F) -> Option<F> {
+        self.prove(key).value
+    }
+
+    /// Insert a new key; fails if the key is already in the tree
+    pub fn insert(&mut self, key: F, value: F) -> Result<(), PoseidonError> {
+        self.root = self.insert_at(self.root, 0, key, value)?;
+        Ok(())
+    }
+
+    /// Replace the value of a key, returning the old value
+    pub fn update(&mut self, key: F, value: F) -> Result<F, PoseidonError> {
+        let (root, old) = self.update_at(self.root, 0, key, value)?;
+        self.root = root;
+        Ok(old)
+    }
+
+    /// Remove a key, returning its value
+    pub fn delete(&mut self, key: F) -> Result<F, PoseidonError> {
+        let (root, old) = self.delete_at(self.root, 0, key)?;
+        self.root = root;
+        Ok(old)
+    }
+
+    /// Inclusion proof if `key` is in the tree, exclusion proof otherwise
+    pub fn prove(&self, key: &F) -> SmtProof<F> {
+        let mut siblings = Vec::new();
+        let mut node = self.root;
+        loop {
+            match self.nodes.get(&node) {
+                None => {
+                    return SmtProof {
+                        siblings,
+                        value: None,
+                        other: None,
+                    }
+                }
+                Some(Node::Leaf { key: k, value }) => {
+                    let (value, other) = if k == key {
+                        (Some(*value), None)
+                    } else {
+                        (None, Some((*k, *value)))
+                    };
+                    return SmtProof {
+                        siblings,
+                        value,
+                        other,
+                    };
+                }
+                Some(Node::Middle { left, right }) => {
+                    if bit(key, siblings.len()) {
+                        siblings.push(*left);
+                        node = *right;
+                    } else {
+                        siblings.push(*right);
+                        node = *left;
+                    }
+                }
+            }
+        }
+    }
+
+    fn insert_at(&mut self, node: F, level: usize, key: F, value: F) -> Result<F, PoseidonError> {
+        match self.nodes.get(&node).copied() {
+            None => Ok(self.leaf(key, value)),
+            Some(Node::Leaf { key: k, .. }) if k == key => Err(PoseidonError::KeyAlreadyExists),
+            Some(Node::Leaf { key: k, .. }) => {
+                let new = self.leaf(key, value);
+                Ok(self.split(level, (key, new), (k, node)))
+            }
+            Some(Node::Middle { left, right }) => {
+                let (left, right) = if bit(&key, level) {
+                    (left, self.insert_at(right, level + 1, key, value)?)
+                } else {
+                    (self.insert_at(left, level + 1, key, value)?, right)
+                };
+                self.nodes.remove(&node);
+                Ok(self.middle(left, right))
+            }
+        }
+    }
+
+    fn update_at(
+        &mut self,
+        node: F,
+        level: usize,
+        key: F,
+        value: F,
+    ) -> Result<(F, F), PoseidonError> {
+        match self.nodes.get(&node).copied() {
+            Some(Node::Leaf { key: k, value: old }) if k == key => {
+                self.nodes.remove(&node);
+                Ok((self.leaf(key, value), old))
+            }
+            None | Some(Node::Leaf { .. }) => Err(PoseidonError::KeyNotFound),
+            Some(Node::Middle { left, right }) => {
+                let (left, right, old) = if bit(&key, level) {
+                    let (right, old) = self.update_at(right, level + 1, key, value)?;
+                    (left, right, old)
+                } else {
+                    let (left, old) = self.update_at(left, level + 1, key, value)?;
+                    (left, right, old)
+                };
+                self.nodes.remove(&node);
+                Ok((self.middle(left, right), old))
+            }
+        }
+    }
+
+    fn delete_at(&mut self, node: F, level: usize, key: F) -> Result<(F, F), PoseidonError> {
+        match self.nodes.get(&node).copied() {
+            Some(Node::Leaf { key: k, value: old }) if k == key => {
+                self.nodes.remove(&node);
+                Ok((F::zero(), old))
+            }
+            None | Some(Node::Leaf { .. }) => Err(PoseidonError::KeyNotFound),
+            Some(Node::Middle { left, right }) => {
+                let right_side = bit(&key, level);
+                let (child, other) = if right_side {
+                    (right, left)
+                } else {
+                    (left, right)
+                };
+                let (child, old) = self.delete_at(child, level + 1, key)?;
+                self.nodes.remove(&node);
+                // A lone leaf moves up to replace its parent
+                let new = if child.is_zero() && self.is_leaf(&other) {
+                    other
+                } else if other.is_zero() && self.is_leaf(&child) {
+                    child
+                } else if right_side {
+                    self.middle(other, child)
+                } else {
+                    self.middle(child, other)
+                };
+                Ok((new, old))
+            }
+        }
+    }
+
+    /// Smallest subtree at `level` holding the leaves `a` and `b`, whose keys share bits
+    /// below `level`
+    fn split(&mut self, level: usize, a: (F, F), b: (F, F)) -> F {
+        match (bit(&a.0, level), bit(&b.0, level)) {
+            (false, true) => self.middle(a.1, b.1),
+            (true, false) => self.middle(b.1, a.1),
+            (false, false) => {
+                let child = self.split(level + 1, a, b);
+                self.middle(child, F::zero())
+            }
+            (true, true) => {
+                let child = self.split(level + 1, a, b);
+                self.middle(F::zero(), child)
+            }
+        }
+    }
+
+    fn is_leaf(&self, node: &F) -> bool {
+        matches!(self.nodes.get(node), Some(Node::Leaf { .. }))
+    }
+
+    fn leaf(&mut self, key: F, value: F) -> F {
+        let hash = leaf_hash(&self.poseidon, key, value);
+        self.nodes.insert(hash, Node::Leaf { key, value });
+        hash
+    }
+
+    fn middle(&mut self, left: F, right: F) -> F {
+        let hash = middle_hash(&self.poseidon, left, right);
+        self.nodes.insert(hash, Node::Middle { left, right });
+        hash
+    }
+}
+
+impl SmtProof {
+    /// Check the proof for `key` against `root`
+    pub fn verify(&self, root: Fr, key: Fr) -> bool {
+        self.verify_with(&Poseidon::new(), root, key)
+    }
+}
+
+impl<F: PrimeField> SmtProof<F> {
+    /// Check the proof for `key` against `root`, hashing with `poseidon`; an inclusion
+    /// proof also proves `value`
+    pub fn verify_with(&self, poseidon: &Poseidon<F>, root: F, key: F) -> bool {
+        if !poseidon.supports_width(3) || !poseidon.supports_width(4) {
+            return false;
+        }
+        let mut node = match (self.value, self.other) {
+            (Some(value), None) => leaf_hash(poseidon, key, value),
+            (None, Some((k, value))) if k != key => {
+                if (0..self.siblings.len()).any(|level| bit(&k, level) != bit(&key, level)) {
+                    return false;
+                }
+                leaf_hash(poseidon, k, value)
+            }
+            (None, None) => F::zero(),
+            _ => return false,
+        };
+        for (level, sibling) in self.siblings.iter().enumerate().rev() {
+            node = if bit(&key, level) {
+                middle_hash(poseidon, *sibling, node)
+            } else {
+                middle_hash(poseidon, node, *sibling)
+            };
+        }
+        node == root
+    }
+}
+
+fn bit<F: PrimeField>(key: &F, level: usize) -> bool {
+    key.into_bigint().get_bit(level)
+}
+
+fn leaf_hash<F: PrimeField>(poseidon: &Poseidon<F>, key: F, value: F) -> F {
+    poseidon
+        .hash(&[key, value, F::one()])
+        .expect("width 4 is checked on construction")
+}
+
+fn middle_hash<F: PrimeField>(poseidon: &Poseidon<F>, left: F, right: F) -> F {
+    poseidon
+        .hash(&[left, right])
+        .expect("width 3 is checked on construction")
+}
@@ -0,0 +1,141 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{Poseidon, PoseidonError, MAX_WIDTH};
+
+/// Poseidon sponge over the circomlib permutation.
+///
+/// The first `capacity` state elements form the capacity and the following `rate`
+/// elements the rate, so `rate + capacity` selects the permutation width (2..=25).
+/// Absorbed input is padded with a single one followed by zeros before squeezing.
+#[derive(Clone)]
+pub struct PoseidonSponge<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+    state: [F; MAX_WIDTH],
+    rate: usize,
+    capacity: usize,
+    absorbed: usize,
+    squeezed: Option<usize>,
+}
+
+impl PoseidonSponge {
+    pub fn new(rate: usize, capacity: usize) -> Result<PoseidonSponge, PoseidonError> {
+        PoseidonSponge::with_poseidon(Poseidon::new(), rate, capacity)
+    }
+}
+
+impl<F: PrimeField> PoseidonSponge<F> {
+    /// Sponge over the permutation of the given Poseidon instance
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        rate: usize,
+        capacity: usize,
+    ) -> Result<PoseidonSponge<F>, PoseidonError> {
+        if rate == 0 || capacity == 0 || !poseidon.supports_width(rate + capacity) {
+            return Err(PoseidonError::InvalidSpongeParameters { rate, capacity });
+        }
+        Ok(PoseidonSponge {
+            poseidon,
+            state: [F::zero(); MAX_WIDTH],
+            rate,
+            capacity,
+            absorbed: 0,
+            squeezed: None,
+        })
+    }
+
+    /// Sponge starting with `domain` in the first capacity element instead of zero, which
+    /// separates it from sponges over the same permutation with other domains
+    pub fn with_domain(
+        poseidon: Poseidon<F>,
+        rate: usize,
+        capacity: usize,
+        domain: F,
+    ) -> Result<PoseidonSponge<F>, PoseidonError> {
+        let mut sponge = PoseidonSponge::with_poseidon(poseidon, rate, capacity)?;
+        sponge.state[0] = domain;
+        Ok(sponge)
+    }
+
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Absorb field elements; fails once squeezing has started
+    pub fn absorb(&mut self, inp: &[F]) -> Result<(), PoseidonError> {
+        if self.squeezed.is_some() {
+            return Err(PoseidonError::AlreadySqueezed);
+        }
+        for x in inp {
+            self.absorb_one(*x);
+        }
+        Ok(())
+    }
+
+    /// Squeeze one field element from the rate part of the state
+    pub fn squeeze(&mut self) -> F {
+        let next = match self.squeezed {
+            None => {
+                self.pad();
+                0
+            }
+            Some(n) if n == self.rate => {
+                self.permute();
+                0
+            }
+            Some(n) => n,
+        };
+        self.squeezed = Some(next + 1);
+        self.state[self.capacity + next]
+    }
+
+    /// Squeeze `n` field elements
+    #[cfg(feature = "alloc")]
+    pub fn squeeze_many(&mut self, n: usize) -> Vec<F> {
+        (0..n).map(|_| self.squeeze()).collect()
+    }
+
+    pub(crate) fn absorb_one(&mut self, x: F) {
+        if self.absorbed == self.rate {
+            self.permute();
+            self.absorbed = 0;
+        }
+        self.state[self.capacity + self.absorbed] += x;
+        self.absorbed += 1;
+    }
+
+    fn pad(&mut self) {
+        self.absorb_one(F::one());
+        self.permute();
+    }
+
+    fn permute(&mut self) {
+        let t = self.rate + self.capacity;
+        self.poseidon.permute(&mut self.state[..t]);
+    }
+}
+
+/// Wipes the state, leaving an empty sponge with a zero domain
+#[cfg(feature = "zeroize")]
+impl<F: PrimeField> zeroize::Zeroize for PoseidonSponge<F> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.absorbed = 0;
+        self.squeezed = None;
+    }
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    /// Hash an arbitrary number of field elements with a rate-`rate`, capacity-1 sponge
+    pub fn hash_sponge(&self, rate: usize, inp: &[F]) -> Result<F, PoseidonError> {
+        let mut sponge = PoseidonSponge::with_poseidon(self.clone(), rate, 1)?;
+        sponge.absorb(inp)?;
+        Ok(sponge.squeeze())
+    }
+}
@@ -0,0 +1,163 @@
+//! Per-step states of the permutation, for diffing the native hash against a circuit.
+//!
+//! The trace follows the reference round schedule that circuits such as
+//! `r1cs::PoseidonGadget` implement: every round adds its constants, applies the s-box
+//! (to the full state in full rounds, to `state[0]` in partial rounds) and mixes with the
+//! dense MDS matrix. The sparse partial rounds `hash` runs internally reach the same
+//! output through different intermediate states, so they are not traced.
+
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{Constants, Poseidon, PoseidonError, Width};
+
+/// One of the three steps of a round
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundStep {
+    /// Round constant addition
+    Ark,
+    /// S-box
+    Sbox,
+    /// MDS multiplication
+    Mix,
+}
+
+/// The state after one step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep<F = Fr> {
+    pub round: usize,
+    pub step: RoundStep,
+    /// Whether `round` is a full round
+    pub full_round: bool,
+    pub state: Vec<F>,
+}
+
+/// The states of one permutation: the input state, then three steps per round
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoseidonTrace<F = Fr> {
+    pub initial: Vec<F>,
+    pub steps: Vec<TraceStep<F>>,
+}
+
+impl<F: PrimeField> PoseidonTrace<F> {
+    /// The permuted state
+    pub fn output(&self) -> &[F] {
+        self.steps.last().map_or(&self.initial, |s| &s.state)
+    }
+
+    /// Index into `steps` of the first state that differs from `other`'s, or `None` if
+    /// all states agree. If one trace is a prefix of the other, this is the length of the
+    /// shorter one. `initial` is not compared, so traces of different inputs diverge at
+    /// the first step they disagree on.
+    pub fn first_divergence(&self, other: &PoseidonTrace<F>) -> Option<usize> {
+        self.steps
+            .iter()
+            .zip(&other.steps)
+            .position(|(a, b)| a.state != b.state)
+            .or_else(|| {
+                (self.steps.len() != other.steps.len())
+                    .then(|| self.steps.len().min(other.steps.len()))
+            })
+    }
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    /// `hash` together with the state after every ark, s-box and mix step
+    pub fn hash_with_trace(&self, inp: &[F]) -> Result<(F, PoseidonTrace<F>), PoseidonError> {
+        let t = inp.len() + 1;
+        if inp.is_empty() || !self.supports_width(t) {
+            return Err(PoseidonError::InvalidInputLength {
+                got: inp.len(),
+                max: self.max_inputs(),
+            });
+        }
+        let mut state = Vec::with_capacity(t);
+        state.push(F::zero());
+        state.extend_from_slice(inp);
+        let trace = match &self.constants {
+            Constants::Static(k) => {
+                let k = Width::new(t)
+                    .and_then(|w| k.width(w))
+                    .expect("supported width");
+                self.trace(&mut state, k.n_rounds_f, k.n_rounds_p, k.c, k.m)
+            }
+            Constants::Params(p) => {
+                let k = &p.params;
+                self.trace(&mut state, k.n_rounds_f, k.n_rounds_p, &k.c, &k.m)
+            }
+        };
+        Ok((state[0], trace))
+    }
+
+    fn trace<R: AsRef<[F]>>(
+        &self,
+        state: &mut [F],
+        n_rounds_f: usize,
+        n_rounds_p: usize,
+        c: &[F],
+        m: &[R],
+    ) -> PoseidonTrace<F> {
+        let t = state.len();
+        let mut trace = PoseidonTrace {
+            initial: state.to_vec(),
+            steps: Vec::with_capacity(3 * (n_rounds_f + n_rounds_p)),
+        };
+        for round in 0..(n_rounds_f + n_rounds_p) {
+            let full_round = round < n_rounds_f / 2 || round >= n_rounds_f / 2 + n_rounds_p;
+            let mut record = |step, state: &[F]| {
+                trace.steps.push(TraceStep {
+                    round,
+                    step,
+                    full_round,
+                    state: state.to_vec(),
+                })
+            };
+            self.ark(state, c, round * t);
+            record(RoundStep::Ark, state);
+            self.sbox(n_rounds_f, n_rounds_p, state, round);
+            record(RoundStep::Sbox, state);
+            self.mix_inplace(state, m);
+            record(RoundStep::Mix, state);
+        }
+        trace
+    }
+}
+
+#[cfg(all(test, feature = "width-3"))]
+mod tests {
+    use super::*;
+
+    fn trace(inp: &[Fr]) -> PoseidonTrace {
+        let (hash, trace) = Poseidon::new().hash_with_trace(inp).unwrap();
+        assert_eq!(hash, crate::hash(inp).unwrap());
+        trace
+    }
+
+    #[test]
+    fn equal_traces_do_not_diverge() {
+        let a = trace(&[Fr::from(1u64), Fr::from(2u64)]);
+        assert_eq!(a.first_divergence(&a.clone()), None);
+    }
+
+    #[test]
+    fn divergence_at_the_first_differing_step() {
+        let a = trace(&[Fr::from(1u64), Fr::from(2u64)]);
+        let mut b = a.clone();
+        b.steps[10].state[1] += Fr::from(1u64);
+        assert_eq!(a.first_divergence(&b), Some(10));
+        assert_eq!(b.first_divergence(&a), Some(10));
+
+        let c = trace(&[Fr::from(1u64), Fr::from(3u64)]);
+        assert_eq!(a.first_divergence(&c), Some(0));
+    }
+
+    #[test]
+    fn prefix_diverges_at_its_end() {
+        let a = trace(&[Fr::from(1u64), Fr::from(2u64)]);
+        let mut b = a.clone();
+        b.steps.truncate(7);
+        assert_eq!(a.first_divergence(&b), Some(7));
+        assert_eq!(b.first_divergence(&a), Some(7));
+    }
+}
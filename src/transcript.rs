@@ -0,0 +1,109 @@
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+use crate::{pack_bytes, Poseidon, PoseidonError};
+
+/// Capacity tag of transcript sponges
+const TRANSCRIPT_DOMAIN: u64 = 0x7472616e736372;
+
+const RATE: usize = 2;
+const WIDTH: usize = RATE + 1;
+
+// Operation codes absorbed before each label
+const OP_ABSORB: u64 = 1;
+const OP_ABSORB_BYTES: u64 = 2;
+const OP_CHALLENGE: u64 = 3;
+
+/// Fiat–Shamir transcript over a rate-2, capacity-1 duplex sponge.
+///
+/// Every operation absorbs an operation code and its length-prefixed label before its
+/// data, so transcripts with different operation sequences never share a state. A
+/// challenge permutes and outputs the first rate element; the next absorption permutes
+/// again before adding to the state, so challenges are never mixed with later inputs.
+pub struct PoseidonTranscript<F: 'static = Fr> {
+    poseidon: Poseidon<F>,
+    state: [F; WIDTH],
+    pos: usize,
+}
+
+impl PoseidonTranscript {
+    /// Transcript for the protocol named `label`
+    #[cfg(feature = "width-3")]
+    pub fn new(label: &[u8]) -> PoseidonTranscript {
+        PoseidonTranscript::with_poseidon(Poseidon::new(), label).expect("BN254 supports width 3")
+    }
+}
+
+impl<F: PrimeField> PoseidonTranscript<F> {
+    /// Transcript over the permutation of the given instance, which has to support width 3
+    pub fn with_poseidon(
+        poseidon: Poseidon<F>,
+        label: &[u8],
+    ) -> Result<PoseidonTranscript<F>, PoseidonError> {
+        if !poseidon.supports_width(WIDTH) {
+            return Err(PoseidonError::InvalidSpongeParameters {
+                rate: RATE,
+                capacity: 1,
+            });
+        }
+        let mut state = [F::zero(); WIDTH];
+        state[0] = F::from(TRANSCRIPT_DOMAIN);
+        let mut transcript = PoseidonTranscript {
+            poseidon,
+            state,
+            pos: 0,
+        };
+        transcript.absorb_bytes_raw(label);
+        Ok(transcript)
+    }
+
+    /// Absorb a field element
+    pub fn absorb(&mut self, label: &[u8], x: F) {
+        self.absorb_label(OP_ABSORB, label);
+        self.absorb_one(x);
+    }
+
+    /// Absorb a byte string
+    pub fn absorb_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.absorb_label(OP_ABSORB_BYTES, label);
+        self.absorb_bytes_raw(bytes);
+    }
+
+    /// Derive a challenge from everything absorbed so far
+    pub fn challenge(&mut self, label: &[u8]) -> F {
+        self.absorb_label(OP_CHALLENGE, label);
+        self.poseidon.permute(&mut self.state);
+        self.pos = RATE;
+        self.state[1]
+    }
+
+    fn absorb_label(&mut self, op: u64, label: &[u8]) {
+        self.absorb_one(F::from(op));
+        self.absorb_bytes_raw(label);
+    }
+
+    fn absorb_bytes_raw(&mut self, bytes: &[u8]) {
+        self.absorb_one(F::from(bytes.len() as u64));
+        for x in pack_bytes(bytes) {
+            self.absorb_one(x);
+        }
+    }
+
+    fn absorb_one(&mut self, x: F) {
+        if self.pos == RATE {
+            self.poseidon.permute(&mut self.state);
+            self.pos = 0;
+        }
+        self.state[1 + self.pos] += x;
+        self.pos += 1;
+    }
+}
+
+/// Wipes the duplex state
+#[cfg(feature = "zeroize")]
+impl<F: PrimeField> zeroize::Zeroize for PoseidonTranscript<F> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+        self.pos = 0;
+    }
+}
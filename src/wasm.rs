@@ -0,0 +1,50 @@
+//! wasm-bindgen exports of the circomlib BN254 hash for browsers and Node.
+//!
+//! `hash_hex` speaks the big-endian hex of circomlibjs and ethers; `hash_bytes` takes the
+//! 32-byte little-endian elements of `Poseidon::hash_bytes` and the Solana syscall, so a
+//! dApp can build the exact instruction data an on-chain program hashes.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use wasm_bindgen::prelude::*;
+
+use crate::{DecodeMode, Poseidon, PoseidonError};
+
+/// Hash field elements given as big-endian hex strings, with or without `0x`, into a
+/// `0x`-prefixed 64-digit hex digest
+#[wasm_bindgen(js_name = hashHex)]
+pub fn hash_hex(inputs: Vec<String>) -> Result<String, JsError> {
+    let inp = inputs
+        .iter()
+        .map(|s| Poseidon::hex_to_field(s.as_bytes()))
+        .collect::<Result<Vec<Fr>, _>>()
+        .map_err(to_js)?;
+    let hash = Poseidon::new().hash(&inp).map_err(to_js)?;
+    let hex = Poseidon::field_to_hex(&hash);
+    Ok(alloc::format!(
+        "0x{}",
+        core::str::from_utf8(&hex).expect("hex is ASCII")
+    ))
+}
+
+/// Hash the concatenation of 32-byte little-endian field elements into a 32-byte
+/// little-endian digest, rejecting non-canonical elements
+#[wasm_bindgen(js_name = hashBytes)]
+pub fn hash_bytes(inputs: &[u8]) -> Result<Vec<u8>, JsError> {
+    if !inputs.len().is_multiple_of(32) {
+        return Err(JsError::new("input length must be a multiple of 32"));
+    }
+    let inp: Vec<&[u8; 32]> = inputs
+        .chunks_exact(32)
+        .map(|c| c.try_into().expect("32-byte chunks"))
+        .collect();
+    let digest = Poseidon::new()
+        .hash_bytes(&inp, DecodeMode::Strict)
+        .map_err(to_js)?;
+    Ok(digest.as_bytes().to_vec())
+}
+
+fn to_js(e: PoseidonError) -> JsError {
+    JsError::new(&alloc::format!("{}", e))
+}
@@ -0,0 +1,128 @@
+//! Typed Poseidon state widths.
+//!
+//! A `Width` is one of the widths `t = 2..=25` the constant tables have slots for, so
+//! code holding one cannot index past them; `index` is the slot of the width in the
+//! per-width tables of `PoseidonConstants`. Whether a constant set actually has
+//! constants for it is still checked at runtime, as the tables follow the `width-{t}`
+//! features.
+
+use crate::PoseidonError;
+
+/// State width `t`, one more than the number of inputs a hash takes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Width {
+    W2 = 2,
+    W3,
+    W4,
+    W5,
+    W6,
+    W7,
+    W8,
+    W9,
+    W10,
+    W11,
+    W12,
+    W13,
+    W14,
+    W15,
+    W16,
+    W17,
+    W18,
+    W19,
+    W20,
+    W21,
+    W22,
+    W23,
+    W24,
+    W25,
+}
+
+impl Width {
+    /// Number of widths
+    pub const COUNT: usize = 24;
+
+    /// Every width, in increasing order
+    pub const ALL: [Width; Width::COUNT] = [
+        Width::W2,
+        Width::W3,
+        Width::W4,
+        Width::W5,
+        Width::W6,
+        Width::W7,
+        Width::W8,
+        Width::W9,
+        Width::W10,
+        Width::W11,
+        Width::W12,
+        Width::W13,
+        Width::W14,
+        Width::W15,
+        Width::W16,
+        Width::W17,
+        Width::W18,
+        Width::W19,
+        Width::W20,
+        Width::W21,
+        Width::W22,
+        Width::W23,
+        Width::W24,
+        Width::W25,
+    ];
+
+    pub const MIN: Width = Width::W2;
+    pub const MAX: Width = Width::W25;
+
+    /// Width `t`, if it is in 2..=25
+    pub const fn new(t: usize) -> Option<Width> {
+        if t >= Width::MIN.get() && t <= Width::MAX.get() {
+            Some(Width::ALL[t - Width::MIN.get()])
+        } else {
+            None
+        }
+    }
+
+    /// The width hashing `n` inputs, if `n` is in 1..=24
+    pub const fn from_inputs(n: usize) -> Option<Width> {
+        Width::new(n.saturating_add(1))
+    }
+
+    /// Width `T`, checked at compile time
+    pub const fn of<const T: usize>() -> Width {
+        const { assert!(T >= 2 && T <= 25, "Poseidon width must be in 2..=25") };
+        Width::ALL[T - Width::MIN.get()]
+    }
+
+    /// `t`
+    pub const fn get(self) -> usize {
+        self as usize
+    }
+
+    /// Inputs of a hash of this width, `t - 1`
+    pub const fn inputs(self) -> usize {
+        self.get() - 1
+    }
+
+    /// Slot of the width in the per-width tables, `t - 2`
+    pub const fn index(self) -> usize {
+        self.get() - Width::MIN.get()
+    }
+}
+
+/// Fails with `InvalidInputLength` for the inputs `t - 1` outside 1..=24
+impl TryFrom<usize> for Width {
+    type Error = PoseidonError;
+
+    fn try_from(t: usize) -> Result<Self, Self::Error> {
+        Width::new(t).ok_or(PoseidonError::InvalidInputLength {
+            got: t.saturating_sub(1),
+            max: Width::MAX.inputs(),
+        })
+    }
+}
+
+impl From<Width> for usize {
+    fn from(width: Width) -> Self {
+        width.get()
+    }
+}